@@ -147,14 +147,14 @@ impl Default for MyCanvas {
                     }),
                     Shape::Points((
                         vec![
-                            (21.0 as f64, 13.0 as f64),
-                            (66.0 as f64, 77.0 as f64),
-                            (34.0 as f64, 69.0 as f64),
-                            (45.0 as f64, 76.0 as f64),
-                            (120.0 as f64, 55.0 as f64),
-                            (-32.0 as f64, -50.0 as f64),
-                            (-4.0 as f64, 2.0 as f64),
-                            (-32.0 as f64, -48.0 as f64),
+                            (21.0_f64, 13.0_f64),
+                            (66.0_f64, 77.0_f64),
+                            (34.0_f64, 69.0_f64),
+                            (45.0_f64, 76.0_f64),
+                            (120.0_f64, 55.0_f64),
+                            (-32.0_f64, -50.0_f64),
+                            (-4.0_f64, 2.0_f64),
+                            (-32.0_f64, -48.0_f64),
                         ],
                         Color::Green,
                     )),
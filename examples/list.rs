@@ -4,8 +4,8 @@
 
 use std::time::Duration;
 
-use tui_realm_stdlib::List;
-use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tui_realm_stdlib::{utils, List};
+use tuirealm::command::CmdResult;
 use tuirealm::props::{Alignment, BorderType, Borders, Color, TableBuilder, TextSpan};
 use tuirealm::terminal::{CrosstermTerminalAdapter, TerminalBridge};
 use tuirealm::{
@@ -214,30 +214,15 @@ impl Default for ListAlfa {
 
 impl Component<Msg, NoUserEvent> for ListAlfa {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
-        let _ = match ev {
-            Event::Keyboard(KeyEvent {
-                code: Key::Down, ..
-            }) => self.perform(Cmd::Move(Direction::Down)),
-            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
-                self.perform(Cmd::Move(Direction::Up))
-            }
-            Event::Keyboard(KeyEvent {
-                code: Key::PageDown,
-                ..
-            }) => self.perform(Cmd::Scroll(Direction::Down)),
-            Event::Keyboard(KeyEvent {
-                code: Key::PageUp, ..
-            }) => self.perform(Cmd::Scroll(Direction::Up)),
-            Event::Keyboard(KeyEvent {
-                code: Key::Home, ..
-            }) => self.perform(Cmd::GoTo(Position::Begin)),
-            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
-                self.perform(Cmd::GoTo(Position::End))
-            }
+        match ev {
             Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => return Some(Msg::ListAlfaBlur),
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::AppClose),
-            _ => CmdResult::None,
-        };
+            ev => {
+                if let Some(cmd) = utils::default_keymap(ev) {
+                    self.perform(cmd);
+                }
+            }
+        }
         Some(Msg::None)
     }
 }
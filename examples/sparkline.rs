@@ -10,7 +10,7 @@ use tui_realm_stdlib::Sparkline;
 // tui
 use tuirealm::ratatui::layout::{Constraint, Direction as LayoutDirection, Layout};
 
-use tuirealm::command::CmdResult;
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::listener::{ListenerResult, Poll};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, BorderType, Borders, Color, PropPayload, PropValue,
@@ -54,7 +54,7 @@ impl Default for Model {
             EventListenerCfg::default()
                 .crossterm_input_listener(Duration::from_millis(10), 10)
                 .add_port(
-                    Box::new(DataGen::new(0 as u64, 64 as u64)),
+                    Box::new(DataGen::new(0_u64, 64_u64)),
                     Duration::from_millis(100),
                     1,
                 ),
@@ -168,6 +168,18 @@ impl Component<Msg, UserEvent> for SparklineAlfa {
     fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
         let _ = match ev {
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::AppClose),
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => self.perform(Cmd::Move(Direction::Left)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => self.perform(Cmd::Move(Direction::Right)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => self.perform(Cmd::GoTo(Position::Begin)),
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End))
+            }
             Event::User(UserEvent::DataGenerated(data)) => {
                 let data: Vec<PropValue> = data.into_iter().map(PropValue::U64).collect();
                 self.attr(
@@ -2,6 +2,9 @@
 //!
 //! `Utils` provides structures useful to implement gui with tui-rs
 
+// not every example uses every utility
+#![allow(dead_code, unused_imports)]
+
 mod data_gen;
 mod loader;
 
@@ -7,13 +7,19 @@ use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
 };
-use tuirealm::ratatui::{layout::Rect, widgets::BarChart as TuiBarChart};
+use tuirealm::ratatui::text::Line;
+use tuirealm::ratatui::{
+    layout::Rect,
+    widgets::{Bar, BarChart as TuiBarChart, BarGroup},
+};
 use tuirealm::{Frame, MockComponent, State};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 // -- Props
 
 use super::props::{
-    BAR_CHART_BARS_GAP, BAR_CHART_BARS_STYLE, BAR_CHART_LABEL_STYLE, BAR_CHART_MAX_BARS,
+    BAR_CHART_BARS_GAP, BAR_CHART_BARS_STYLE, BAR_CHART_GROUPED_DATA, BAR_CHART_LABEL_ELLIPSIS,
+    BAR_CHART_LABEL_STYLE, BAR_CHART_LABEL_TRUNCATE, BAR_CHART_MAX_BARS, BAR_CHART_SERIES_COLORS,
     BAR_CHART_VALUES_STYLE,
 };
 
@@ -23,6 +29,7 @@ use super::props::{
 ///
 /// Bar chart states
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BarChartStates {
     pub cursor: usize,
 }
@@ -46,6 +53,22 @@ impl BarChartStates {
         }
     }
 
+    /// ### scroll_cursor_left
+    ///
+    /// Move cursor to the left by `step`, stopping at the beginning of the chart
+    pub fn scroll_cursor_left(&mut self, step: usize) {
+        self.cursor = self.cursor.saturating_sub(step);
+    }
+
+    /// ### scroll_cursor_right
+    ///
+    /// Move cursor to the right by `step`, stopping at the end of the chart
+    pub fn scroll_cursor_right(&mut self, step: usize, data_len: usize) {
+        if data_len > 0 {
+            self.cursor = self.cursor.saturating_add(step).min(data_len - 1);
+        }
+    }
+
     /// ### reset_cursor
     ///
     /// Reset cursor to 0
@@ -117,6 +140,12 @@ impl BarChart {
         self
     }
 
+    /// Set the amount of entries the cursor is moved by on `Cmd::Scroll` (default 8)
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
     pub fn data(mut self, data: &[(&str, u64)]) -> Self {
         let mut list: LinkedList<PropPayload> = LinkedList::new();
         data.iter().for_each(|(a, b)| {
@@ -132,6 +161,42 @@ impl BarChart {
         self
     }
 
+    /// Set data as groups of bars, one bar for each series, displayed side by side (e.g. incomes and expenses per month).
+    /// Each group is made of its label and of its bars; when set, it is displayed instead of `data`.
+    /// The cursor and `max_bars` work on groups
+    pub fn grouped_data(mut self, data: &[(&str, &[(&str, u64)])]) -> Self {
+        let groups: LinkedList<PropPayload> = data
+            .iter()
+            .map(|(label, bars)| {
+                let mut group: LinkedList<PropPayload> = LinkedList::new();
+                group.push_back(PropPayload::One(PropValue::Str(label.to_string())));
+                bars.iter().for_each(|(a, b)| {
+                    group.push_back(PropPayload::Tup2((
+                        PropValue::Str(a.to_string()),
+                        PropValue::U64(*b),
+                    )))
+                });
+                PropPayload::Linked(group)
+            })
+            .collect();
+        self.attr(
+            Attribute::Custom(BAR_CHART_GROUPED_DATA),
+            AttrValue::Payload(PropPayload::Linked(groups)),
+        );
+        self
+    }
+
+    /// Set the color of the bars of each series in grouped data, by position in the group
+    pub fn series_colors(mut self, colors: &[Color]) -> Self {
+        self.attr(
+            Attribute::Custom(BAR_CHART_SERIES_COLORS),
+            AttrValue::Payload(PropPayload::Vec(
+                colors.iter().map(|x| PropValue::Color(*x)).collect(),
+            )),
+        );
+        self
+    }
+
     pub fn bar_gap(mut self, gap: u16) -> Self {
         self.attr(Attribute::Custom(BAR_CHART_BARS_GAP), AttrValue::Size(gap));
         self
@@ -150,6 +215,24 @@ impl BarChart {
         self
     }
 
+    /// Truncate labels wider than `width` columns, to keep them legible when bars are narrow
+    pub fn label_truncate(mut self, width: usize) -> Self {
+        self.attr(
+            Attribute::Custom(BAR_CHART_LABEL_TRUNCATE),
+            AttrValue::Length(width),
+        );
+        self
+    }
+
+    /// End truncated labels with an ellipsis (`…`), to show they have been abbreviated (default `false`)
+    pub fn label_ellipsis(mut self, ellipsis: bool) -> Self {
+        self.attr(
+            Attribute::Custom(BAR_CHART_LABEL_ELLIPSIS),
+            AttrValue::Flag(ellipsis),
+        );
+        self
+    }
+
     pub fn max_bars(mut self, l: usize) -> Self {
         self.attr(Attribute::Custom(BAR_CHART_MAX_BARS), AttrValue::Length(l));
         self
@@ -168,22 +251,196 @@ impl BarChart {
         self
     }
 
+    /// Set the string prepended to the label of the bar at the cursor in active mode
+    pub fn highlighted_str<S: Into<String>>(mut self, s: S) -> Self {
+        self.attr(Attribute::HighlightedStr, AttrValue::String(s.into()));
+        self
+    }
+
+    /// Set the color of the bar at the cursor in active mode
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
     fn is_disabled(&self) -> bool {
         self.props
             .get_or(Attribute::Disabled, AttrValue::Flag(false))
             .unwrap_flag()
     }
 
+    fn scroll_step(&self) -> usize {
+        self.props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length()
+    }
+
     /// ### data_len
     ///
-    /// Retrieve current data len from properties
+    /// Retrieve current data len from properties; if grouped data is set, this is the amount of groups
     fn data_len(&self) -> usize {
+        if let Some(PropPayload::Linked(groups)) = self
+            .props
+            .get_ref(Attribute::Custom(BAR_CHART_GROUPED_DATA))
+            .and_then(|x| x.as_payload())
+        {
+            return groups.len();
+        }
         self.props
             .get(Attribute::Dataset)
             .map(|x| x.unwrap_payload().unwrap_linked().len())
             .unwrap_or(0)
     }
 
+    /// ### truncate_label
+    ///
+    /// Truncate `label` to the width set with `label_truncate`, if any
+    fn truncate_label(&self, label: String) -> String {
+        let Some(width) = self
+            .props
+            .get(Attribute::Custom(BAR_CHART_LABEL_TRUNCATE))
+            .map(|x| x.unwrap_length())
+        else {
+            return label;
+        };
+        if label.width() <= width {
+            return label;
+        }
+        let ellipsis = self
+            .props
+            .get_or(
+                Attribute::Custom(BAR_CHART_LABEL_ELLIPSIS),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag();
+        if ellipsis {
+            return crate::utils::truncate_with_ellipsis(&label, width);
+        }
+        let mut used: usize = 0;
+        label
+            .chars()
+            .take_while(|x| {
+                used += x.width().unwrap_or(0);
+                used <= width
+            })
+            .collect()
+    }
+
+    fn series_color(&self, series: usize) -> Option<Color> {
+        match self
+            .props
+            .get_ref(Attribute::Custom(BAR_CHART_SERIES_COLORS))
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Vec(colors)) => match colors.get(series) {
+                Some(PropValue::Color(color)) => Some(*color),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// ### get_groups
+    ///
+    /// Get the groups of bars to be displayed, starting from provided index at `start` with a max length of `len`.
+    /// Returns `None` if there is no grouped data
+    fn get_groups(&self, start: usize, len: usize) -> Option<Vec<BarGroup<'static>>> {
+        let Some(PropPayload::Linked(groups)) = self
+            .props
+            .get_ref(Attribute::Custom(BAR_CHART_GROUPED_DATA))
+            .and_then(|x| x.as_payload())
+        else {
+            return None;
+        };
+        let groups = groups
+            .iter()
+            .skip(start)
+            .take(len)
+            .enumerate()
+            .filter_map(|(i, group)| match group {
+                PropPayload::Linked(group) => Some((i, group)),
+                _ => None,
+            })
+            .map(|(i, group)| {
+                let label = match group.front() {
+                    Some(PropPayload::One(PropValue::Str(label))) => {
+                        self.truncate_label(label.clone())
+                    }
+                    _ => String::default(),
+                };
+                let bars: Vec<Bar> = group
+                    .iter()
+                    .filter_map(|x| match x {
+                        PropPayload::Tup2((PropValue::Str(label), PropValue::U64(value))) => {
+                            Some((self.truncate_label(label.clone()), *value))
+                        }
+                        _ => None,
+                    })
+                    .enumerate()
+                    .map(|(series, (label, value))| {
+                        let bar = Bar::default().value(value).label(Line::from(label));
+                        match self.series_color(series) {
+                            Some(color) => bar.style(Style::default().fg(color)),
+                            None => bar,
+                        }
+                    })
+                    .collect();
+                // Data starts at cursor, so the cursor group is the first one
+                let label = match i == 0 && !self.is_disabled() {
+                    true => self.highlighted_label(label),
+                    false => Line::from(label),
+                };
+                BarGroup::default().label(label).bars(&bars)
+            })
+            .collect();
+        Some(groups)
+    }
+
+    /// ### highlighted_label
+    ///
+    /// Make the label of the group at the cursor, using the highlighted string and color if set
+    fn highlighted_label(&self, label: String) -> Line<'static> {
+        let label = match self
+            .props
+            .get(Attribute::HighlightedStr)
+            .map(|x| x.unwrap_string())
+        {
+            Some(prefix) => format!("{prefix}{label}"),
+            None => label,
+        };
+        match self
+            .props
+            .get(Attribute::HighlightedColor)
+            .map(|x| x.unwrap_color())
+        {
+            Some(color) => Line::styled(label, Style::default().fg(color)),
+            None => Line::from(label),
+        }
+    }
+
+    /// ### highlighted_bar
+    ///
+    /// Make the bar at the cursor, using the highlighted string and color if set
+    fn highlighted_bar(&self, label: String, value: u64) -> Bar<'static> {
+        let label = match self
+            .props
+            .get(Attribute::HighlightedStr)
+            .map(|x| x.unwrap_string())
+        {
+            Some(prefix) => format!("{prefix}{label}"),
+            None => label,
+        };
+        let bar = Bar::default().value(value).label(Line::from(label));
+        match self
+            .props
+            .get(Attribute::HighlightedColor)
+            .map(|x| x.unwrap_color())
+        {
+            Some(color) => bar.style(Style::default().fg(color)),
+            None => bar,
+        }
+    }
+
     fn get_data(&self, start: usize, len: usize) -> Vec<(String, u64)> {
         if let Some(PropPayload::Linked(list)) = self
             .props
@@ -201,7 +458,7 @@ impl BarChart {
                 }
                 // Push item
                 if let PropPayload::Tup2((PropValue::Str(label), PropValue::U64(value))) = item {
-                    data.push((label.clone(), *value));
+                    data.push((self.truncate_label(label.clone()), *value));
                 }
                 // Break
                 if data.len() >= len {
@@ -252,12 +509,26 @@ impl MockComponent for BarChart {
                 .get(Attribute::Custom(BAR_CHART_MAX_BARS))
                 .map(|x| x.unwrap_length() as u64)
                 .unwrap_or(self.data_len() as u64);
-            // Get data
-            let data = self.get_data(self.states.cursor, data_max_len as usize);
-            let data_ref: Vec<(&str, u64)> = data.iter().map(|x| (x.0.as_str(), x.1)).collect();
             // Create widget
-            let mut widget: TuiBarChart =
-                TuiBarChart::default().block(div).data(data_ref.as_slice());
+            let mut widget: TuiBarChart = TuiBarChart::default().block(div);
+            // Get data
+            if let Some(groups) = self.get_groups(self.states.cursor, data_max_len as usize) {
+                for group in groups {
+                    widget = widget.data(group);
+                }
+            } else {
+                let data = self.get_data(self.states.cursor, data_max_len as usize);
+                // Data starts at cursor, so the cursor bar is the first one
+                let bars: Vec<Bar> = data
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (label, value))| match i == 0 && !self.is_disabled() {
+                        true => self.highlighted_bar(label, value),
+                        false => Bar::default().value(value).label(Line::from(label)),
+                    })
+                    .collect();
+                widget = widget.data(BarGroup::default().bars(&bars));
+            }
             if let Some(gap) = self
                 .props
                 .get(Attribute::Custom(BAR_CHART_BARS_GAP))
@@ -311,6 +582,13 @@ impl MockComponent for BarChart {
                 Cmd::Move(Direction::Right) => {
                     self.states.move_cursor_right(self.data_len());
                 }
+                Cmd::Scroll(Direction::Left) => {
+                    self.states.scroll_cursor_left(self.scroll_step());
+                }
+                Cmd::Scroll(Direction::Right) => {
+                    self.states
+                        .scroll_cursor_right(self.scroll_step(), self.data_len());
+                }
                 Cmd::GoTo(Position::Begin) => {
                     self.states.reset_cursor();
                 }
@@ -334,6 +612,8 @@ mod test {
     use super::*;
 
     use pretty_assertions::assert_eq;
+    use tuirealm::props::{BorderSides, Borders};
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
 
     #[test]
     fn test_components_bar_chart_states() {
@@ -356,6 +636,17 @@ mod test {
         assert_eq!(states.cursor, 2);
         states.reset_cursor();
         assert_eq!(states.cursor, 0);
+        // Scroll
+        states.scroll_cursor_right(8, 10);
+        assert_eq!(states.cursor, 8);
+        states.scroll_cursor_right(8, 10);
+        assert_eq!(states.cursor, 9);
+        states.scroll_cursor_left(8);
+        assert_eq!(states.cursor, 1);
+        states.scroll_cursor_left(8);
+        assert_eq!(states.cursor, 0);
+        states.scroll_cursor_right(8, 0);
+        assert_eq!(states.cursor, 0);
     }
 
     #[test]
@@ -408,4 +699,197 @@ mod test {
         );
         assert_eq!(component.states.cursor, 0);
     }
+
+    #[test]
+    fn test_components_bar_chart_scroll() {
+        let mut component: BarChart = BarChart::default().data(&[
+            ("january", 250),
+            ("february", 300),
+            ("march", 275),
+            ("april", 312),
+            ("may", 420),
+            ("june", 170),
+            ("july", 220),
+            ("august", 160),
+            ("september", 180),
+            ("october", 470),
+        ]);
+        // Default step is 8
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 8);
+        // Clamped at the end
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 9);
+        // Custom step
+        let mut component = component.step(4);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 5);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 1);
+        // Clamped at the beginning
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 0);
+        // Disabled
+        let mut component = component.disabled(true);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 0);
+    }
+
+    #[test]
+    fn test_components_bar_chart_highlighted() {
+        let render = |component: &mut BarChart| {
+            let mut terminal = Terminal::new(TestBackend::new(8, 4)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 8, 4)))
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let mut component = BarChart::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .width(3)
+            .bar_gap(1)
+            .highlighted_str(">")
+            .highlighted_color(Color::Red)
+            .data(&[("a", 10), ("b", 10), ("c", 10)]);
+        component.states.cursor = 1;
+        let buffer = render(&mut component);
+        // Bar at cursor is highlighted
+        assert_eq!(buffer[(0, 1)].fg, Color::Red);
+        assert_eq!(buffer[(4, 1)].fg, Color::Reset);
+        assert_eq!(buffer[(0, 3)].symbol(), ">");
+        assert_eq!(buffer[(1, 3)].symbol(), "b");
+        assert_eq!(buffer[(5, 3)].symbol(), "c");
+        // Not highlighted while disabled
+        let mut component = component.disabled(true);
+        let buffer = render(&mut component);
+        assert_eq!(buffer[(0, 1)].fg, Color::Reset);
+        assert_eq!(buffer[(0, 3)].symbol(), " ");
+        assert_eq!(buffer[(1, 3)].symbol(), "b");
+    }
+
+    #[test]
+    fn test_components_bar_chart_grouped_data() {
+        let mut component = BarChart::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .width(1)
+            .bar_gap(0)
+            .series_colors(&[Color::Green, Color::Red])
+            .grouped_data(&[
+                ("jan", &[("in", 10), ("out", 10)]),
+                ("feb", &[("in", 10), ("out", 10)]),
+                ("mar", &[("in", 10), ("out", 10)]),
+            ]);
+        // Payload shape
+        let groups = component
+            .query(Attribute::Custom(BAR_CHART_GROUPED_DATA))
+            .unwrap()
+            .unwrap_payload()
+            .unwrap_linked();
+        assert_eq!(groups.len(), 3);
+        let group = groups.front().cloned().unwrap().unwrap_linked();
+        assert_eq!(
+            group.into_iter().collect::<Vec<PropPayload>>(),
+            vec![
+                PropPayload::One(PropValue::Str("jan".to_string())),
+                PropPayload::Tup2((PropValue::Str("in".to_string()), PropValue::U64(10))),
+                PropPayload::Tup2((PropValue::Str("out".to_string()), PropValue::U64(10))),
+            ]
+        );
+        // Cursor moves through groups
+        assert_eq!(component.data_len(), 3);
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.cursor, 2);
+        component.perform(Cmd::GoTo(Position::Begin));
+        // Bars are colored by series
+        let mut terminal = Terminal::new(TestBackend::new(4, 4)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 4, 4)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(0, 0)].fg, Color::Green);
+        assert_eq!(buffer[(1, 0)].fg, Color::Red);
+        assert_eq!(buffer[(2, 0)].fg, Color::Green);
+        assert_eq!(buffer[(3, 0)].fg, Color::Red);
+        // Max bars applies to groups
+        let mut component = component.max_bars(1);
+        let mut terminal = Terminal::new(TestBackend::new(4, 4)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 4, 4)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(1, 0)].fg, Color::Red);
+        assert_eq!(buffer[(2, 0)].symbol(), " ");
+    }
+
+    #[test]
+    fn test_components_bar_chart_label_truncate() {
+        let component = BarChart::default().label_truncate(3).data(&[
+            ("january", 250),
+            ("may", 420),
+            ("日本語", 120),
+        ]);
+        assert_eq!(
+            component.get_data(0, 3),
+            vec![
+                (String::from("jan"), 250),
+                (String::from("may"), 420),
+                (String::from("日"), 120),
+            ]
+        );
+        // Abbreviated
+        let component = component.label_ellipsis(true);
+        assert_eq!(
+            component.get_data(0, 3),
+            vec![
+                (String::from("ja…"), 250),
+                (String::from("may"), 420),
+                (String::from("日…"), 120),
+            ]
+        );
+        // Grouped data
+        let mut component = BarChart::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .width(3)
+            .bar_gap(1)
+            .label_truncate(3)
+            .grouped_data(&[
+                ("january", &[("income", 10)]),
+                ("february", &[("income", 10)]),
+            ]);
+        let mut terminal = Terminal::new(TestBackend::new(8, 5)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 8, 5)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..8).map(|x| buffer[(x, y)].symbol()).collect() };
+        assert_eq!(row(3), "inc inc ");
+        assert_eq!(row(4), "jan feb ");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_components_bar_chart_states_serde() {
+        let states = BarChartStates { cursor: 4 };
+        let json = serde_json::to_string(&states).unwrap();
+        let restored: BarChartStates = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.cursor, 4);
+    }
 }
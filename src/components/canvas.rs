@@ -11,16 +11,23 @@ use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::{
     layout::Rect,
     text::Span,
-    widgets::canvas::{Canvas as TuiCanvas, Context, Points},
+    widgets::canvas::{Canvas as TuiCanvas, Context, Line, Map, MapResolution, Points},
 };
 use tuirealm::{Frame, MockComponent, State};
 
 // -- Props
 use super::props::{
-    CANVAS_MARKER, CANVAS_MARKER_BLOCK, CANVAS_MARKER_BRAILLE, CANVAS_MARKER_DOT, CANVAS_X_BOUNDS,
-    CANVAS_Y_BOUNDS,
+    CANVAS_AXES, CANVAS_GRID, CANVAS_MARKER, CANVAS_MARKER_BAR, CANVAS_MARKER_BLOCK,
+    CANVAS_MARKER_BRAILLE, CANVAS_MARKER_DOT, CANVAS_MARKER_HALF_BLOCK, CANVAS_WORLD_MAP,
+    CANVAS_X_BOUNDS, CANVAS_Y_BOUNDS,
 };
 
+/// A closure painting on the canvas `Context`
+pub type CanvasPainter = Box<dyn Fn(&mut Context)>;
+
+/// Amount of cells the grid splits each axis into
+const GRID_DIVISIONS: usize = 4;
+
 // -- Component
 
 /// ## Canvas
@@ -29,6 +36,7 @@ use super::props::{
 #[derive(Default)]
 pub struct Canvas {
     props: Props,
+    painter: Option<CanvasPainter>,
 }
 
 impl Canvas {
@@ -62,6 +70,13 @@ impl Canvas {
         self
     }
 
+    /// Set a closure to paint on the canvas `Context`, after the shapes set with `data()`.
+    /// The closure is called on each frame the canvas is rendered
+    pub fn painter(mut self, painter: CanvasPainter) -> Self {
+        self.painter = Some(painter);
+        self
+    }
+
     /// From <https://github.com/fdehau/tui-rs/issues/286>:
     ///
     /// > Those are used to define the viewport of the canvas.
@@ -96,6 +111,37 @@ impl Canvas {
         self
     }
 
+    /// Draw the world map with `color` as the base layer of the canvas, below shapes and painter.
+    /// When enabled, bounds default to `(-180.0, 180.0)` and `(-90.0, 90.0)`
+    pub fn world_map(mut self, enabled: bool, color: Color) -> Self {
+        self.attr(
+            Attribute::Custom(CANVAS_WORLD_MAP),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::Bool(enabled),
+                PropValue::Color(color),
+            ))),
+        );
+        self
+    }
+
+    /// Draw a grid with `color` below shapes and painter, splitting the bounds of each axis in equal parts
+    pub fn grid(mut self, enabled: bool, color: Color) -> Self {
+        self.attr(
+            Attribute::Custom(CANVAS_GRID),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::Bool(enabled),
+                PropValue::Color(color),
+            ))),
+        );
+        self
+    }
+
+    /// Draw the `x = 0` and `y = 0` axes with the foreground color, if within bounds, below shapes and painter
+    pub fn axes(mut self, enabled: bool) -> Self {
+        self.attr(Attribute::Custom(CANVAS_AXES), AttrValue::Flag(enabled));
+        self
+    }
+
     /// Set marker to use to draw on canvas
     pub fn marker(mut self, marker: Marker) -> Self {
         self.attr(
@@ -107,8 +153,8 @@ impl Canvas {
 
     fn marker_to_prop(marker: Marker) -> AttrValue {
         AttrValue::Number(match marker {
-            Marker::HalfBlock => crate::props::CANVAS_MARKER_HALF_BLOCK,
-            Marker::Bar => crate::props::CANVAS_MARKER_BAR,
+            Marker::HalfBlock => CANVAS_MARKER_HALF_BLOCK,
+            Marker::Bar => CANVAS_MARKER_BAR,
             Marker::Block => CANVAS_MARKER_BLOCK,
             Marker::Braille => CANVAS_MARKER_BRAILLE,
             Marker::Dot => CANVAS_MARKER_DOT,
@@ -124,12 +170,69 @@ impl Canvas {
             )
             .unwrap_number()
         {
+            CANVAS_MARKER_BAR => Marker::Bar,
             CANVAS_MARKER_BLOCK => Marker::Block,
             CANVAS_MARKER_DOT => Marker::Dot,
+            CANVAS_MARKER_HALF_BLOCK => Marker::HalfBlock,
             _ => Marker::Braille,
         }
     }
 
+    /// Get bounds stored in `prop`, or `default` if unset
+    fn prop_to_bounds(&self, prop: &'static str, default: [f64; 2]) -> [f64; 2] {
+        self.props
+            .get(Attribute::Custom(prop))
+            .map(|x| x.unwrap_payload().unwrap_tup2())
+            .map(|(a, b)| [a.unwrap_f64(), b.unwrap_f64()])
+            .unwrap_or(default)
+    }
+
+    /// Get the world map color, if the world map is enabled
+    fn world_map_color(&self) -> Option<Color> {
+        match self
+            .props
+            .get(Attribute::Custom(CANVAS_WORLD_MAP))
+            .map(|x| x.unwrap_payload().unwrap_tup2())
+        {
+            Some((PropValue::Bool(true), PropValue::Color(color))) => Some(color),
+            _ => None,
+        }
+    }
+
+    /// Get the grid color, if the grid is enabled
+    fn grid_color(&self) -> Option<Color> {
+        match self
+            .props
+            .get(Attribute::Custom(CANVAS_GRID))
+            .map(|x| x.unwrap_payload().unwrap_tup2())
+        {
+            Some((PropValue::Bool(true), PropValue::Color(color))) => Some(color),
+            _ => None,
+        }
+    }
+
+    /// Draw the grid lines within the bounds, edges excluded
+    fn draw_grid(ctx: &mut Context, x_bounds: [f64; 2], y_bounds: [f64; 2], color: Color) {
+        let x_step = (x_bounds[1] - x_bounds[0]) / GRID_DIVISIONS as f64;
+        let y_step = (y_bounds[1] - y_bounds[0]) / GRID_DIVISIONS as f64;
+        for i in 1..GRID_DIVISIONS {
+            let x = x_bounds[0] + x_step * i as f64;
+            let y = y_bounds[0] + y_step * i as f64;
+            ctx.draw(&Line::new(x, y_bounds[0], x, y_bounds[1], color));
+            ctx.draw(&Line::new(x_bounds[0], y, x_bounds[1], y, color));
+        }
+    }
+
+    /// Draw the `x = 0` and `y = 0` axes, if they're within bounds
+    fn draw_axes(ctx: &mut Context, x_bounds: [f64; 2], y_bounds: [f64; 2], color: Color) {
+        if x_bounds[0] <= 0.0 && x_bounds[1] >= 0.0 {
+            ctx.draw(&Line::new(0.0, y_bounds[0], 0.0, y_bounds[1], color));
+        }
+        if y_bounds[0] <= 0.0 && y_bounds[1] >= 0.0 {
+            ctx.draw(&Line::new(x_bounds[0], 0.0, x_bounds[1], 0.0, color));
+        }
+    }
+
     /// Draw a shape into the canvas `Context`
     fn draw_shape(ctx: &mut Context, shape: &Shape) {
         match shape {
@@ -172,18 +275,22 @@ impl MockComponent for Canvas {
             let mut block = crate::utils::get_block(borders, title, focus, None);
             block = block.style(Style::default().bg(background).fg(foreground));
             // Get properties
-            let x_bounds: [f64; 2] = self
-                .props
-                .get(Attribute::Custom(CANVAS_X_BOUNDS))
-                .map(|x| x.unwrap_payload().unwrap_tup2())
-                .map(|(a, b)| [a.unwrap_f64(), b.unwrap_f64()])
-                .unwrap_or([0.0, 0.0]);
-            let y_bounds: [f64; 2] = self
+            let world_map = self.world_map_color();
+            let (x_bounds, y_bounds) = match world_map {
+                Some(_) => (
+                    self.prop_to_bounds(CANVAS_X_BOUNDS, [-180.0, 180.0]),
+                    self.prop_to_bounds(CANVAS_Y_BOUNDS, [-90.0, 90.0]),
+                ),
+                None => (
+                    self.prop_to_bounds(CANVAS_X_BOUNDS, [0.0, 1.0]),
+                    self.prop_to_bounds(CANVAS_Y_BOUNDS, [0.0, 1.0]),
+                ),
+            };
+            let grid = self.grid_color();
+            let axes = self
                 .props
-                .get(Attribute::Custom(CANVAS_X_BOUNDS))
-                .map(|x| x.unwrap_payload().unwrap_tup2())
-                .map(|(a, b)| [a.unwrap_f64(), b.unwrap_f64()])
-                .unwrap_or([0.0, 0.0]);
+                .get_or(Attribute::Custom(CANVAS_AXES), AttrValue::Flag(false))
+                .unwrap_flag();
             // Get shapes
             let shapes: Vec<Shape> = self
                 .props
@@ -204,7 +311,28 @@ impl MockComponent for Canvas {
                 .marker(self.prop_to_marker())
                 .x_bounds(x_bounds)
                 .y_bounds(y_bounds)
-                .paint(|ctx| shapes.iter().for_each(|x| Self::draw_shape(ctx, x)));
+                .paint(|ctx| {
+                    if let Some(color) = world_map {
+                        ctx.draw(&Map {
+                            resolution: MapResolution::High,
+                            color,
+                        });
+                        ctx.layer();
+                    }
+                    if let Some(color) = grid {
+                        Self::draw_grid(ctx, x_bounds, y_bounds, color);
+                    }
+                    if axes {
+                        Self::draw_axes(ctx, x_bounds, y_bounds, foreground);
+                    }
+                    if grid.is_some() || axes {
+                        ctx.layer();
+                    }
+                    shapes.iter().for_each(|x| Self::draw_shape(ctx, x));
+                    if let Some(painter) = self.painter.as_ref() {
+                        painter(ctx);
+                    }
+                });
             // Render
             render.render_widget(canvas, area);
         }
@@ -233,7 +361,10 @@ mod test {
     use super::*;
 
     use pretty_assertions::assert_eq;
-    use tuirealm::ratatui::widgets::canvas::{Line, Map, MapResolution, Rectangle};
+    use tuirealm::props::BorderSides;
+    use tuirealm::ratatui::buffer::Buffer;
+    use tuirealm::ratatui::widgets::canvas::{Map, MapResolution, Rectangle};
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
 
     #[test]
     fn test_component_canvas_with_shapes() {
@@ -280,4 +411,154 @@ mod test {
             ]);
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    fn test_component_canvas_with_painter() {
+        let mut component: Canvas = Canvas::default()
+            .borders(Borders::default())
+            .marker(Marker::Block)
+            .x_bounds((0.0, 10.0))
+            .y_bounds((0.0, 10.0))
+            .painter(Box::new(|ctx| {
+                ctx.draw(&Line {
+                    x1: 0.0,
+                    y1: 0.0,
+                    x2: 10.0,
+                    y2: 0.0,
+                    color: Color::Red,
+                })
+            }));
+        let mut terminal = Terminal::new(TestBackend::new(12, 12)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 12)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert!((1..11).all(|x| buffer[(x, 10)].symbol() == "█"));
+        assert!((1..11).all(|x| buffer[(x, 10)].fg == Color::Red));
+        assert_eq!(buffer[(1, 1)].symbol(), " ");
+    }
+
+    #[test]
+    fn test_component_canvas_bounds_and_marker() {
+        let component = Canvas::default();
+        assert_eq!(
+            component.prop_to_bounds(CANVAS_X_BOUNDS, [0.0, 1.0]),
+            [0.0, 1.0]
+        );
+        assert_eq!(component.prop_to_marker(), Marker::Braille);
+        let component = component
+            .x_bounds((-180.0, 180.0))
+            .y_bounds((-90.0, 90.0))
+            .marker(Marker::HalfBlock);
+        assert_eq!(
+            component.prop_to_bounds(CANVAS_X_BOUNDS, [0.0, 1.0]),
+            [-180.0, 180.0]
+        );
+        assert_eq!(
+            component.prop_to_bounds(CANVAS_Y_BOUNDS, [0.0, 1.0]),
+            [-90.0, 90.0]
+        );
+        assert_eq!(component.prop_to_marker(), Marker::HalfBlock);
+        assert_eq!(component.marker(Marker::Bar).prop_to_marker(), Marker::Bar);
+        // Y bounds are applied to the vertical axis
+        let mut component = Canvas::default()
+            .borders(Borders::default())
+            .marker(Marker::Block)
+            .x_bounds((0.0, 10.0))
+            .y_bounds((0.0, 100.0))
+            .painter(Box::new(|ctx| {
+                ctx.draw(&Line {
+                    x1: 0.0,
+                    y1: 100.0,
+                    x2: 10.0,
+                    y2: 100.0,
+                    color: Color::Red,
+                })
+            }));
+        let mut terminal = Terminal::new(TestBackend::new(12, 12)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 12)))
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(1, 1)].symbol(), "█");
+        assert_eq!(terminal.backend().buffer()[(1, 10)].symbol(), " ");
+    }
+
+    #[test]
+    fn test_component_canvas_world_map() {
+        let component = Canvas::default();
+        assert_eq!(component.world_map_color(), None);
+        let component = component.world_map(true, Color::Green);
+        assert_eq!(component.world_map_color(), Some(Color::Green));
+        let mut component = component.world_map(false, Color::Green);
+        assert_eq!(component.world_map_color(), None);
+        // Map is drawn below painter
+        let render = |component: &mut Canvas| {
+            let mut terminal = Terminal::new(TestBackend::new(40, 20)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 40, 20)))
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let buffer = render(&mut component);
+        assert!(buffer.content().iter().all(|x| x.fg != Color::Green));
+        let mut component = component
+            .world_map(true, Color::Green)
+            .painter(Box::new(|ctx| {
+                ctx.draw(&Line {
+                    x1: -180.0,
+                    y1: -90.0,
+                    x2: 180.0,
+                    y2: -90.0,
+                    color: Color::Red,
+                })
+            }));
+        let buffer = render(&mut component);
+        assert!(buffer.content().iter().any(|x| x.fg == Color::Green));
+        // Default bounds for the map make the painter line the bottom row
+        assert!((1..39).all(|x| buffer[(x, 18)].fg == Color::Red));
+    }
+
+    #[test]
+    fn test_component_canvas_grid_and_axes() {
+        let component = Canvas::default();
+        assert_eq!(component.grid_color(), None);
+        let component = component.grid(true, Color::DarkGray);
+        assert_eq!(component.grid_color(), Some(Color::DarkGray));
+        let render = |component: &mut Canvas| {
+            let mut terminal = Terminal::new(TestBackend::new(10, 10)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 10, 10)))
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let filled = |buffer: &Buffer, cells: Vec<(u16, u16)>| -> usize {
+            cells
+                .into_iter()
+                .filter(|(x, y)| buffer[(*x, *y)].symbol() == "█")
+                .count()
+        };
+        let bottom_row = || (0..10).map(|x| (x, 9)).collect::<Vec<_>>();
+        let left_column = || (0..10).map(|y| (0, y)).collect::<Vec<_>>();
+        // Grid splits the bounds in quarters, edges excluded
+        let mut component = component
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .marker(Marker::Block)
+            .x_bounds((0.0, 8.0))
+            .y_bounds((0.0, 8.0));
+        let buffer = render(&mut component);
+        assert_eq!(filled(&buffer, bottom_row()), 3);
+        assert_eq!(filled(&buffer, left_column()), 3);
+        assert_eq!(buffer[(2, 9)].fg, Color::DarkGray);
+        // Axes are drawn at x = 0 and y = 0 with the foreground color
+        let mut component = component.foreground(Color::Red).axes(true);
+        let buffer = render(&mut component);
+        assert_eq!(filled(&buffer, bottom_row()), 10);
+        assert_eq!(filled(&buffer, left_column()), 10);
+        assert_eq!(buffer[(2, 9)].fg, Color::Red);
+        // Axes out of bounds are not drawn
+        let mut component = component.x_bounds((1.0, 9.0)).y_bounds((1.0, 9.0));
+        let buffer = render(&mut component);
+        assert_eq!(filled(&buffer, bottom_row()), 3);
+        assert_eq!(filled(&buffer, left_column()), 3);
+    }
 }
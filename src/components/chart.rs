@@ -5,6 +5,7 @@
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, Dataset, PropPayload, PropValue, Props, Style,
+    TextModifiers,
 };
 use tuirealm::ratatui::text::Line;
 use tuirealm::ratatui::{
@@ -14,22 +15,41 @@ use tuirealm::ratatui::{
 };
 use tuirealm::{Frame, MockComponent, State};
 
+use std::collections::HashSet;
+use unicode_width::UnicodeWidthStr;
+
 // -- Props
 use super::props::{
-    CHART_X_BOUNDS, CHART_X_LABELS, CHART_X_STYLE, CHART_X_TITLE, CHART_Y_BOUNDS, CHART_Y_LABELS,
-    CHART_Y_STYLE, CHART_Y_TITLE,
+    CHART_ANNOTATED_DATASET, CHART_ANNOTATE_CURSOR, CHART_X_BOUNDS, CHART_X_LABELS, CHART_X_STYLE,
+    CHART_X_TITLE, CHART_Y_BOUNDS, CHART_Y_LABELS, CHART_Y_LOG, CHART_Y_STYLE, CHART_Y_TITLE,
 };
 
+/// Value which y values lower or equal than 0 are clamped to, on a logarithmic y axis
+const LOG_EPSILON: f64 = 1e-9;
+
 /// ### ChartStates
 ///
 /// chart states
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChartStates {
     pub cursor: usize,
+    /// Names of the hidden datasets
+    pub hidden: HashSet<String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub data: Vec<Dataset>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub last_area: Option<Rect>, // Area the chart has been last rendered in
 }
 
 impl ChartStates {
+    /// ### last_area
+    ///
+    /// Get the area the component has been last rendered in, if any
+    pub fn last_area(&self) -> Option<Rect> {
+        self.last_area
+    }
+
     /// ### move_cursor_left
     ///
     /// Move cursor to the left
@@ -48,6 +68,22 @@ impl ChartStates {
         }
     }
 
+    /// ### scroll_cursor_left
+    ///
+    /// Move cursor to the left by `step`, stopping at the beginning of the chart
+    pub fn scroll_cursor_left(&mut self, step: usize) {
+        self.cursor = self.cursor.saturating_sub(step);
+    }
+
+    /// ### scroll_cursor_right
+    ///
+    /// Move cursor to the right by `step`, stopping at the end of the chart
+    pub fn scroll_cursor_right(&mut self, step: usize, data_len: usize) {
+        if data_len > 0 {
+            self.cursor = self.cursor.saturating_add(step).min(data_len - 1);
+        }
+    }
+
     /// ### reset_cursor
     ///
     /// Reset cursor to 0
@@ -55,6 +91,15 @@ impl ChartStates {
         self.cursor = 0;
     }
 
+    /// ### toggle_series
+    ///
+    /// Hide the dataset called `name` if visible, show it otherwise
+    pub fn toggle_series(&mut self, name: &str) {
+        if !self.hidden.remove(name) {
+            self.hidden.insert(name.to_string());
+        }
+    }
+
     /// ### cursor_at_end
     ///
     /// Move cursor to the end of the chart
@@ -120,6 +165,12 @@ impl Chart {
         self
     }
 
+    /// Set the amount of entries the cursor is moved by on `Cmd::Scroll` (default 8)
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
     pub fn data(mut self, data: &[Dataset]) -> Self {
         self.props.set(
             Attribute::Dataset,
@@ -204,12 +255,168 @@ impl Chart {
         self
     }
 
+    /// Plot the y values on a logarithmic (base 10) scale. Y bounds are transformed too, while y labels are
+    /// displayed as they are, so they should be set at each power of 10 within the bounds.
+    /// Only the rendering is transformed: values lower or equal than 0 are clamped to a small epsilon
+    pub fn y_log(mut self, log: bool) -> Self {
+        self.props
+            .set(Attribute::Custom(CHART_Y_LOG), AttrValue::Flag(log));
+        self
+    }
+
+    /// In active mode, annotate the `(x, y)` value of the point at the cursor next to it, on the plotted data
+    pub fn annotate_cursor(mut self, annotate: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CHART_ANNOTATE_CURSOR),
+            AttrValue::Flag(annotate),
+        );
+        self
+    }
+
+    /// Set the index of the dataset whose point at the cursor is annotated (default 0)
+    pub fn annotated_dataset(mut self, index: usize) -> Self {
+        self.attr(
+            Attribute::Custom(CHART_ANNOTATED_DATASET),
+            AttrValue::Length(index),
+        );
+        self
+    }
+
+    fn is_cursor_annotated(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(CHART_ANNOTATE_CURSOR),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn get_annotated_dataset(&self) -> usize {
+        self.props
+            .get_or(
+                Attribute::Custom(CHART_ANNOTATED_DATASET),
+                AttrValue::Length(0),
+            )
+            .unwrap_length()
+    }
+
+    fn get_bounds(&self, attr: &'static str) -> Option<(f64, f64)> {
+        match self
+            .props
+            .get(Attribute::Custom(attr))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Tup2((PropValue::F64(floor), PropValue::F64(ceil)))) => {
+                Some((floor, ceil))
+            }
+            _ => None,
+        }
+    }
+
+    fn get_labels(&self, attr: &'static str) -> Vec<String> {
+        match self
+            .props
+            .get(Attribute::Custom(attr))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(labels)) => labels.into_iter().map(|x| x.unwrap_str()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### graph_area
+    ///
+    /// Get the area the data is plotted in, given the area inside the block, leaving room for the axes and their labels
+    /// as ratatui does
+    fn graph_area(&self, inner: Rect) -> Rect {
+        if inner.width == 0 || inner.height == 0 {
+            return inner;
+        }
+        let x_labels = self.get_labels(CHART_X_LABELS);
+        let y_labels = self.get_labels(CHART_Y_LABELS);
+        let mut x = inner.left();
+        let mut y = inner.bottom() - 1;
+        // Labels and axis line of the x axis
+        if !x_labels.is_empty() && y > inner.top() {
+            y -= 1;
+        }
+        if !x_labels.is_empty() && y > inner.top() {
+            y -= 1;
+        }
+        // Labels and axis line of the y axis; the first x label is left aligned to the y axis
+        let labels_width = y_labels.iter().map(|x| x.width()).max().unwrap_or(0).max(
+            x_labels
+                .first()
+                .map(|x| x.width().saturating_sub(usize::from(!y_labels.is_empty())))
+                .unwrap_or(0),
+        ) as u16;
+        x += labels_width.min(inner.width / 3);
+        if !y_labels.is_empty() && x + 1 < inner.right() {
+            x += 1;
+        }
+        Rect::new(x, inner.top(), inner.right() - x, y - inner.top() + 1)
+    }
+
+    /// ### annotate
+    ///
+    /// Draw the value of the point at the cursor of the annotated dataset next to it, keeping the label inside the graph
+    fn annotate(&self, render: &mut Frame, graph: Rect, style: Style) {
+        let (Some(x_bounds), Some(y_bounds)) = (
+            self.get_bounds(CHART_X_BOUNDS),
+            self.get_bounds(CHART_Y_BOUNDS),
+        ) else {
+            return;
+        };
+        let Some((x, y)) = self
+            .props
+            .get(Attribute::Dataset)
+            .and_then(|x| {
+                x.unwrap_payload()
+                    .unwrap_vec()
+                    .into_iter()
+                    .nth(self.get_annotated_dataset())
+            })
+            .map(|x| x.unwrap_dataset())
+            .filter(|x| !self.states.hidden.contains(&x.name))
+            .and_then(|x| x.get_data().get(self.states.cursor).copied())
+        else {
+            return;
+        };
+        let (y_plot, y_bounds) = match self.is_y_log() {
+            true => (log_scale(y), (log_scale(y_bounds.0), log_scale(y_bounds.1))),
+            false => (y, y_bounds),
+        };
+        let Some((col, row)) = screen_position((x, y_plot), x_bounds, y_bounds, graph) else {
+            return;
+        };
+        let label = format!("({x}, {y})");
+        let width = (label.width() as u16).min(graph.width);
+        // Right of the point and one row above, moved back inside the graph if it overflows
+        let label_x = (col + 1).min(graph.right() - width);
+        let label_y = row.saturating_sub(1).max(graph.top());
+        render
+            .buffer_mut()
+            .set_stringn(label_x, label_y, label, width as usize, style);
+    }
+
+    fn is_y_log(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(CHART_Y_LOG), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
     fn is_disabled(&self) -> bool {
         self.props
             .get_or(Attribute::Disabled, AttrValue::Flag(false))
             .unwrap_flag()
     }
 
+    fn scroll_step(&self) -> usize {
+        self.props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length()
+    }
+
     /// ### max_dataset_len
     ///
     /// Get the maximum len among the datasets
@@ -228,10 +435,29 @@ impl Chart {
             .unwrap_or(0)
     }
 
+    /// ### dataset_names
+    ///
+    /// Get the names of the datasets, in order
+    fn dataset_names(&self) -> Vec<String> {
+        self.props
+            .get(Attribute::Dataset)
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_vec()
+                    .into_iter()
+                    .map(|x| x.unwrap_dataset().name)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// ### data
     ///
-    /// Get data to be displayed, starting from provided index at `start` with a max length of `len`
-    fn get_data(&mut self, start: usize, len: usize) -> Vec<TuiDataset> {
+    /// Get data to be displayed, starting from provided index at `start` with a max length of `len`.
+    /// Hidden datasets are skipped.
+    /// If the y axis is logarithmic, the y values of the displayed data are transformed
+    fn get_data(&mut self, start: usize, len: usize) -> Vec<TuiDataset<'_>> {
+        let y_log = self.is_y_log();
         self.states.data = self
             .props
             .get(Attribute::Dataset)
@@ -240,6 +466,18 @@ impl Chart {
                     .unwrap_vec()
                     .into_iter()
                     .map(|x| x.unwrap_dataset())
+                    .filter(|x| !self.states.hidden.contains(&x.name))
+                    .map(|x| match y_log {
+                        true => {
+                            let points = x
+                                .get_data()
+                                .iter()
+                                .map(|(x, y)| (*x, log_scale(*y)))
+                                .collect();
+                            x.data(points)
+                        }
+                        false => x,
+                    })
                     .collect()
             })
             .unwrap_or_default();
@@ -251,6 +489,40 @@ impl Chart {
     }
 }
 
+/// ### screen_position
+///
+/// Get the cell `point` is plotted at in `graph`, given the bounds of the axes, as ratatui does.
+/// Returns `None` if the point is out of bounds
+fn screen_position(
+    point: (f64, f64),
+    x_bounds: (f64, f64),
+    y_bounds: (f64, f64),
+    graph: Rect,
+) -> Option<(u16, u16)> {
+    let (x, y) = point;
+    let width = x_bounds.1 - x_bounds.0;
+    let height = y_bounds.1 - y_bounds.0;
+    if graph.width == 0
+        || graph.height == 0
+        || width <= 0.0
+        || height <= 0.0
+        || !(x_bounds.0..=x_bounds.1).contains(&x)
+        || !(y_bounds.0..=y_bounds.1).contains(&y)
+    {
+        return None;
+    }
+    let col = ((x - x_bounds.0) * f64::from(graph.width - 1) / width) as u16;
+    let row = ((y_bounds.1 - y) * f64::from(graph.height - 1) / height) as u16;
+    Some((graph.x + col, graph.y + row))
+}
+
+/// ### log_scale
+///
+/// Get the position of `value` on a logarithmic axis
+fn log_scale(value: f64) -> f64 {
+    value.max(LOG_EPSILON).log10()
+}
+
 impl<'a> Chart {
     /// ### get_tui_dataset
     ///
@@ -259,9 +531,9 @@ impl<'a> Chart {
     fn get_tui_dataset(dataset: &'a Dataset, start: usize, len: usize) -> TuiDataset<'a> {
         // Recalc len
         let points = dataset.get_data();
-        let end: usize = match points.len() > start {
-            true => std::cmp::min(len, points.len() - start),
-            false => 0,
+        let (start, end): (usize, usize) = match points.len() > start {
+            true => (start, start + std::cmp::min(len, points.len() - start)),
+            false => (0, 0),
         };
 
         // Prepare data storage
@@ -276,6 +548,7 @@ impl<'a> Chart {
 
 impl MockComponent for Chart {
     fn view(&mut self, render: &mut Frame, area: Rect) {
+        self.states.last_area = Some(area);
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
             let foreground = self
                 .props
@@ -345,6 +618,10 @@ impl MockComponent for Chart {
                 .get(Attribute::Custom(CHART_Y_BOUNDS))
                 .map(|x| x.unwrap_payload().unwrap_tup2())
             {
+                let (floor, ceil) = match self.is_y_log() {
+                    true => (log_scale(floor), log_scale(ceil)),
+                    false => (floor, ceil),
+                };
                 let why_using_vecs_when_you_can_use_useless_arrays: [f64; 2] = [floor, ceil];
                 y_axis = y_axis.bounds(why_using_vecs_when_you_can_use_useless_arrays);
             }
@@ -372,12 +649,31 @@ impl MockComponent for Chart {
                     Style::default().fg(foreground).bg(background),
                 ));
             }
+            // Hidden datasets are listed dimmed in the legend, without data
+            let hidden: Vec<String> = self
+                .dataset_names()
+                .into_iter()
+                .filter(|x| self.states.hidden.contains(x))
+                .collect();
             // Get data
-            let data: Vec<TuiDataset> = self.get_data(self.states.cursor, area.width as usize);
+            let mut data: Vec<TuiDataset> = self.get_data(self.states.cursor, area.width as usize);
+            data.extend(hidden.into_iter().map(|name| {
+                TuiDataset::default()
+                    .name(name)
+                    .style(Style::default().add_modifier(TextModifiers::DIM))
+            }));
+            let inner = div.inner(area);
             // Build widget
             let widget: TuiChart = TuiChart::new(data).block(div).x_axis(x_axis).y_axis(y_axis);
             // Render
             render.render_widget(widget, area);
+            if self.is_cursor_annotated() && !self.is_disabled() {
+                self.annotate(
+                    render,
+                    self.graph_area(inner),
+                    Style::default().fg(foreground).bg(background),
+                );
+            }
         }
     }
 
@@ -399,12 +695,29 @@ impl MockComponent for Chart {
                 Cmd::Move(Direction::Right) => {
                     self.states.move_cursor_right(self.max_dataset_len());
                 }
+                Cmd::Scroll(Direction::Left) => {
+                    self.states.scroll_cursor_left(self.scroll_step());
+                }
+                Cmd::Scroll(Direction::Right) => {
+                    self.states
+                        .scroll_cursor_right(self.scroll_step(), self.max_dataset_len());
+                }
                 Cmd::GoTo(Position::Begin) => {
                     self.states.reset_cursor();
                 }
                 Cmd::GoTo(Position::End) => {
                     self.states.cursor_at_end(self.max_dataset_len());
                 }
+                // Number keys toggle the dataset at the same position, starting from 1
+                Cmd::Type(ch) => {
+                    if let Some(name) = ch
+                        .to_digit(10)
+                        .and_then(|x| (x as usize).checked_sub(1))
+                        .and_then(|x| self.dataset_names().into_iter().nth(x))
+                    {
+                        self.states.toggle_series(&name);
+                    }
+                }
                 _ => {}
             }
         }
@@ -422,6 +735,8 @@ mod test {
     use super::*;
 
     use pretty_assertions::assert_eq;
+    use tuirealm::props::BorderSides;
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
     use tuirealm::ratatui::{symbols::Marker, widgets::GraphType};
 
     #[test]
@@ -445,6 +760,22 @@ mod test {
         assert_eq!(states.cursor, 2);
         states.reset_cursor();
         assert_eq!(states.cursor, 0);
+        // Scroll
+        states.scroll_cursor_right(8, 10);
+        assert_eq!(states.cursor, 8);
+        states.scroll_cursor_right(8, 10);
+        assert_eq!(states.cursor, 9);
+        states.scroll_cursor_left(8);
+        assert_eq!(states.cursor, 1);
+        states.scroll_cursor_left(8);
+        assert_eq!(states.cursor, 0);
+        states.scroll_cursor_right(8, 0);
+        assert_eq!(states.cursor, 0);
+        // Toggle series
+        states.toggle_series("temperatures");
+        assert!(states.hidden.contains("temperatures"));
+        states.toggle_series("temperatures");
+        assert!(states.hidden.is_empty());
     }
 
     #[test]
@@ -550,7 +881,7 @@ mod test {
             .marker(Marker::Dot)
             .style(Style::default().fg(Color::LightRed))
             .data(vec![(0.0, 7.0)])]);
-        assert!(comp.get_data(0, 1).len() > 0);
+        assert!(!comp.get_data(0, 1).is_empty());
 
         // Update and test empty data
         component.states.cursor_at_end(12);
@@ -562,4 +893,234 @@ mod test {
         // Cursor is reset
         assert_eq!(component.states.cursor, 0);
     }
+
+    #[test]
+    fn test_components_chart_scroll() {
+        let mut component = Chart::default().data(&[Dataset::default()
+            .name("Maximum")
+            .data((0..10).map(|x| (x as f64, x as f64)).collect())]);
+        // Default step is 8
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 8);
+        // Clamped at the end
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 9);
+        // Custom step; as any attribute, it resets the cursor
+        let mut component = component.step(4);
+        assert_eq!(component.states.cursor, 0);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 4);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 8);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 4);
+        // Clamped at the beginning
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 0);
+        // Disabled
+        let mut component = component.disabled(true);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 0);
+    }
+
+    #[test]
+    fn test_components_chart_cursor_past_half() {
+        let mut component = Chart::default()
+            .x_bounds((0.0, 9.0))
+            .y_bounds((0.0, 9.0))
+            .data(&[Dataset::default()
+                .name("Maximum")
+                .data((0..10).map(|x| (x as f64, x as f64)).collect())]);
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.cursor, 9);
+        // Data from the cursor on is rendered
+        let mut terminal = Terminal::new(TestBackend::new(12, 12)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 12)))
+            .unwrap();
+        assert_ne!(terminal.backend().buffer()[(10, 1)].symbol(), " ");
+        component.perform(Cmd::Move(Direction::Left));
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 12)))
+            .unwrap();
+        assert_ne!(terminal.backend().buffer()[(9, 2)].symbol(), " ");
+    }
+
+    #[test]
+    fn test_components_chart_y_log() {
+        let data = Dataset::default().name("data").data(vec![
+            (0.0, 100.0),
+            (1.0, 1.0),
+            (2.0, 0.0),
+            (3.0, -5.0),
+        ]);
+        let mut component = Chart::default()
+            .y_log(true)
+            .data(std::slice::from_ref(&data));
+        assert_eq!(
+            component.query(Attribute::Custom(CHART_Y_LOG)),
+            Some(AttrValue::Flag(true))
+        );
+        assert_eq!(component.get_data(0, 4).len(), 1);
+        assert_eq!(
+            component.states.data[0].get_data(),
+            &[(0.0, 2.0), (1.0, 0.0), (2.0, -9.0), (3.0, -9.0)]
+        );
+        // Original data is untouched
+        assert_eq!(
+            component
+                .query(Attribute::Dataset)
+                .unwrap()
+                .unwrap_payload()
+                .unwrap_vec()[0]
+                .clone()
+                .unwrap_dataset()
+                .get_data(),
+            data.get_data()
+        );
+        // Linear by default
+        let mut component = Chart::default().data(std::slice::from_ref(&data));
+        component.get_data(0, 4);
+        assert_eq!(component.states.data[0].get_data(), data.get_data());
+    }
+
+    #[test]
+    fn test_components_chart_hidden_series() {
+        let data = [
+            Dataset::default().name("min").data(vec![(0.0, 1.0)]),
+            Dataset::default().name("max").data(vec![(0.0, 9.0)]),
+        ];
+        let mut component = Chart::default().data(&data);
+        assert_eq!(component.get_data(0, 1).len(), 2);
+        // Number keys toggle the datasets by position
+        assert_eq!(component.perform(Cmd::Type('1')), CmdResult::None);
+        assert!(component.states.hidden.contains("min"));
+        let datasets = component.get_data(0, 1);
+        assert_eq!(datasets.len(), 1);
+        assert_eq!(component.states.data[0].name, "max");
+        component.perform(Cmd::Type('3'));
+        component.perform(Cmd::Type('0'));
+        component.perform(Cmd::Type('a'));
+        assert_eq!(component.states.hidden.len(), 1);
+        component.perform(Cmd::Type('1'));
+        assert_eq!(component.get_data(0, 1).len(), 2);
+        // Hidden series are dimmed in the legend
+        component.states.toggle_series("max");
+        let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 60, 20)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let cell = (0..20)
+            .flat_map(|y| (0..57).map(move |x| (x, y)))
+            .find(|(x, y)| {
+                (0..3)
+                    .map(|i| buffer[(x + i, *y)].symbol())
+                    .collect::<String>()
+                    == "max"
+            })
+            .unwrap();
+        assert!(buffer[cell].modifier.contains(TextModifiers::DIM));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_components_chart_states_serde() {
+        let states = ChartStates {
+            cursor: 4,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&states).unwrap();
+        let restored: ChartStates = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.cursor, 4);
+        assert!(restored.hidden.is_empty());
+        // Data is not serialized
+        assert!(restored.data.is_empty());
+    }
+
+    #[test]
+    fn test_components_chart_annotate_cursor() {
+        // Cursor mapping
+        let graph = Rect::new(10, 2, 21, 11);
+        assert_eq!(
+            screen_position((0.0, 0.0), (0.0, 10.0), (0.0, 10.0), graph),
+            Some((10, 12))
+        );
+        assert_eq!(
+            screen_position((10.0, 10.0), (0.0, 10.0), (0.0, 10.0), graph),
+            Some((30, 2))
+        );
+        assert_eq!(
+            screen_position((5.0, 5.0), (0.0, 10.0), (0.0, 10.0), graph),
+            Some((20, 7))
+        );
+        assert_eq!(
+            screen_position((11.0, 5.0), (0.0, 10.0), (0.0, 10.0), graph),
+            None
+        );
+        // Annotation
+        let mut component = Chart::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .x_bounds((0.0, 10.0))
+            .y_bounds((0.0, 10.0))
+            .data(&[Dataset::default().name("data").data(vec![
+                (0.0, 0.0),
+                (5.0, 5.0),
+                (10.0, 10.0),
+            ])])
+            .annotate_cursor(true);
+        assert!(component.is_cursor_annotated());
+        assert_eq!(component.get_annotated_dataset(), 0);
+        assert_eq!(
+            component.graph_area(Rect::new(0, 0, 21, 11)),
+            Rect::new(0, 0, 21, 11)
+        );
+        component.perform(Cmd::Move(Direction::Right));
+        let mut terminal = Terminal::new(TestBackend::new(21, 11)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 21, 11)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let label: String = (11..17).map(|x| buffer[(x, 4)].symbol()).collect();
+        assert_eq!(label, "(5, 5)");
+        // Label is kept inside the graph
+        component.perform(Cmd::GoTo(Position::End));
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 21, 11)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let label: String = (13..21).map(|x| buffer[(x, 0)].symbol()).collect();
+        assert_eq!(label, "(10, 10)");
+        // Labels make room for the axes
+        let component = component
+            .x_labels(&["0", "10"])
+            .y_labels(&["0", "10"])
+            .annotated_dataset(1);
+        assert_eq!(
+            component.graph_area(Rect::new(0, 0, 21, 11)),
+            Rect::new(3, 0, 18, 9)
+        );
+        assert_eq!(component.get_annotated_dataset(), 1);
+    }
 }
@@ -33,12 +33,16 @@ use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::{layout::Rect, text::Span, widgets::Tabs};
 use tuirealm::{Frame, MockComponent, State, StateValue};
 
+// -- Props
+use super::props::{CHECKBOX_REPORT_LABELS, CHECKBOX_STRICT_BOUNDARY};
+
 // -- states
 
 /// ## CheckboxStates
 ///
 /// CheckboxStates contains states for this component
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckboxStates {
     pub choice: usize,         // Selected option
     pub choices: Vec<String>,  // Available choices
@@ -150,11 +154,41 @@ impl Checkbox {
         self
     }
 
+    /// If true, the checkbox group is rendered as inactive and ignores all commands
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    /// If true, choices which don't fit the width are wrapped on the next lines, instead of being clipped
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.attr(Attribute::TextWrap, AttrValue::Flag(wrap));
+        self
+    }
+
     pub fn rewind(mut self, r: bool) -> Self {
         self.attr(Attribute::Rewind, AttrValue::Flag(r));
         self
     }
 
+    /// If true, a move which hits a boundary of the group returns `CmdResult::Changed` with the unchanged state
+    pub fn strict_boundary(mut self, strict: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CHECKBOX_STRICT_BOUNDARY),
+            AttrValue::Flag(strict),
+        );
+        self
+    }
+
+    /// Make the state report the text of the selected choices, instead of their indexes (default `false`)
+    pub fn report_labels(mut self, report: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CHECKBOX_REPORT_LABELS),
+            AttrValue::Flag(report),
+        );
+        self
+    }
+
     pub fn choices<S: AsRef<str>>(mut self, choices: &[S]) -> Self {
         self.attr(
             Attribute::Content,
@@ -179,11 +213,54 @@ impl Checkbox {
         self
     }
 
+    fn is_wrap(&self) -> bool {
+        self.props
+            .get_or(Attribute::TextWrap, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
     fn rewindable(&self) -> bool {
         self.props
             .get_or(Attribute::Rewind, AttrValue::Flag(false))
             .unwrap_flag()
     }
+
+    fn is_report_labels(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(CHECKBOX_REPORT_LABELS),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn is_strict_boundary(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(CHECKBOX_STRICT_BOUNDARY),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    /// ### move_result
+    ///
+    /// Get the result for a move; moves don't change the state, so `None` is returned, unless the
+    /// choice hit a boundary and `strict_boundary` is set
+    fn move_result(&self, prev: usize) -> CmdResult {
+        crate::utils::boundary_result(
+            prev != self.states.choice,
+            self.is_strict_boundary(),
+            self.state(),
+            CmdResult::None,
+        )
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.props
+            .get_or(Attribute::Disabled, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
 }
 
 impl MockComponent for Checkbox {
@@ -202,10 +279,12 @@ impl MockComponent for Checkbox {
                 .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
                 .unwrap_borders();
             let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            // Disabled components are rendered as inactive
             let focus = self
                 .props
                 .get_or(Attribute::Focus, AttrValue::Flag(false))
-                .unwrap_flag();
+                .unwrap_flag()
+                && !self.is_disabled();
             let inactive_style = self
                 .props
                 .get(Attribute::FocusStyle)
@@ -241,10 +320,30 @@ impl MockComponent for Checkbox {
                     ])
                 })
                 .collect();
+            let style = Style::default().fg(block_color);
+            if self.is_wrap() {
+                let inner = div.inner(area);
+                render.render_widget(div.style(style), area);
+                let widths: Vec<usize> = choices.iter().map(|x| x.width()).collect();
+                let lines = crate::utils::wrap_tabs(&widths, inner.width as usize);
+                for (row, range) in lines.into_iter().take(inner.height as usize).enumerate() {
+                    let selected = range
+                        .contains(&self.states.choice)
+                        .then(|| self.states.choice - range.start);
+                    let checkbox: Tabs = Tabs::new(choices[range].to_vec())
+                        .select(selected)
+                        .style(style);
+                    render.render_widget(
+                        checkbox,
+                        Rect::new(inner.x, inner.y + row as u16, inner.width, 1),
+                    );
+                }
+                return;
+            }
             let checkbox: Tabs = Tabs::new(choices)
                 .block(div)
                 .select(self.states.choice)
-                .style(Style::default().fg(block_color));
+                .style(style);
             render.render_widget(checkbox, area);
         }
     }
@@ -289,26 +388,41 @@ impl MockComponent for Checkbox {
     /// Get current state from component
     /// For this component returns the vec of selected items
     fn state(&self) -> State {
-        State::Vec(
-            self.states
-                .selection
-                .iter()
-                .map(|x| StateValue::Usize(*x))
-                .collect(),
-        )
+        match self.is_report_labels() {
+            true => State::Vec(
+                self.states
+                    .selection
+                    .iter()
+                    .filter_map(|x| self.states.choices.get(*x))
+                    .map(|x| StateValue::String(x.clone()))
+                    .collect(),
+            ),
+            false => State::Vec(
+                self.states
+                    .selection
+                    .iter()
+                    .map(|x| StateValue::Usize(*x))
+                    .collect(),
+            ),
+        }
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if self.is_disabled() {
+            return CmdResult::None;
+        }
         match cmd {
             Cmd::Move(Direction::Right) => {
                 // Increment choice
+                let prev = self.states.choice;
                 self.states.next_choice(self.rewindable());
-                CmdResult::None
+                self.move_result(prev)
             }
             Cmd::Move(Direction::Left) => {
                 // Decrement choice
+                let prev = self.states.choice;
                 self.states.prev_choice(self.rewindable());
-                CmdResult::None
+                self.move_result(prev)
             }
             Cmd::Toggle => {
                 self.states.toggle();
@@ -330,6 +444,7 @@ mod test {
 
     use pretty_assertions::{assert_eq, assert_ne};
     use tuirealm::props::{PropPayload, PropValue};
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
 
     #[test]
     fn test_components_checkbox_states() {
@@ -499,4 +614,123 @@ mod test {
             CmdResult::Submit(State::Vec(vec![StateValue::Usize(0)])),
         );
     }
+
+    #[test]
+    fn test_components_checkbox_strict_boundary() {
+        let mut component = Checkbox::default()
+            .choices(&["Pizza", "Hummus"])
+            .values(&[1])
+            .strict_boundary(true);
+        // Hit the left boundary
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::Vec(vec![StateValue::Usize(1)])),
+        );
+        assert_eq!(component.states.choice, 0);
+        // Moving doesn't change the state
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None,
+        );
+        assert_eq!(component.states.choice, 1);
+        // Hit the right boundary
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::Vec(vec![StateValue::Usize(1)])),
+        );
+        assert_eq!(component.states.choice, 1);
+        // With rewind there are no boundaries
+        let mut component = component.rewind(true);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None,
+        );
+        assert_eq!(component.states.choice, 0);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_components_checkbox_states_serde() {
+        let mut states = CheckboxStates::default();
+        states.set_choices(&["lemon".to_string(), "strawberry".to_string()]);
+        states.select(1);
+        let json = serde_json::to_string(&states).unwrap();
+        let restored: CheckboxStates = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.choices, states.choices);
+        assert_eq!(restored.selection, vec![1]);
+    }
+
+    #[test]
+    fn test_components_checkbox_disabled() {
+        let mut component = Checkbox::default()
+            .choices(&["Pizza", "Hummus"])
+            .disabled(true);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.choice, 0);
+        assert_eq!(component.perform(Cmd::Toggle), CmdResult::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(component.state(), State::Vec(vec![]));
+        let mut component = component.disabled(false);
+        assert_eq!(
+            component.perform(Cmd::Toggle),
+            CmdResult::Changed(State::Vec(vec![StateValue::Usize(0)]))
+        );
+    }
+
+    #[test]
+    fn test_components_checkbox_report_labels() {
+        let mut component = Checkbox::default()
+            .choices(&["Pizza", "Hummus", "Ramen"])
+            .values(&[0, 2]);
+        assert_eq!(
+            component.state(),
+            State::Vec(vec![StateValue::Usize(0), StateValue::Usize(2)])
+        );
+        component = component.report_labels(true);
+        assert_eq!(
+            component.state(),
+            State::Vec(vec![
+                StateValue::String(String::from("Pizza")),
+                StateValue::String(String::from("Ramen"))
+            ])
+        );
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(
+            component.perform(Cmd::Toggle),
+            CmdResult::Changed(State::Vec(vec![
+                StateValue::String(String::from("Pizza")),
+                StateValue::String(String::from("Ramen")),
+                StateValue::String(String::from("Hummus"))
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_components_checkbox_wrap() {
+        let mut component = Checkbox::default()
+            .borders(Borders::default())
+            .choices(&["one", "two", "three", "four", "five"])
+            .values(&[3])
+            .wrap(true);
+        // Each choice is 2 columns wider for the checkbox; 5 choices need 3 lines of 20 columns
+        let mut terminal = Terminal::new(TestBackend::new(22, 5)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 22, 5)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (1..21).map(|x| buffer[(x, y)].symbol()).collect() };
+        assert_eq!(row(1).trim_end(), " ☐ one │ ☐ two");
+        assert_eq!(row(2).trim_end(), " ☐ three │ ☑ four");
+        assert_eq!(row(3).trim_end(), " ☐ five");
+        // Without wrap, choices are clipped on a single line
+        let mut component = component.wrap(false);
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 22, 5)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(2, 2)].symbol(), " ");
+    }
 }
@@ -5,11 +5,145 @@
 //! The way it updates properties is usually assigning the attributes to all the children components, but
 //! when defining the component you can override these behaviours implementing `attr()` by yourself.
 //! By default it will forward `Commands' to all the children and will return a `CmdResult::Batch` with all the results.
+//! `Cmd::Move` moves the focus among children instead, towards the nearest child in the given direction;
+//! once a child is focused, commands are forwarded to it only.
+//! When scrollable, children which don't fit vertically can be scrolled into view with `Cmd::Scroll`.
 
-use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::props::{Alignment, AttrValue, Attribute, Borders, Color, Layout, Props};
-use tuirealm::ratatui::layout::Rect;
-use tuirealm::{Frame, MockComponent, State};
+use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, BorderSides, Borders, Color, Layout, PropPayload, PropValue,
+    Props, Style,
+};
+use tuirealm::ratatui::{layout::Rect, widgets::Paragraph};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+// -- Props
+use super::props::{
+    BORDERS_SIDES, CONTAINER_AGGREGATE_STATES, CONTAINER_SCROLL, CONTAINER_SCROLL_STEP,
+    CONTAINER_SECTION_TITLES,
+};
+
+// -- States
+
+#[derive(Default)]
+pub struct ContainerStates {
+    pub focus: Option<usize>,     // Index of the focused child
+    pub offset: usize,            // Index of the first visible row, when scrollable
+    pub content_height: usize,    // Rows taken by children
+    pub height: usize,            // Rows visible in the container area
+    pub areas: Vec<Option<Rect>>, // Area of each child, as last rendered
+}
+
+impl ContainerStates {
+    /// ### set_content_height
+    ///
+    /// Set the rows taken by children and the visible height and fix offset
+    pub fn set_content_height(&mut self, content_height: usize, height: usize) {
+        self.content_height = content_height;
+        self.height = height;
+        self.fix_offset();
+    }
+
+    /// ### scroll_down
+    ///
+    /// Scroll down by `step` rows, without scrolling the last row out of view
+    pub fn scroll_down(&mut self, step: usize) {
+        self.offset = (self.offset + step).min(self.max_offset());
+    }
+
+    /// ### scroll_up
+    ///
+    /// Scroll up by `step` rows
+    pub fn scroll_up(&mut self, step: usize) {
+        self.offset = self.offset.saturating_sub(step);
+    }
+
+    /// ### fix_offset
+    ///
+    /// Keep offset if possible, otherwise set it to the max offset
+    pub fn fix_offset(&mut self) {
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    fn max_offset(&self) -> usize {
+        self.content_height.saturating_sub(self.height)
+    }
+
+    /// ### focus_next
+    ///
+    /// Move focus to the next child; after the last one, focus goes back to the first child
+    pub fn focus_next(&mut self, children: usize) {
+        self.focus = match (self.focus, children) {
+            (_, 0) => None,
+            (Some(i), _) if i + 1 < children => Some(i + 1),
+            _ => Some(0),
+        };
+    }
+
+    /// ### focus_previous
+    ///
+    /// Move focus to the previous child; before the first one, focus goes to the last child
+    pub fn focus_previous(&mut self, children: usize) {
+        self.focus = match (self.focus, children) {
+            (_, 0) => None,
+            (Some(i), _) if i > 0 && i < children => Some(i - 1),
+            _ => Some(children - 1),
+        };
+    }
+
+    /// ### focus_towards
+    ///
+    /// Move focus to the nearest child in `direction`, based on the last rendered areas.
+    /// Children in the same row (or column) are preferred, then the closest ones.
+    /// Falls back to `focus_next` (`Down`, `Right`) and `focus_previous` (`Up`, `Left`)
+    /// when there's no focus, the focused child has no area or no child lies in `direction`
+    pub fn focus_towards(&mut self, children: usize, direction: Direction) {
+        let neighbor = self
+            .focus
+            .and_then(|focus| self.areas.get(focus).copied().flatten())
+            .and_then(|from| {
+                self.areas
+                    .iter()
+                    .take(children)
+                    .enumerate()
+                    .filter_map(|(i, area)| area.map(|area| (i, area)))
+                    .filter(|(i, _)| Some(*i) != self.focus)
+                    .filter_map(|(i, to)| {
+                        Self::spatial_distance(from, to, direction).map(|dist| (dist, i))
+                    })
+                    .min()
+                    .map(|(_, i)| i)
+            });
+        match (neighbor, direction) {
+            (Some(i), _) => self.focus = Some(i),
+            (None, Direction::Down | Direction::Right) => self.focus_next(children),
+            (None, Direction::Up | Direction::Left) => self.focus_previous(children),
+        }
+    }
+
+    /// Distance of `to` from `from` in `direction`, as (not aligned, gap, offset between centers);
+    /// `None` if `to` doesn't lie in `direction`
+    fn spatial_distance(from: Rect, to: Rect, direction: Direction) -> Option<(bool, u16, u16)> {
+        let gap = match direction {
+            Direction::Right if to.left() >= from.right() => to.left() - from.right(),
+            Direction::Left if to.right() <= from.left() => from.left() - to.right(),
+            Direction::Down if to.top() >= from.bottom() => to.top() - from.bottom(),
+            Direction::Up if to.bottom() <= from.top() => from.top() - to.bottom(),
+            _ => return None,
+        };
+        let (aligned, offset) = match direction {
+            Direction::Left | Direction::Right => (
+                to.top() < from.bottom() && from.top() < to.bottom(),
+                (to.top() + to.height / 2).abs_diff(from.top() + from.height / 2),
+            ),
+            Direction::Up | Direction::Down => (
+                to.left() < from.right() && from.left() < to.right(),
+                (to.left() + to.width / 2).abs_diff(from.left() + from.width / 2),
+            ),
+        };
+        Some((!aligned, gap, offset))
+    }
+}
 
 // -- Component
 
@@ -19,6 +153,7 @@ use tuirealm::{Frame, MockComponent, State};
 #[derive(Default)]
 pub struct Container {
     props: Props,
+    pub states: ContainerStates,
     /// Container children
     pub children: Vec<Box<dyn MockComponent>>,
 }
@@ -39,6 +174,16 @@ impl Container {
         self
     }
 
+    /// Set which sides of the block have a border (default all), keeping the other border properties.
+    /// The sides replace the ones of `borders()`, regardless of the order they're set in
+    pub fn borders_sides(mut self, sides: BorderSides) -> Self {
+        self.attr(
+            Attribute::Custom(BORDERS_SIDES),
+            AttrValue::Number(sides.bits() as isize),
+        );
+        self
+    }
+
     pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
         self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
         self
@@ -51,19 +196,204 @@ impl Container {
 
     pub fn children(mut self, children: Vec<Box<dyn MockComponent>>) -> Self {
         self.children = children;
+        self.states.focus = None;
+        self.states.areas.clear();
         self
     }
+
+    /// Make children scrollable with `Cmd::Scroll` when they don't fit vertically in the container
+    pub fn scrollable(mut self, scrollable: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CONTAINER_SCROLL),
+            AttrValue::Flag(scrollable),
+        );
+        self
+    }
+
+    /// Set the rows to scroll by with `Cmd::Scroll` (default 1)
+    pub fn scroll_step(mut self, step: usize) -> Self {
+        self.attr(
+            Attribute::Custom(CONTAINER_SCROLL_STEP),
+            AttrValue::Length(step),
+        );
+        self
+    }
+
+    /// Render a title on the top row of each child's chunk; titles map to chunks by index
+    /// and empty titles draw nothing. Children are rendered below their title
+    pub fn section_titles(mut self, titles: &[&str]) -> Self {
+        self.attr(
+            Attribute::Custom(CONTAINER_SECTION_TITLES),
+            AttrValue::Payload(PropPayload::Vec(
+                titles
+                    .iter()
+                    .map(|x| PropValue::Str(x.to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Make `state()` return the states of all the children, in order, as a `State::Linked`.
+    /// A linked state is used instead of a `State::Vec`, so that children with compound states are kept as they are
+    pub fn aggregate_states(mut self, aggregate: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CONTAINER_AGGREGATE_STATES),
+            AttrValue::Flag(aggregate),
+        );
+        self
+    }
+
+    /// ### push_child
+    ///
+    /// Append a child to the container.
+    /// Children are rendered only if the layout has a constraint for them
+    pub fn push_child(&mut self, child: Box<dyn MockComponent>) {
+        self.children.push(child);
+    }
+
+    /// ### remove_child
+    ///
+    /// Remove the child at `index` and return it, if any.
+    /// If the focused child is removed, focus moves to the child taking its place
+    pub fn remove_child(&mut self, index: usize) -> Option<Box<dyn MockComponent>> {
+        if index >= self.children.len() {
+            return None;
+        }
+        let child = self.children.remove(index);
+        if index < self.states.areas.len() {
+            self.states.areas.remove(index);
+        }
+        match self.states.focus {
+            Some(focus) if focus > index => self.states.focus = Some(focus - 1),
+            Some(focus) if focus == index => {
+                self.states.focus = None;
+                self.move_focus(|states, children| {
+                    states.focus = children.checked_sub(1).map(|last| index.min(last));
+                });
+            }
+            _ => {}
+        }
+        Some(child)
+    }
+
+    /// ### child
+    ///
+    /// Get the child at `index`, if any
+    pub fn child(&self, index: usize) -> Option<&dyn MockComponent> {
+        self.children.get(index).map(|x| x.as_ref())
+    }
+
+    /// ### child_mut
+    ///
+    /// Get the child at `index` as mutable, if any
+    pub fn child_mut(&mut self, index: usize) -> Option<&mut dyn MockComponent> {
+        self.children
+            .get_mut(index)
+            .map(|x| x.as_mut() as &mut dyn MockComponent)
+    }
+
+    /// ### query_child
+    ///
+    /// Query attribute of the child at `index`; returns `None` if the child doesn't exist
+    pub fn query_child(&self, index: usize, attr: Attribute) -> Option<AttrValue> {
+        self.child(index).and_then(|x| x.query(attr))
+    }
+
+    /// ### attr_child
+    ///
+    /// Set attribute on the child at `index` only; nothing happens if the child doesn't exist
+    pub fn attr_child(&mut self, index: usize, attr: Attribute, value: AttrValue) {
+        if let Some(child) = self.child_mut(index) {
+            child.attr(attr, value);
+        }
+    }
+
+    /// ### state_child
+    ///
+    /// Get the state of the child at `index`; returns `State::None` if the child doesn't exist
+    pub fn state_child(&self, index: usize) -> State {
+        self.child(index).map(|x| x.state()).unwrap_or(State::None)
+    }
+
+    fn is_scrollable(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(CONTAINER_SCROLL), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    /// ### scrolled_chunks
+    ///
+    /// Lay out children in a virtual area, tall enough to fit all the children with a fixed height,
+    /// then shift chunks by the scroll offset. Chunks outside of `area` are `None`, while
+    /// the ones partially visible are cut to the visible rows.
+    fn scrolled_chunks(&mut self, layout: &Layout, area: Rect) -> Vec<Option<Rect>> {
+        // Chunks with the same height in two tall areas have a fixed height
+        let tall = layout.chunks(Rect::new(area.x, 0, area.width, 10_000));
+        let taller = layout.chunks(Rect::new(area.x, 0, area.width, 20_000));
+        let margin = tall.first().map(|x| x.y).unwrap_or(0);
+        let fixed: u16 = tall
+            .iter()
+            .zip(taller.iter())
+            .filter(|(a, b)| a.height == b.height)
+            .map(|(a, _)| a.height)
+            .sum();
+        let content_height = (fixed + margin * 2).max(area.height);
+        self.states
+            .set_content_height(content_height as usize, area.height as usize);
+        let offset = self.states.offset as u16;
+        layout
+            .chunks(Rect::new(area.x, 0, area.width, content_height))
+            .into_iter()
+            .map(|chunk| {
+                let top = chunk.top().max(offset);
+                let bottom = chunk.bottom().min(offset + area.height);
+                (bottom > top)
+                    .then(|| Rect::new(chunk.x, area.y + top - offset, chunk.width, bottom - top))
+            })
+            .collect()
+    }
+
+    fn section_titles_list(&self) -> Vec<String> {
+        match self
+            .props
+            .get(Attribute::Custom(CONTAINER_SECTION_TITLES))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(titles)) => titles.into_iter().map(|x| x.unwrap_str()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### move_focus
+    ///
+    /// Move focus with `f` and patch the `Focus` attribute of children
+    fn move_focus<F>(&mut self, f: F) -> CmdResult
+    where
+        F: FnOnce(&mut ContainerStates, usize),
+    {
+        let prev = self.states.focus;
+        f(&mut self.states, self.children.len());
+        match self.states.focus {
+            Some(focus) if prev != Some(focus) => {
+                self.children
+                    .iter_mut()
+                    .enumerate()
+                    .for_each(|(i, x)| x.attr(Attribute::Focus, AttrValue::Flag(i == focus)));
+                CmdResult::Changed(State::One(StateValue::Usize(focus)))
+            }
+            _ => CmdResult::None,
+        }
+    }
 }
 
 impl MockComponent for Container {
     fn view(&mut self, render: &mut Frame, area: Rect) {
+        self.states.areas = vec![None; self.children.len()];
         // Make a Span
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
             // Make block
-            let borders = self
-                .props
-                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
-                .unwrap_borders();
+            let borders = crate::utils::get_borders(&self.props);
             let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
             let div = crate::utils::get_block(borders, title, true, None);
             // Render block
@@ -71,10 +401,39 @@ impl MockComponent for Container {
             // Render children
             if let Some(layout) = self.props.get(Attribute::Layout).map(|x| x.unwrap_layout()) {
                 // make chunks
-                let chunks = layout.chunks(area);
+                let chunks: Vec<Option<Rect>> = match self.is_scrollable() {
+                    true => self.scrolled_chunks(&layout, area),
+                    false => layout.chunks(area).into_iter().map(Some).collect(),
+                };
+                // Section titles take the top row of chunks
+                let titles = self.section_titles_list();
+                let style = Style::default()
+                    .fg(self
+                        .props
+                        .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                        .unwrap_color())
+                    .bg(self
+                        .props
+                        .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                        .unwrap_color());
                 // iter chunks
                 for (i, chunk) in chunks.into_iter().enumerate() {
-                    if let Some(child) = self.children.get_mut(i) {
+                    let chunk = match (chunk, titles.get(i)) {
+                        (Some(chunk), Some(title)) if !title.is_empty() && chunk.height > 0 => {
+                            render.render_widget(
+                                Paragraph::new(title.as_str()).style(style),
+                                Rect { height: 1, ..chunk },
+                            );
+                            Some(Rect {
+                                y: chunk.y + 1,
+                                height: chunk.height - 1,
+                                ..chunk
+                            })
+                        }
+                        (chunk, _) => chunk,
+                    };
+                    if let (Some(child), Some(chunk)) = (self.children.get_mut(i), chunk) {
+                        self.states.areas[i] = Some(chunk);
                         child.view(render, chunk);
                     }
                 }
@@ -88,19 +447,65 @@ impl MockComponent for Container {
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
         self.props.set(attr, value.clone());
-        // Patch attribute to children
-        self.children
-            .iter_mut()
-            .for_each(|x| x.attr(attr, value.clone()));
+        match (attr, self.states.focus) {
+            // Only the focused child can get focus
+            (Attribute::Focus, Some(focus)) => {
+                let focused = value.unwrap_flag();
+                self.children.iter_mut().enumerate().for_each(|(i, x)| {
+                    x.attr(Attribute::Focus, AttrValue::Flag(focused && i == focus))
+                });
+            }
+            // Patch attribute to children
+            _ => self
+                .children
+                .iter_mut()
+                .for_each(|x| x.attr(attr, value.clone())),
+        }
     }
 
     fn state(&self) -> State {
-        State::None
+        match self
+            .props
+            .get_or(
+                Attribute::Custom(CONTAINER_AGGREGATE_STATES),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+        {
+            true => State::Linked(self.children.iter().map(|x| x.state()).collect()),
+            false => State::None,
+        }
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
-        // Send command to children and return batch
-        CmdResult::Batch(self.children.iter_mut().map(|x| x.perform(cmd)).collect())
+        match cmd {
+            Cmd::Move(direction) => {
+                self.move_focus(|states, children| states.focus_towards(children, direction))
+            }
+            Cmd::Scroll(direction) if self.is_scrollable() => {
+                let step = self
+                    .props
+                    .get_or(
+                        Attribute::Custom(CONTAINER_SCROLL_STEP),
+                        AttrValue::Length(1),
+                    )
+                    .unwrap_length();
+                match direction {
+                    Direction::Down => self.states.scroll_down(step),
+                    Direction::Up => self.states.scroll_up(step),
+                    _ => {}
+                }
+                CmdResult::None
+            }
+            // Send command to the focused child
+            _ => match self.states.focus.and_then(|i| self.children.get_mut(i)) {
+                Some(child) => child.perform(cmd),
+                // Send command to children and return batch
+                None => {
+                    CmdResult::Batch(self.children.iter_mut().map(|x| x.perform(cmd)).collect())
+                }
+            },
+        }
     }
 }
 
@@ -108,8 +513,13 @@ impl MockComponent for Container {
 mod tests {
 
     use super::*;
+    use crate::{Input, Label};
 
     use pretty_assertions::assert_eq;
+    use tuirealm::props::BorderSides;
+    use tuirealm::ratatui::buffer::Buffer;
+    use tuirealm::ratatui::layout::{Constraint, Direction as LayoutDirection};
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
 
     #[test]
     fn test_components_paragraph() {
@@ -120,4 +530,465 @@ mod tests {
         // Get value
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    fn test_components_container_children() {
+        let mut component = Container::default().children(vec![
+            Box::new(Input::default().value("foo")),
+            Box::new(Input::default().value("bar")),
+        ]);
+        assert!(component.child(1).is_some());
+        assert!(component.child(2).is_none());
+        assert_eq!(
+            component.query_child(0, Attribute::Value),
+            Some(AttrValue::String(String::from("foo")))
+        );
+        assert_eq!(component.query_child(2, Attribute::Value), None);
+        // Set attribute on a single child
+        component.attr_child(1, Attribute::Value, AttrValue::String(String::from("baz")));
+        component.attr_child(5, Attribute::Value, AttrValue::String(String::from("baz")));
+        assert_eq!(
+            component.state_child(1),
+            State::One(StateValue::String(String::from("baz")))
+        );
+        assert_eq!(
+            component.state_child(0),
+            State::One(StateValue::String(String::from("foo")))
+        );
+        assert_eq!(component.state_child(2), State::None);
+        if let Some(child) = component.child_mut(0) {
+            child.perform(Cmd::Type('d'));
+        }
+        assert_eq!(
+            component.state_child(0),
+            State::One(StateValue::String(String::from("food")))
+        );
+        // Aggregate states
+        assert_eq!(component.state(), State::None);
+        let component = component.aggregate_states(true);
+        assert_eq!(
+            component.state(),
+            State::Linked(
+                vec![
+                    State::One(StateValue::String(String::from("food"))),
+                    State::One(StateValue::String(String::from("baz"))),
+                ]
+                .into_iter()
+                .collect()
+            )
+        );
+        assert_eq!(
+            Container::default().aggregate_states(true).state(),
+            State::Linked(Default::default())
+        );
+    }
+
+    #[test]
+    fn test_components_container_scroll() {
+        let mut component = Container::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .layout(
+                Layout::default()
+                    .direction(LayoutDirection::Vertical)
+                    .constraints(&[
+                        Constraint::Length(3),
+                        Constraint::Length(3),
+                        Constraint::Length(3),
+                    ]),
+            )
+            .scrollable(true)
+            .scroll_step(2)
+            .children(vec![
+                Box::new(Label::default().text("first")),
+                Box::new(Label::default().text("second")),
+                Box::new(Label::default().text("third")),
+            ]);
+        let mut terminal = Terminal::new(TestBackend::new(10, 5)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+            .unwrap();
+        assert_eq!(component.states.content_height, 9);
+        assert_eq!(component.states.height, 5);
+        let row = |terminal: &Terminal<TestBackend>, y: u16| -> String {
+            let buffer = terminal.backend().buffer();
+            (0..10).map(|x| buffer[(x, y)].symbol()).collect()
+        };
+        assert_eq!(row(&terminal, 0), "first     ");
+        assert_eq!(row(&terminal, 3), "second    ");
+        // Scroll down
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.offset, 2);
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+            .unwrap();
+        // Partially visible children are rendered in their visible rows
+        assert_eq!(row(&terminal, 0), "first     ");
+        assert_eq!(row(&terminal, 1), "second    ");
+        assert_eq!(row(&terminal, 4), "third     ");
+        // Last child can't scroll out of view
+        component.perform(Cmd::Scroll(Direction::Down));
+        component.perform(Cmd::Scroll(Direction::Down));
+        assert_eq!(component.states.offset, 4);
+        component.perform(Cmd::Scroll(Direction::Up));
+        assert_eq!(component.states.offset, 2);
+        // A taller area fixes the offset
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+            .unwrap();
+        let mut terminal = Terminal::new(TestBackend::new(10, 12)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 12)))
+            .unwrap();
+        assert_eq!(component.states.offset, 0);
+        assert_eq!(component.states.content_height, 12);
+        // Not scrollable: commands are forwarded to children
+        let mut component = component.scrollable(false);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::Batch(vec![CmdResult::None; 3])
+        );
+    }
+
+    #[test]
+    fn test_components_container_push_remove_child() {
+        let mut component = Container::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .layout(
+                Layout::default()
+                    .direction(LayoutDirection::Vertical)
+                    .constraints(&[Constraint::Length(1), Constraint::Length(1)]),
+            )
+            .children(vec![Box::new(Label::default().text("first"))]);
+        component.push_child(Box::new(Label::default().text("second")));
+        assert_eq!(component.children.len(), 2);
+        let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 2)))
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        assert_eq!(
+            (0..6).map(|x| buffer[(x, 1)].symbol()).collect::<String>(),
+            "second"
+        );
+        // Remove
+        assert!(component.remove_child(2).is_none());
+        assert!(component.remove_child(0).is_some());
+        assert_eq!(component.children.len(), 1);
+        assert_eq!(
+            component.query_child(0, Attribute::Text),
+            Some(AttrValue::String(String::from("second")))
+        );
+    }
+
+    #[test]
+    fn test_components_container_remove_focused_child() {
+        let mut component = Container::default().children(vec![
+            Box::new(Input::default()),
+            Box::new(Input::default()),
+            Box::new(Input::default()),
+        ]);
+        // Focus the last child, then remove a child before it
+        component.perform(Cmd::Move(Direction::Up));
+        assert_eq!(component.states.focus, Some(2));
+        component.remove_child(0);
+        assert_eq!(component.states.focus, Some(1));
+        // Remove focused child: focus moves to the child taking its place
+        component.push_child(Box::new(Input::default()));
+        component.remove_child(1);
+        assert_eq!(component.states.focus, Some(1));
+        assert_eq!(
+            component.query_child(1, Attribute::Focus),
+            Some(AttrValue::Flag(true))
+        );
+        // Remove focused last child
+        component.remove_child(1);
+        assert_eq!(component.states.focus, Some(0));
+        assert_eq!(
+            component.query_child(0, Attribute::Focus),
+            Some(AttrValue::Flag(true))
+        );
+        component.remove_child(0);
+        assert_eq!(component.states.focus, None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::Batch(vec![]));
+    }
+
+    #[test]
+    fn test_components_container_section_titles() {
+        let render = |direction: LayoutDirection| -> Buffer {
+            let mut component = Container::default()
+                .borders(Borders::default().sides(BorderSides::NONE))
+                .foreground(Color::Yellow)
+                .layout(
+                    Layout::default()
+                        .direction(direction)
+                        .constraints(&[Constraint::Length(5), Constraint::Length(5)]),
+                )
+                .section_titles(&["one"])
+                .children(vec![
+                    Box::new(Label::default().text("a")),
+                    Box::new(Label::default().text("b")),
+                ]);
+            let mut terminal = Terminal::new(TestBackend::new(10, 10)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 10, 10)))
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        // Vertical
+        let buffer = render(LayoutDirection::Vertical);
+        assert_eq!(buffer[(0, 0)].symbol(), "o");
+        assert_eq!(buffer[(0, 0)].fg, Color::Yellow);
+        assert_eq!(buffer[(0, 1)].symbol(), "a");
+        // Missing title draws nothing
+        assert_eq!(buffer[(0, 5)].symbol(), "b");
+        // Horizontal
+        let buffer = render(LayoutDirection::Horizontal);
+        assert_eq!(buffer[(0, 0)].symbol(), "o");
+        assert_eq!(buffer[(0, 1)].symbol(), "a");
+        assert_eq!(buffer[(5, 0)].symbol(), "b");
+    }
+
+    #[test]
+    fn test_components_container_focus() {
+        let mut component = Container::default().children(vec![
+            Box::new(Input::default()),
+            Box::new(Input::default()),
+            Box::new(Input::default()),
+        ]);
+        // Without focus, commands are sent to all children
+        assert_eq!(
+            component.perform(Cmd::Type('a')),
+            CmdResult::Batch(vec![
+                CmdResult::Changed(State::One(StateValue::String(
+                    String::from("a")
+                )));
+                3
+            ])
+        );
+        // Move focus
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(component.states.focus, Some(1));
+        assert_eq!(
+            component.children[1].query(Attribute::Focus),
+            Some(AttrValue::Flag(true))
+        );
+        assert_eq!(
+            component.children[0].query(Attribute::Focus),
+            Some(AttrValue::Flag(false))
+        );
+        // Commands are forwarded to the focused child only
+        assert_eq!(
+            component.perform(Cmd::Type('b')),
+            CmdResult::Changed(State::One(StateValue::String(String::from("ab"))))
+        );
+        assert_eq!(
+            component.children[0].state(),
+            State::One(StateValue::String(String::from("a")))
+        );
+        // Cycle
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        // Container losing and getting focus
+        component.attr(Attribute::Focus, AttrValue::Flag(false));
+        assert_eq!(
+            component.children[1].query(Attribute::Focus),
+            Some(AttrValue::Flag(false))
+        );
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        assert_eq!(
+            component.children[1].query(Attribute::Focus),
+            Some(AttrValue::Flag(true))
+        );
+        assert_eq!(
+            component.children[2].query(Attribute::Focus),
+            Some(AttrValue::Flag(false))
+        );
+    }
+
+    #[test]
+    fn test_components_container_spatial_focus() {
+        let mut component = Container::default().children(vec![
+            Box::new(Input::default()),
+            Box::new(Input::default()),
+            Box::new(Input::default()),
+            Box::new(Input::default()),
+        ]);
+        // 2x2 grid: 0 1 / 2 3
+        component.states.areas = vec![
+            Some(Rect::new(0, 0, 10, 3)),
+            Some(Rect::new(10, 0, 10, 3)),
+            Some(Rect::new(0, 3, 10, 3)),
+            Some(Rect::new(10, 3, 10, 3)),
+        ];
+        // Without focus, cycle
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(3)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(3)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.children[0].query(Attribute::Focus),
+            Some(AttrValue::Flag(true))
+        );
+        // No child in direction: cycle
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(3)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        // Aligned children are preferred over closer ones
+        component.states.areas[1] = Some(Rect::new(10, 1, 2, 1));
+        component.states.areas[2] = Some(Rect::new(20, 0, 10, 3));
+        component.states.areas[3] = Some(Rect::new(10, 5, 10, 3));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        component.states.areas[1] = Some(Rect::new(12, 3, 2, 1));
+        component.perform(Cmd::Move(Direction::Left));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+    }
+
+    #[test]
+    fn test_components_container_spatial_focus_view() {
+        let mut component = Container::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .layout(
+                Layout::default()
+                    .direction(LayoutDirection::Horizontal)
+                    .constraints(&[Constraint::Length(5), Constraint::Length(5)]),
+            )
+            .children(vec![
+                Box::new(Input::default()),
+                Box::new(Input::default()),
+                Box::new(Input::default()),
+            ]);
+        let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 3)))
+            .unwrap();
+        // Children without a chunk have no area
+        assert_eq!(
+            component.states.areas,
+            vec![
+                Some(Rect::new(0, 0, 5, 3)),
+                Some(Rect::new(5, 0, 5, 3)),
+                None
+            ]
+        );
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        // Nothing below: cycle
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        // Removing a child removes its area
+        component.remove_child(0);
+        assert_eq!(
+            component.states.areas,
+            vec![Some(Rect::new(5, 0, 5, 3)), None]
+        );
+    }
+
+    #[test]
+    fn test_components_container_focus_empty_and_single() {
+        let mut component = Container::default();
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        assert_eq!(component.perform(Cmd::Move(Direction::Up)), CmdResult::None);
+        assert_eq!(component.states.focus, None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::Batch(vec![]));
+        // Single child
+        let mut component = Container::default().children(vec![Box::new(Input::default())]);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.focus, Some(0));
+        assert_eq!(
+            component.perform(Cmd::Type('a')),
+            CmdResult::Changed(State::One(StateValue::String(String::from("a"))))
+        );
+    }
+
+    #[test]
+    fn test_components_container_borders_sides() {
+        let component = Container::default().borders_sides(BorderSides::NONE);
+        assert_eq!(
+            crate::utils::get_borders(&component.props).sides,
+            BorderSides::NONE
+        );
+    }
 }
@@ -3,21 +3,39 @@
 //! `Input` represents a read-write input field. This component supports different input types, input length
 //! and handles input events related to cursor position, backspace, canc, ...
 
-use super::props::{INPUT_INVALID_STYLE, INPUT_PLACEHOLDER, INPUT_PLACEHOLDER_STYLE};
+use super::props::{
+    INPUT_COMPLETIONS, INPUT_CURSOR_STYLE, INPUT_DEBOUNCE, INPUT_ERROR_MESSAGE, INPUT_GHOST_SUFFIX,
+    INPUT_GHOST_SUFFIX_STYLE, INPUT_INVALID_STYLE, INPUT_MASK_EXCEPT_LAST, INPUT_PLACEHOLDER,
+    INPUT_PLACEHOLDER_STYLE, INPUT_REPORT_CURSOR, INPUT_REPORT_ON_VALUE_CHANGE, INPUT_STEPPER,
+    INPUT_SUGGESTIONS, INPUT_WORD_BOUNDARIES,
+};
 use crate::utils::calc_utf8_cursor_position;
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
-    Alignment, AttrValue, Attribute, Borders, Color, InputType, Props, Style, TextModifiers,
+    Alignment, AttrValue, Attribute, Borders, Color, InputType, PropPayload, PropValue, Props,
+    Style, TextModifiers,
+};
+use tuirealm::ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Clear, List as TuiList, ListItem, ListState, Paragraph},
 };
-use tuirealm::ratatui::{layout::Rect, widgets::Paragraph};
 use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use std::time::{Duration, Instant};
+
+use crate::utils::Theme;
 
 // -- states
 
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InputStates {
     pub input: Vec<char>, // Current input
     pub cursor: usize,    // Input position
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub suggestion: Option<usize>, // Index of the highlighted suggestion among the matching ones
 }
 
 impl InputStates {
@@ -87,6 +105,69 @@ impl InputStates {
         }
     }
 
+    /// ### word_left
+    ///
+    /// Get the position of the start of the word before the cursor, skipping the boundaries right before it.
+    /// Whitespaces and `boundaries` separate words
+    pub fn word_left(&self, boundaries: &[char]) -> usize {
+        let is_boundary = |x: &char| x.is_whitespace() || boundaries.contains(x);
+        let before = &self.input[..self.cursor];
+        let end = before
+            .iter()
+            .rposition(|x| !is_boundary(x))
+            .map(|x| x + 1)
+            .unwrap_or(0);
+        before[..end]
+            .iter()
+            .rposition(is_boundary)
+            .map(|x| x + 1)
+            .unwrap_or(0)
+    }
+
+    /// ### word_right
+    ///
+    /// Get the position of the end of the word after the cursor, skipping the boundaries right after it.
+    /// Whitespaces and `boundaries` separate words
+    pub fn word_right(&self, boundaries: &[char]) -> usize {
+        let is_boundary = |x: &char| x.is_whitespace() || boundaries.contains(x);
+        let after = &self.input[self.cursor..];
+        let start = after
+            .iter()
+            .position(|x| !is_boundary(x))
+            .unwrap_or(after.len());
+        self.cursor
+            + start
+            + after[start..]
+                .iter()
+                .position(is_boundary)
+                .unwrap_or(after.len() - start)
+    }
+
+    /// ### delete_word
+    ///
+    /// Delete the word before the cursor, along with the boundaries between it and the cursor
+    pub fn delete_word(&mut self, boundaries: &[char]) {
+        let start = self.word_left(boundaries);
+        self.input.drain(start..self.cursor);
+        self.cursor = start;
+    }
+
+    /// ### set_value
+    ///
+    /// Replace the input with `s`, without validating it, and place the cursor at the end
+    pub fn set_value(&mut self, s: &str) {
+        self.input = s.chars().collect();
+        self.cursor_at_end();
+    }
+
+    /// ### clear
+    ///
+    /// Clear the input and place the cursor at the begin
+    pub fn clear(&mut self) {
+        self.input.clear();
+        self.cursor_at_begin();
+    }
+
     /// ### render_value
     ///
     /// Get value as string to render
@@ -114,8 +195,74 @@ impl InputStates {
     }
 }
 
+/// Maximum amount of suggestions visible at once in the popup
+const SUGGESTIONS_MAX_ROWS: usize = 5;
+
+// -- cursor style
+
+/// ## CursorStyle
+///
+/// Shape of the terminal cursor while the input is focused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorStyle {
+    /// Shape configured by the user in the terminal
+    Default = 0,
+    BlinkingBlock = 1,
+    SteadyBlock = 2,
+    BlinkingUnderline = 3,
+    SteadyUnderline = 4,
+    BlinkingBar = 5,
+    SteadyBar = 6,
+}
+
+impl CursorStyle {
+    /// ### escape_sequence
+    ///
+    /// Get the `DECSCUSR` escape sequence which sets this cursor shape
+    pub fn escape_sequence(&self) -> String {
+        format!("\x1b[{} q", *self as u8)
+    }
+
+    fn from_code(code: isize) -> Self {
+        match code {
+            1 => Self::BlinkingBlock,
+            2 => Self::SteadyBlock,
+            3 => Self::BlinkingUnderline,
+            4 => Self::SteadyUnderline,
+            5 => Self::BlinkingBar,
+            6 => Self::SteadyBar,
+            _ => Self::Default,
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl From<CursorStyle> for tuirealm::ratatui::crossterm::cursor::SetCursorStyle {
+    fn from(style: CursorStyle) -> Self {
+        match style {
+            CursorStyle::Default => Self::DefaultUserShape,
+            CursorStyle::BlinkingBlock => Self::BlinkingBlock,
+            CursorStyle::SteadyBlock => Self::SteadyBlock,
+            CursorStyle::BlinkingUnderline => Self::BlinkingUnderScore,
+            CursorStyle::SteadyUnderline => Self::SteadyUnderScore,
+            CursorStyle::BlinkingBar => Self::BlinkingBar,
+            CursorStyle::SteadyBar => Self::SteadyBar,
+        }
+    }
+}
+
 // -- Component
 
+/// Completion being cycled through with `Cmd::Change`
+struct Completion {
+    /// Index of the first char of the completed token
+    start: usize,
+    /// Token typed by the user
+    prefix: String,
+    /// Index of the current completion among the matching ones
+    index: usize,
+}
+
 /// ## Input
 ///
 /// Input list component
@@ -123,9 +270,22 @@ impl InputStates {
 pub struct Input {
     props: Props,
     pub states: InputStates,
+    /// Completion being cycled through, if any
+    completion: Option<Completion>,
+    /// When the input has last been changed by an edit, if debounced
+    last_edit: Option<Instant>,
+    /// Whether a `Changed` has been held back by the debounce and not reported yet
+    pending_change: bool,
 }
 
 impl Input {
+    /// Custom command deleting the word before the cursor (e.g. bound to Ctrl+W)
+    pub const CMD_DELETE_WORD: &'static str = "delete-word";
+    /// Custom command moving the cursor to the start of the previous word (e.g. bound to Ctrl+Left)
+    pub const CMD_WORD_LEFT: &'static str = "word-left";
+    /// Custom command moving the cursor to the end of the next word (e.g. bound to Ctrl+Right)
+    pub const CMD_WORD_RIGHT: &'static str = "word-right";
+
     pub fn foreground(mut self, fg: Color) -> Self {
         self.attr(Attribute::Foreground, AttrValue::Color(fg));
         self
@@ -136,11 +296,30 @@ impl Input {
         self
     }
 
+    /// Set colors, borders and styles from `theme`
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(theme.foreground));
+        self.attr(Attribute::Background, AttrValue::Color(theme.background));
+        self.attr(Attribute::Borders, AttrValue::Borders(theme.borders()));
+        self.attr(
+            Attribute::TextProps,
+            AttrValue::TextModifiers(theme.modifiers),
+        );
+        self.attr(Attribute::FocusStyle, AttrValue::Style(theme.inactive));
+        self
+    }
+
     pub fn inactive(mut self, s: Style) -> Self {
         self.attr(Attribute::FocusStyle, AttrValue::Style(s));
         self
     }
 
+    /// If true, the input is rendered as inactive and ignores all commands
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
     pub fn borders(mut self, b: Borders) -> Self {
         self.attr(Attribute::Borders, AttrValue::Borders(b));
         self
@@ -171,6 +350,15 @@ impl Input {
         self
     }
 
+    /// Set a message rendered on the bottom border, with the invalid style, while the input is focused and invalid
+    pub fn error_message<S: Into<String>>(mut self, message: S) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_ERROR_MESSAGE),
+            AttrValue::String(message.into()),
+        );
+        self
+    }
+
     pub fn placeholder<S: Into<String>>(mut self, placeholder: S, style: Style) -> Self {
         self.attr(
             Attribute::Custom(INPUT_PLACEHOLDER),
@@ -183,6 +371,378 @@ impl Input {
         self
     }
 
+    /// Show a suffix after the displayed text with `style`, without making it part of the value
+    /// (e.g. to preview an autocompletion). The suffix is hidden if it doesn't fit in the input
+    pub fn ghost_suffix<S: Into<String>>(mut self, suffix: S, style: Style) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_GHOST_SUFFIX),
+            AttrValue::String(suffix.into()),
+        );
+        self.attr(
+            Attribute::Custom(INPUT_GHOST_SUFFIX_STYLE),
+            AttrValue::Style(style),
+        );
+        self
+    }
+
+    /// Display all the characters but the last `n` as `mask` (e.g. to show only the last 4 digits of a card number).
+    /// The state still holds the unmasked value
+    pub fn mask_except_last(mut self, n: usize, mask: char) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_MASK_EXCEPT_LAST),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::Usize(n),
+                PropValue::Str(mask.to_string()),
+            ))),
+        );
+        self
+    }
+
+    /// Make `Move` and `GoTo` commands return `CmdResult::Changed` when the cursor moves (default `false`)
+    pub fn report_cursor(mut self, report: bool) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_REPORT_CURSOR),
+            AttrValue::Flag(report),
+        );
+        self
+    }
+
+    /// Make editing commands return `CmdResult::Changed` only when the state changes, instead of the raw input (default `false`).
+    /// Useful to ignore edits which leave the validated value unchanged
+    pub fn report_on_value_change(mut self, report: bool) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_REPORT_ON_VALUE_CHANGE),
+            AttrValue::Flag(report),
+        );
+        self
+    }
+
+    /// Coalesce rapid edits: editing commands return `CmdResult::None`, while the input is still updated immediately,
+    /// and the change is reported by `perform(Cmd::Tick)` once `interval` has elapsed since the last edit,
+    /// so the application must tick regularly
+    pub fn debounce(mut self, interval: Duration) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_DEBOUNCE),
+            AttrValue::Payload(PropPayload::One(
+                PropValue::U64(interval.as_millis() as u64),
+            )),
+        );
+        self
+    }
+
+    /// Set the shape of the terminal cursor while the input is focused.
+    /// The input doesn't change the cursor shape by itself: the application applies the one returned by `active_cursor_style()`
+    pub fn cursor_style(mut self, style: CursorStyle) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_CURSOR_STYLE),
+            AttrValue::Number(style as isize),
+        );
+        self
+    }
+
+    /// Make the input a number stepper: `Move(Up)` and `Move(Down)` increment and decrement the value by `step`,
+    /// within `min` and `max`. Typed values are clamped when the input loses focus.
+    /// Works only with `Number`, `SignedInteger` and `UnsignedInteger` input types
+    pub fn stepper(mut self, min: f64, max: f64, step: f64) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_STEPPER),
+            AttrValue::Payload(PropPayload::Tup3((
+                PropValue::F64(min),
+                PropValue::F64(max),
+                PropValue::F64(step),
+            ))),
+        );
+        self
+    }
+
+    /// Set the suggestions to complete the value with. While the input is focused, the suggestions starting with the value
+    /// are listed in a popup rendered with `view_suggestions()`; `Move(Down)` and `Move(Up)` highlight them and `Submit` accepts the highlighted one
+    pub fn suggestions<S: AsRef<str>>(mut self, suggestions: &[S]) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_SUGGESTIONS),
+            AttrValue::Payload(PropPayload::Vec(
+                suggestions
+                    .iter()
+                    .map(|x| PropValue::Str(x.as_ref().to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the completions for the token before the cursor. Each `Cmd::Change` (e.g. bound to `Tab`) replaces the token
+    /// with the next completion starting with it, cycling; any other command commits the current completion
+    pub fn completions<S: AsRef<str>>(mut self, completions: &[S]) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_COMPLETIONS),
+            AttrValue::Payload(PropPayload::Vec(
+                completions
+                    .iter()
+                    .map(|x| PropValue::Str(x.as_ref().to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the characters separating words, along with whitespaces, for the word commands
+    /// (e.g. `/` for paths, or `_` and `.` for code). Words are separated by whitespaces only by default
+    pub fn word_boundaries(mut self, boundaries: &[char]) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_WORD_BOUNDARIES),
+            AttrValue::String(boundaries.iter().collect()),
+        );
+        self
+    }
+
+    /// ### view_suggestions
+    ///
+    /// Render the suggestions matching the current value in a popup beneath `input_area`, which is the area the input has been rendered in.
+    /// The popup is rendered only while the input is focused and there are matching suggestions. Call it after rendering the other components,
+    /// so that the popup is drawn over them
+    pub fn view_suggestions(&self, render: &mut Frame, input_area: Rect) {
+        let focus = self
+            .props
+            .get_or(Attribute::Focus, AttrValue::Flag(false))
+            .unwrap_flag();
+        let suggestions = self.matching_suggestions();
+        if !focus || suggestions.is_empty() {
+            return;
+        }
+        // Place the popup below the input, within the frame
+        let frame = render.area();
+        let y = input_area.bottom().min(frame.bottom());
+        let height =
+            (suggestions.len().min(SUGGESTIONS_MAX_ROWS) as u16 + 2).min(frame.bottom() - y);
+        let area = Rect::new(input_area.x, y, input_area.width, height);
+        let foreground = self
+            .props
+            .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+            .unwrap_color();
+        let background = self
+            .props
+            .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+            .unwrap_color();
+        let borders = self
+            .props
+            .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+            .unwrap_borders();
+        let block = crate::utils::get_block(borders, None, true, None);
+        let list = TuiList::new(suggestions.into_iter().map(ListItem::new))
+            .block(block)
+            .style(Style::default().fg(foreground).bg(background))
+            .highlight_style(Style::default().add_modifier(TextModifiers::REVERSED));
+        let mut state = ListState::default().with_selected(self.states.suggestion);
+        render.render_widget(Clear, area);
+        render.render_stateful_widget(list, area, &mut state);
+    }
+
+    /// ### matching_suggestions
+    ///
+    /// Get the suggestions starting with the current value, if it's not empty
+    fn matching_suggestions(&self) -> Vec<String> {
+        let value = self.states.get_value();
+        if value.is_empty() {
+            return Vec::new();
+        }
+        match self
+            .props
+            .get_ref(Attribute::Custom(INPUT_SUGGESTIONS))
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Vec(suggestions)) => suggestions
+                .iter()
+                .filter_map(|x| match x {
+                    PropValue::Str(s) if s.starts_with(&value) && *s != value => Some(s.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### complete
+    ///
+    /// Replace the token before the cursor with the next completion starting with it.
+    /// If there is a single completion, it is committed immediately
+    fn complete(&mut self) {
+        let completion = match self.completion.take() {
+            Some(completion) => completion,
+            None => {
+                // Token starts after the last whitespace before the cursor
+                let start = self.states.input[..self.states.cursor]
+                    .iter()
+                    .rposition(|x| x.is_whitespace())
+                    .map(|x| x + 1)
+                    .unwrap_or(0);
+                Completion {
+                    start,
+                    prefix: self.states.input[start..self.states.cursor]
+                        .iter()
+                        .collect(),
+                    index: usize::MAX,
+                }
+            }
+        };
+        if completion.prefix.is_empty() {
+            return;
+        }
+        let matches: Vec<String> = match self
+            .props
+            .get_ref(Attribute::Custom(INPUT_COMPLETIONS))
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Vec(completions)) => completions
+                .iter()
+                .filter_map(|x| match x {
+                    PropValue::Str(s) if s.starts_with(&completion.prefix) => Some(s.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        if matches.is_empty() {
+            return;
+        }
+        let index = completion.index.wrapping_add(1) % matches.len();
+        // Replace the token, typing the completion as the user would, so that it's validated and truncated to the input length
+        self.states
+            .input
+            .drain(completion.start..self.states.cursor);
+        self.states.cursor = completion.start;
+        let (itype, max_len) = (self.get_input_type(), self.get_input_len());
+        for ch in matches[index].chars() {
+            self.states.append(ch, &itype, max_len);
+        }
+        if matches.len() > 1 {
+            self.completion = Some(Completion {
+                index,
+                ..completion
+            });
+        }
+    }
+
+    /// ### move_suggestion
+    ///
+    /// Highlight the next (or previous) matching suggestion.
+    /// Moving up from the first suggestion goes back to the typed value.
+    /// Returns whether the suggestions are open
+    fn move_suggestion(&mut self, down: bool) -> bool {
+        let matches = self.matching_suggestions().len();
+        if matches == 0 {
+            return false;
+        }
+        self.states.suggestion = match (self.states.suggestion, down) {
+            (None, true) => Some(0),
+            (None, false) => None,
+            (Some(i), true) => Some((i + 1).min(matches - 1)),
+            (Some(i), false) => i.checked_sub(1),
+        };
+        true
+    }
+
+    /// ### accept_suggestion
+    ///
+    /// Replace the value with the highlighted suggestion, if any.
+    /// Returns whether a suggestion has been accepted
+    fn accept_suggestion(&mut self) -> bool {
+        let Some(suggestion) = self
+            .states
+            .suggestion
+            .and_then(|i| self.matching_suggestions().into_iter().nth(i))
+        else {
+            return false;
+        };
+        self.attr(Attribute::Value, AttrValue::String(suggestion));
+        true
+    }
+
+    /// ### cursor_result
+    ///
+    /// Get the result of a cursor movement from `prev_cursor`
+    fn cursor_result(&self, prev_cursor: usize) -> CmdResult {
+        let report = self
+            .props
+            .get_or(
+                Attribute::Custom(INPUT_REPORT_CURSOR),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag();
+        match report && prev_cursor != self.states.cursor {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+
+    /// ### change_result
+    ///
+    /// Get the result of an edit, given the input and the state before it.
+    /// If `report_on_value_change` is set, the states are compared instead of the inputs
+    fn change_result(&mut self, prev_input: Vec<char>, prev_state: State) -> CmdResult {
+        let report_on_value_change = self
+            .props
+            .get_or(
+                Attribute::Custom(INPUT_REPORT_ON_VALUE_CHANGE),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag();
+        let state = self.state();
+        let changed = match report_on_value_change {
+            true => prev_state != state,
+            false => prev_input != self.states.input,
+        };
+        match changed {
+            true => self.debounce_change(CmdResult::Changed(state), Instant::now()),
+            false => CmdResult::None,
+        }
+    }
+
+    fn get_debounce(&self) -> Option<Duration> {
+        match self
+            .props
+            .get(Attribute::Custom(INPUT_DEBOUNCE))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::U64(millis))) => Some(Duration::from_millis(millis)),
+            _ => None,
+        }
+    }
+
+    /// ### debounce_change
+    ///
+    /// Hold back the `Changed` result of an edit made at `now`, if debounced, until `Tick` reports it
+    fn debounce_change(&mut self, result: CmdResult, now: Instant) -> CmdResult {
+        if self.get_debounce().is_none() {
+            return result;
+        }
+        self.last_edit = Some(now);
+        self.pending_change = true;
+        CmdResult::None
+    }
+
+    /// ### flush_change
+    ///
+    /// Report the change held back by the debounce, if the debounce interval has elapsed at `now` since the last edit
+    fn flush_change(&mut self, now: Instant) -> CmdResult {
+        match (self.pending_change, self.last_edit, self.get_debounce()) {
+            (true, Some(last_edit), Some(interval))
+                if now.saturating_duration_since(last_edit) >= interval =>
+            {
+                self.pending_change = false;
+                CmdResult::Changed(self.state())
+            }
+            _ => CmdResult::None,
+        }
+    }
+
+    fn get_word_boundaries(&self) -> Vec<char> {
+        self.props
+            .get_ref(Attribute::Custom(INPUT_WORD_BOUNDARIES))
+            .and_then(|x| x.as_string())
+            .map(|x| x.chars().collect())
+            .unwrap_or_default()
+    }
+
     fn get_input_len(&self) -> Option<usize> {
         self.props
             .get(Attribute::InputLength)
@@ -202,6 +762,106 @@ impl Input {
         let value = self.states.get_value();
         self.get_input_type().validate(value.as_str())
     }
+
+    fn is_disabled(&self) -> bool {
+        self.props
+            .get_or(Attribute::Disabled, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    /// ### display_chars
+    ///
+    /// Get the characters to display for the current value, masked if `mask_except_last` is set
+    fn display_chars(&self) -> Vec<char> {
+        let mut chars = self.states.render_value_chars(self.get_input_type());
+        if let Some(PropPayload::Tup2((PropValue::Usize(n), PropValue::Str(mask)))) = self
+            .props
+            .get(Attribute::Custom(INPUT_MASK_EXCEPT_LAST))
+            .map(|x| x.unwrap_payload())
+        {
+            let mask = mask.chars().next().unwrap_or('*');
+            let masked = chars.len().saturating_sub(n);
+            chars.iter_mut().take(masked).for_each(|x| *x = mask);
+        }
+        chars
+    }
+
+    /// ### get_stepper
+    ///
+    /// Get `(min, max, step)` of the stepper, if set and if the input type is numeric
+    fn get_stepper(&self) -> Option<(f64, f64, f64)> {
+        if !matches!(
+            self.get_input_type(),
+            InputType::Number | InputType::SignedInteger | InputType::UnsignedInteger
+        ) {
+            return None;
+        }
+        match self
+            .props
+            .get_ref(Attribute::Custom(INPUT_STEPPER))
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Tup3((
+                PropValue::F64(min),
+                PropValue::F64(max),
+                PropValue::F64(step),
+            ))) => Some((*min, *max, *step)),
+            _ => None,
+        }
+    }
+
+    /// ### step_value
+    ///
+    /// Add `delta` steps to the current value, clamped within the stepper bounds.
+    /// A non-numeric value is treated as the minimum.
+    /// Returns whether the value changed
+    fn step_value(&mut self, delta: f64) -> bool {
+        let Some((min, max, step)) = self.get_stepper() else {
+            return false;
+        };
+        let current = self.states.get_value();
+        let value = current
+            .parse::<f64>()
+            .map(|x| x + delta * step)
+            .unwrap_or(min)
+            .clamp(min, max.max(min));
+        let value = match self.get_input_type() {
+            InputType::Number => {
+                // Keep the precision of the step, to avoid floating point noise
+                let precision = step
+                    .to_string()
+                    .split_once('.')
+                    .map(|(_, decimals)| decimals.len())
+                    .unwrap_or(0);
+                format!("{value:.precision$}")
+            }
+            _ => format!("{}", value.round() as i64),
+        };
+        if value == current {
+            return false;
+        }
+        self.states.set_value(&value);
+        true
+    }
+
+    fn get_cursor_style(&self) -> Option<CursorStyle> {
+        self.props
+            .get(Attribute::Custom(INPUT_CURSOR_STYLE))
+            .map(|x| CursorStyle::from_code(x.unwrap_number()))
+    }
+
+    /// ### active_cursor_style
+    ///
+    /// Get the cursor shape the application should set on the terminal for this input:
+    /// the one set with `cursor_style()` while the input is focused, `None` if unset or not focused.
+    /// When no input requests a shape, the application should restore `CursorStyle::Default`
+    pub fn active_cursor_style(&self) -> Option<CursorStyle> {
+        let focus = self
+            .props
+            .get_or(Attribute::Focus, AttrValue::Flag(false))
+            .unwrap_flag();
+        self.get_cursor_style().filter(|_| focus)
+    }
 }
 
 impl MockComponent for Input {
@@ -233,15 +893,16 @@ impl MockComponent for Input {
                 .props
                 .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
                 .unwrap_borders();
+            // Disabled components are rendered as inactive
             let focus = self
                 .props
                 .get_or(Attribute::Focus, AttrValue::Flag(false))
-                .unwrap_flag();
+                .unwrap_flag()
+                && !self.is_disabled();
             let inactive_style = self
                 .props
                 .get(Attribute::FocusStyle)
                 .map(|x| x.unwrap_style());
-            let itype = self.get_input_type();
             let mut block = crate::utils::get_block(borders, Some(title), focus, inactive_style);
             // Apply invalid style
             if focus && !self.is_valid() {
@@ -267,7 +928,26 @@ impl MockComponent for Input {
                     background = style.bg.unwrap_or(Color::Reset);
                 }
             }
-            let text_to_display = self.states.render_value(self.get_input_type());
+            // Show the error message on the bottom border, truncated to the border width
+            if focus && !self.is_valid() {
+                if let Some(message) = self
+                    .props
+                    .get(Attribute::Custom(INPUT_ERROR_MESSAGE))
+                    .map(|x| x.unwrap_string())
+                {
+                    let style = self
+                        .props
+                        .get_or(
+                            Attribute::Custom(INPUT_INVALID_STYLE),
+                            AttrValue::Style(Style::default()),
+                        )
+                        .unwrap_style();
+                    let width = block.inner(area).width as usize;
+                    let message = crate::utils::truncate_with_ellipsis(&message, width);
+                    block = block.title_bottom(Line::styled(message, style));
+                }
+            }
+            let text_to_display: String = self.display_chars().iter().collect();
             let show_placeholder = text_to_display.is_empty();
             // Choose whether to show placeholder; if placeholder is unset, show nothing
             let text_to_display = match show_placeholder {
@@ -300,16 +980,32 @@ impl MockComponent for Input {
             };
             // Create widget
             let block_inner_area = block.inner(area);
-            let p: Paragraph = Paragraph::new(text_to_display)
+            let mut spans = vec![Span::raw(text_to_display)];
+            // Append ghost suffix, if it fits
+            if let Some(suffix) = self
+                .props
+                .get(Attribute::Custom(INPUT_GHOST_SUFFIX))
+                .map(|x| x.unwrap_string())
+            {
+                if spans[0].width() + suffix.width() <= block_inner_area.width as usize {
+                    let style = self
+                        .props
+                        .get_or(
+                            Attribute::Custom(INPUT_GHOST_SUFFIX_STYLE),
+                            AttrValue::Style(Style::default()),
+                        )
+                        .unwrap_style();
+                    spans.push(Span::styled(suffix, style));
+                }
+            }
+            let p: Paragraph = Paragraph::new(Line::from(spans))
                 .style(paragraph_style)
                 .block(block);
             render.render_widget(p, area);
             // Set cursor, if focus
             if focus {
                 let x: u16 = block_inner_area.x
-                    + calc_utf8_cursor_position(
-                        &self.states.render_value_chars(itype)[0..self.states.cursor],
-                    );
+                    + calc_utf8_cursor_position(&self.display_chars()[0..self.states.cursor]);
                 render
                     .set_cursor_position(tuirealm::ratatui::prelude::Position { x, y: area.y + 1 });
             }
@@ -330,7 +1026,21 @@ impl MockComponent for Input {
             Attribute::Value => Some(value.clone().unwrap_string()),
             _ => None,
         };
+        // Clamp the typed value on blur
+        let blur = attr == Attribute::Focus && value == AttrValue::Flag(false);
+        if sanitize_input || blur || attr == Attribute::Custom(INPUT_SUGGESTIONS) {
+            self.states.suggestion = None;
+            self.completion = None;
+        }
+        // A value set from outside supersedes the change held back by the debounce
+        if new_input.is_some() {
+            self.pending_change = false;
+            self.last_edit = None;
+        }
         self.props.set(attr, value);
+        if blur {
+            self.step_value(0.0);
+        }
         if sanitize_input {
             let input = match new_input {
                 None => self.states.input.clone(),
@@ -356,56 +1066,91 @@ impl MockComponent for Input {
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if self.is_disabled() {
+            return CmdResult::None;
+        }
+        // Any command but `Change` commits the current completion
+        if cmd != Cmd::Change {
+            self.completion = None;
+        }
         match cmd {
+            Cmd::Change => {
+                let (prev_input, prev_state) = (self.states.input.clone(), self.state());
+                self.complete();
+                self.change_result(prev_input, prev_state)
+            }
             Cmd::Delete => {
                 // Backspace and None
-                let prev_input = self.states.input.clone();
+                let (prev_input, prev_state) = (self.states.input.clone(), self.state());
+                self.states.suggestion = None;
                 self.states.backspace();
-                if prev_input != self.states.input {
-                    CmdResult::Changed(self.state())
-                } else {
-                    CmdResult::None
-                }
+                self.change_result(prev_input, prev_state)
             }
             Cmd::Cancel => {
                 // Delete and None
-                let prev_input = self.states.input.clone();
+                let (prev_input, prev_state) = (self.states.input.clone(), self.state());
+                self.states.suggestion = None;
                 self.states.delete();
-                if prev_input != self.states.input {
-                    CmdResult::Changed(self.state())
-                } else {
-                    CmdResult::None
-                }
+                self.change_result(prev_input, prev_state)
             }
+            Cmd::Submit if self.accept_suggestion() => CmdResult::Changed(self.state()),
             Cmd::Submit => CmdResult::Submit(self.state()),
             Cmd::Move(Direction::Left) => {
+                let prev_cursor = self.states.cursor;
                 self.states.decr_cursor();
-                CmdResult::None
+                self.cursor_result(prev_cursor)
             }
             Cmd::Move(Direction::Right) => {
+                let prev_cursor = self.states.cursor;
                 self.states.incr_cursor();
-                CmdResult::None
+                self.cursor_result(prev_cursor)
             }
+            Cmd::Move(Direction::Up) if self.move_suggestion(false) => CmdResult::None,
+            Cmd::Move(Direction::Down) if self.move_suggestion(true) => CmdResult::None,
+            Cmd::Move(Direction::Up) => match self.step_value(1.0) {
+                true => CmdResult::Changed(self.state()),
+                false => CmdResult::None,
+            },
+            Cmd::Move(Direction::Down) => match self.step_value(-1.0) {
+                true => CmdResult::Changed(self.state()),
+                false => CmdResult::None,
+            },
             Cmd::GoTo(Position::Begin) => {
+                let prev_cursor = self.states.cursor;
                 self.states.cursor_at_begin();
-                CmdResult::None
+                self.cursor_result(prev_cursor)
             }
             Cmd::GoTo(Position::End) => {
+                let prev_cursor = self.states.cursor;
                 self.states.cursor_at_end();
-                CmdResult::None
+                self.cursor_result(prev_cursor)
             }
             Cmd::Type(ch) => {
                 // Push char to input
-                let prev_input = self.states.input.clone();
+                let (prev_input, prev_state) = (self.states.input.clone(), self.state());
+                self.states.suggestion = None;
                 self.states
                     .append(ch, &self.get_input_type(), self.get_input_len());
                 // Message on change
-                if prev_input != self.states.input {
-                    CmdResult::Changed(self.state())
-                } else {
-                    CmdResult::None
-                }
+                self.change_result(prev_input, prev_state)
+            }
+            Cmd::Custom(Self::CMD_DELETE_WORD) => {
+                let (prev_input, prev_state) = (self.states.input.clone(), self.state());
+                self.states.suggestion = None;
+                self.states.delete_word(&self.get_word_boundaries());
+                self.change_result(prev_input, prev_state)
+            }
+            Cmd::Custom(Self::CMD_WORD_LEFT) => {
+                let prev_cursor = self.states.cursor;
+                self.states.cursor = self.states.word_left(&self.get_word_boundaries());
+                self.cursor_result(prev_cursor)
             }
+            Cmd::Custom(Self::CMD_WORD_RIGHT) => {
+                let prev_cursor = self.states.cursor;
+                self.states.cursor = self.states.word_right(&self.get_word_boundaries());
+                self.cursor_result(prev_cursor)
+            }
+            Cmd::Tick => self.flush_change(Instant::now()),
             _ => CmdResult::None,
         }
     }
@@ -417,6 +1162,7 @@ mod tests {
     use super::*;
 
     use pretty_assertions::assert_eq;
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
 
     #[test]
     fn test_components_input_states() {
@@ -620,4 +1366,667 @@ mod tests {
         );
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_components_input_states_serde() {
+        let states = InputStates {
+            input: vec!['a', 'b', 'c'],
+            cursor: 2,
+            suggestion: Some(1),
+        };
+        let json = serde_json::to_string(&states).unwrap();
+        let restored: InputStates = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.input, vec!['a', 'b', 'c']);
+        assert_eq!(restored.cursor, 2);
+        assert_eq!(restored.suggestion, None);
+    }
+
+    #[test]
+    fn test_components_input_theme() {
+        let theme = Theme {
+            highlighted_color: Color::LightYellow,
+            modifiers: TextModifiers::BOLD,
+            ..Theme::default()
+        };
+        let component = Input::default().theme(&theme);
+        assert_eq!(
+            component.query(Attribute::TextProps),
+            Some(AttrValue::TextModifiers(TextModifiers::BOLD))
+        );
+        assert_eq!(component.query(Attribute::HighlightedColor), None);
+    }
+
+    #[test]
+    fn test_components_input_ghost_suffix() {
+        let mut component = Input::default()
+            .borders(Borders::default())
+            .input_type(InputType::Text)
+            .value("hel")
+            .ghost_suffix("lo", Style::default().fg(Color::DarkGray));
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(String::from("hel")))
+        );
+        let render = |component: &mut Input, width: u16| {
+            let mut terminal = Terminal::new(TestBackend::new(width, 3)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, width, 3)))
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let buffer = render(&mut component, 10);
+        let row: String = (1..6).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert_eq!(row, "hello");
+        assert_eq!(buffer[(4, 1)].fg, Color::DarkGray);
+        assert_ne!(buffer[(3, 1)].fg, Color::DarkGray);
+        // Suffix is hidden if it overflows
+        let buffer = render(&mut component, 6);
+        let row: String = (1..5).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert_eq!(row, "hel ");
+    }
+
+    #[test]
+    fn test_components_input_report_cursor() {
+        // Default: cursor moves are not reported
+        let mut component = Input::default().input_type(InputType::Text).value("ab");
+        component.states.cursor = 0;
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 1);
+        // Report cursor
+        let mut component = component.report_cursor(true);
+        component.states.cursor = 0;
+        // Cursor can't move left at position 0
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("ab"))))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("ab"))))
+        );
+        assert_eq!(component.perform(Cmd::GoTo(Position::End)), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::Begin)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("ab"))))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("ab"))))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("ab"))))
+        );
+    }
+
+    #[test]
+    fn test_components_input_disabled() {
+        let mut component = Input::default()
+            .foreground(Color::Red)
+            .inactive(Style::default().fg(Color::Gray))
+            .input_type(InputType::Text)
+            .value("ab")
+            .disabled(true);
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        // Commands are ignored
+        assert_eq!(component.perform(Cmd::Type('c')), CmdResult::None);
+        assert_eq!(component.perform(Cmd::Delete), CmdResult::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(String::from("ab")))
+        );
+        // Rendered as inactive, although focused
+        let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 3)))
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(1, 1)].fg, Color::Gray);
+        // Enabled again
+        let mut component = component.disabled(false);
+        assert_eq!(
+            component.perform(Cmd::Type('c')),
+            CmdResult::Changed(State::One(StateValue::String(String::from("abc"))))
+        );
+    }
+
+    #[test]
+    fn test_components_input_states_set_value_clear() {
+        let mut states: InputStates = InputStates::default();
+        states.set_value("héllo");
+        assert_eq!(states.get_value(), "héllo");
+        assert_eq!(states.cursor, 5);
+        states.set_value("ab");
+        assert_eq!(states.input, vec!['a', 'b']);
+        assert_eq!(states.cursor, 2);
+        states.clear();
+        assert!(states.input.is_empty());
+        assert_eq!(states.cursor, 0);
+        // Clear on empty input
+        states.clear();
+        assert!(states.input.is_empty());
+        assert_eq!(states.cursor, 0);
+    }
+
+    #[test]
+    fn test_components_input_word_boundaries() {
+        let mut component = Input::default().value("/usr/local/bin");
+        // Whitespaces only by default
+        assert_eq!(
+            component.perform(Cmd::Custom(Input::CMD_DELETE_WORD)),
+            CmdResult::Changed(State::One(StateValue::String(String::new())))
+        );
+        let mut component = component.value("/usr/local/bin").word_boundaries(&['/']);
+        assert_eq!(
+            component.perform(Cmd::Custom(Input::CMD_DELETE_WORD)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("/usr/local/"))))
+        );
+        // Boundaries before the cursor are deleted with the word
+        assert_eq!(
+            component.perform(Cmd::Custom(Input::CMD_DELETE_WORD)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("/usr/"))))
+        );
+        assert_eq!(component.states.cursor, 5);
+        component.perform(Cmd::Custom(Input::CMD_DELETE_WORD));
+        assert_eq!(component.states.get_value(), "/");
+        component.perform(Cmd::Custom(Input::CMD_DELETE_WORD));
+        assert_eq!(component.states.get_value(), "");
+        assert_eq!(
+            component.perform(Cmd::Custom(Input::CMD_DELETE_WORD)),
+            CmdResult::None
+        );
+        // Move by words
+        let mut component = Input::default()
+            .value("foo_bar.baz qux")
+            .word_boundaries(&['_', '.'])
+            .report_cursor(true);
+        component.perform(Cmd::Custom(Input::CMD_WORD_LEFT));
+        assert_eq!(component.states.cursor, 12);
+        component.perform(Cmd::Custom(Input::CMD_WORD_LEFT));
+        assert_eq!(component.states.cursor, 8);
+        component.perform(Cmd::Custom(Input::CMD_WORD_LEFT));
+        assert_eq!(component.states.cursor, 4);
+        component.perform(Cmd::Custom(Input::CMD_WORD_LEFT));
+        assert_eq!(component.states.cursor, 0);
+        assert_eq!(
+            component.perform(Cmd::Custom(Input::CMD_WORD_LEFT)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(Input::CMD_WORD_RIGHT)),
+            CmdResult::Changed(State::One(StateValue::String(String::from(
+                "foo_bar.baz qux"
+            ))))
+        );
+        assert_eq!(component.states.cursor, 3);
+        component.perform(Cmd::Custom(Input::CMD_WORD_RIGHT));
+        assert_eq!(component.states.cursor, 7);
+        component.perform(Cmd::Custom(Input::CMD_WORD_RIGHT));
+        component.perform(Cmd::Custom(Input::CMD_WORD_RIGHT));
+        assert_eq!(component.states.cursor, 15);
+        assert_eq!(
+            component.perform(Cmd::Custom(Input::CMD_WORD_RIGHT)),
+            CmdResult::None
+        );
+        // Delete word in the middle
+        component.states.cursor = 7;
+        component.perform(Cmd::Custom(Input::CMD_DELETE_WORD));
+        assert_eq!(component.states.get_value(), "foo_.baz qux");
+        assert_eq!(component.states.cursor, 4);
+    }
+
+    #[test]
+    fn test_components_input_mask_except_last() {
+        let mut component = Input::default()
+            .input_type(InputType::Number)
+            .mask_except_last(4, '•');
+        assert_eq!(
+            component.query(Attribute::Custom(INPUT_MASK_EXCEPT_LAST)),
+            Some(AttrValue::Payload(PropPayload::Tup2((
+                PropValue::Usize(4),
+                PropValue::Str("•".to_string())
+            ))))
+        );
+        let display = |component: &Input| component.display_chars().iter().collect::<String>();
+        assert_eq!(display(&component).as_str(), "");
+        // Shorter than N: show everything
+        component.attr(Attribute::Value, AttrValue::String("123".to_string()));
+        assert_eq!(display(&component).as_str(), "123");
+        component.attr(Attribute::Value, AttrValue::String("1234".to_string()));
+        assert_eq!(display(&component).as_str(), "1234");
+        component.attr(
+            Attribute::Value,
+            AttrValue::String("4111111111111234".to_string()),
+        );
+        assert_eq!(display(&component).as_str(), "••••••••••••1234");
+        // State is unmasked
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String("4111111111111234".to_string()))
+        );
+        // Composes with input length
+        let mut component = component.input_len(6);
+        component.attr(Attribute::Value, AttrValue::String("12345678".to_string()));
+        assert_eq!(display(&component).as_str(), "••3456");
+        // Rendered
+        let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 8, 3)))
+            .unwrap();
+        let row: String = (1..7)
+            .map(|x| terminal.backend().buffer()[(x, 1)].symbol().to_string())
+            .collect();
+        assert_eq!(row.as_str(), "••3456");
+        // Mask all
+        let component = component.mask_except_last(0, '*');
+        assert_eq!(display(&component).as_str(), "******");
+    }
+
+    #[test]
+    fn test_components_input_cursor_style() {
+        assert_eq!(CursorStyle::Default.escape_sequence(), "\x1b[0 q");
+        assert_eq!(CursorStyle::SteadyBlock.escape_sequence(), "\x1b[2 q");
+        assert_eq!(CursorStyle::BlinkingBar.escape_sequence(), "\x1b[5 q");
+        let mut component = Input::default().cursor_style(CursorStyle::SteadyUnderline);
+        assert_eq!(
+            component.get_cursor_style(),
+            Some(CursorStyle::SteadyUnderline)
+        );
+        // Requested only while focused
+        assert_eq!(component.active_cursor_style(), None);
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        assert_eq!(
+            component.active_cursor_style(),
+            Some(CursorStyle::SteadyUnderline)
+        );
+        component.attr(Attribute::Focus, AttrValue::Flag(false));
+        assert_eq!(component.active_cursor_style(), None);
+        // Unset
+        let mut component = Input::default();
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        assert_eq!(component.active_cursor_style(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "crossterm")]
+    fn test_components_input_cursor_style_crossterm() {
+        use tuirealm::ratatui::crossterm::cursor::SetCursorStyle;
+
+        assert!(matches!(
+            SetCursorStyle::from(CursorStyle::Default),
+            SetCursorStyle::DefaultUserShape
+        ));
+        assert!(matches!(
+            SetCursorStyle::from(CursorStyle::SteadyUnderline),
+            SetCursorStyle::SteadyUnderScore
+        ));
+    }
+
+    #[test]
+    fn test_components_input_stepper() {
+        let mut component = Input::default()
+            .input_type(InputType::UnsignedInteger)
+            .stepper(0.0, 10.0, 4.0)
+            .value("3");
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("7"))))
+        );
+        assert_eq!(component.states.cursor, 1);
+        // Incrementing past the max clamps
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("10"))))
+        );
+        assert_eq!(component.states.cursor, 2);
+        assert_eq!(component.perform(Cmd::Move(Direction::Up)), CmdResult::None);
+        // Decrementing below the min clamps
+        component.attr(Attribute::Value, AttrValue::String(String::from("2")));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("0"))))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        // Non-numeric content is treated as the min
+        component.attr(Attribute::Value, AttrValue::String(String::new()));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("0"))))
+        );
+        // Typed values are clamped on blur
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        assert_eq!(
+            component.perform(Cmd::Type('9')),
+            CmdResult::Changed(State::One(StateValue::String(String::from("09"))))
+        );
+        component.perform(Cmd::Type('9'));
+        component.attr(Attribute::Focus, AttrValue::Flag(false));
+        assert_eq!(component.states.get_value(), "10");
+        // Floating point steps keep the step precision
+        let mut component = Input::default()
+            .input_type(InputType::Number)
+            .stepper(-1.0, 1.0, 0.1)
+            .value("0.2");
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("0.3"))))
+        );
+        // Not a stepper for text inputs
+        let mut component = Input::default().stepper(0.0, 10.0, 1.0).value("3");
+        assert_eq!(component.perform(Cmd::Move(Direction::Up)), CmdResult::None);
+        assert_eq!(component.states.get_value(), "3");
+    }
+
+    #[test]
+    fn test_components_input_error_message() {
+        let mut component = Input::default()
+            .borders(Borders::default())
+            .input_type(InputType::Number)
+            .invalid_style(Style::default().fg(Color::Red))
+            .error_message("not a number")
+            .value("1.2.3");
+        let render = |component: &mut Input, width: u16| {
+            let mut terminal = Terminal::new(TestBackend::new(width, 3)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, width, 3)))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            let row: String = (0..width).map(|x| buffer[(x, 2)].symbol()).collect();
+            (row, buffer[(1, 2)].fg)
+        };
+        // Hidden while unfocused
+        assert_eq!(render(&mut component, 16).0, "└──────────────┘");
+        // Shown on the bottom border while focused and invalid
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        assert_eq!(
+            render(&mut component, 16),
+            (String::from("└not a number──┘"), Color::Red)
+        );
+        // Truncated in narrow areas
+        assert_eq!(render(&mut component, 8).0, "└not a…┘");
+        // Hidden when valid
+        component.attr(Attribute::Value, AttrValue::String(String::from("1.2")));
+        assert_eq!(render(&mut component, 16).0, "└──────────────┘");
+    }
+
+    #[test]
+    fn test_components_input_report_on_value_change() {
+        let mut component = Input::default()
+            .input_type(InputType::Number)
+            .report_on_value_change(true)
+            .value("1");
+        // Rejected char
+        assert_eq!(component.perform(Cmd::Type('a')), CmdResult::None);
+        // Valid digit
+        assert_eq!(
+            component.perform(Cmd::Type('2')),
+            CmdResult::Changed(State::One(StateValue::String(String::from("12"))))
+        );
+        // Accepted char which leaves the value invalid, as it was
+        component.attr(Attribute::Value, AttrValue::String(String::new()));
+        assert_eq!(component.perform(Cmd::Type('-')), CmdResult::None);
+        assert_eq!(component.states.get_value(), "-");
+        assert_eq!(
+            component.perform(Cmd::Type('3')),
+            CmdResult::Changed(State::One(StateValue::String(String::from("-3"))))
+        );
+        assert_eq!(
+            component.perform(Cmd::Delete),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(component.perform(Cmd::Delete), CmdResult::None);
+        // By default the raw input is compared
+        let mut component = component.report_on_value_change(false);
+        assert_eq!(
+            component.perform(Cmd::Type('-')),
+            CmdResult::Changed(State::None)
+        );
+    }
+
+    #[test]
+    fn test_components_input_debounce() {
+        let mut component = Input::default().input_type(InputType::Text);
+        let now = Instant::now();
+        let changed = |x: &str| CmdResult::Changed(State::One(StateValue::String(x.to_string())));
+        // Not debounced
+        assert_eq!(component.debounce_change(changed("a"), now), changed("a"));
+        assert_eq!(component.flush_change(now), CmdResult::None);
+        let mut component = component.debounce(Duration::from_millis(300));
+        // Edits are held back
+        assert_eq!(
+            component.debounce_change(changed("a"), now),
+            CmdResult::None
+        );
+        component.states.input = vec!['a', 'b'];
+        assert_eq!(
+            component.debounce_change(changed("ab"), now + Duration::from_millis(100)),
+            CmdResult::None
+        );
+        component.states.input = vec!['a', 'b', 'c'];
+        assert_eq!(
+            component.debounce_change(changed("abc"), now + Duration::from_millis(350)),
+            CmdResult::None
+        );
+        // Flushed once the interval has elapsed since the last edit
+        assert_eq!(
+            component.flush_change(now + Duration::from_millis(600)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.flush_change(now + Duration::from_millis(650)),
+            changed("abc")
+        );
+        assert_eq!(
+            component.flush_change(now + Duration::from_millis(700)),
+            CmdResult::None
+        );
+        // A later edit is held back as well
+        component.states.input = vec!['a', 'b', 'c', 'd'];
+        assert_eq!(
+            component.debounce_change(changed("abcd"), now + Duration::from_millis(1000)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.flush_change(now + Duration::from_millis(1200)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.flush_change(now + Duration::from_millis(1300)),
+            changed("abcd")
+        );
+        // Setting the value from outside drops the held back change
+        assert_eq!(
+            component.debounce_change(changed("abcde"), now + Duration::from_millis(2000)),
+            CmdResult::None
+        );
+        component.attr(Attribute::Value, AttrValue::String("xyz".to_string()));
+        assert_eq!(
+            component.flush_change(now + Duration::from_millis(3000)),
+            CmdResult::None
+        );
+        // Through perform
+        let mut component = Input::default().debounce(Duration::from_secs(60));
+        assert_eq!(component.perform(Cmd::Type('a')), CmdResult::None);
+        assert_eq!(component.perform(Cmd::Type('b')), CmdResult::None);
+        assert_eq!(component.states.get_value(), "ab");
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        let mut component = Input::default().debounce(Duration::ZERO);
+        assert_eq!(component.perform(Cmd::Type('a')), CmdResult::None);
+        assert_eq!(component.perform(Cmd::Tick), changed("a"));
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_input_suggestions() {
+        let mut component = Input::default().suggestions(&["cargo", "carrot", "cat", "dog"]);
+        // No suggestions for an empty value
+        assert!(component.matching_suggestions().is_empty());
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::String(String::new())))
+        );
+        // Suggestions starting with the value
+        component.perform(Cmd::Type('c'));
+        component.perform(Cmd::Type('a'));
+        component.perform(Cmd::Type('r'));
+        assert_eq!(
+            component.matching_suggestions(),
+            vec![String::from("cargo"), String::from("carrot")]
+        );
+        // Move among suggestions
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.suggestion, Some(0));
+        component.perform(Cmd::Move(Direction::Down));
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(component.states.suggestion, Some(1));
+        component.perform(Cmd::Move(Direction::Up));
+        component.perform(Cmd::Move(Direction::Up));
+        assert_eq!(component.states.suggestion, None);
+        // Typing resets the highlighted suggestion
+        component.perform(Cmd::Move(Direction::Down));
+        component.perform(Cmd::Type('r'));
+        assert_eq!(component.states.suggestion, None);
+        assert_eq!(
+            component.matching_suggestions(),
+            vec![String::from("carrot")]
+        );
+        // Accept
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Changed(State::One(StateValue::String(String::from("carrot"))))
+        );
+        assert_eq!(component.states.cursor, 6);
+        assert_eq!(component.states.suggestion, None);
+        // A suggestion equal to the value is not listed, so submit goes through
+        assert!(component.matching_suggestions().is_empty());
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::String(String::from("carrot"))))
+        );
+    }
+
+    #[test]
+    fn test_components_input_view_suggestions() {
+        let mut component = Input::default()
+            .suggestions(&["cargo", "carrot", "cat"])
+            .value("car");
+        let render = |component: &mut Input| {
+            let mut terminal = Terminal::new(TestBackend::new(12, 8)).unwrap();
+            terminal
+                .draw(|f| {
+                    component.view(f, Rect::new(0, 0, 12, 3));
+                    component.view_suggestions(f, Rect::new(0, 0, 12, 3));
+                })
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let row = |buffer: &tuirealm::ratatui::buffer::Buffer, y: u16| -> String {
+            (0..12).map(|x| buffer[(x, y)].symbol()).collect()
+        };
+        // Not focused
+        let buffer = render(&mut component);
+        assert_eq!(row(&buffer, 3).as_str(), "            ");
+        // Popup beneath the input
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        component.perform(Cmd::Move(Direction::Down));
+        component.perform(Cmd::Move(Direction::Down));
+        let buffer = render(&mut component);
+        assert_eq!(row(&buffer, 4).as_str(), "│cargo     │");
+        assert_eq!(row(&buffer, 5).as_str(), "│carrot    │");
+        assert_eq!(row(&buffer, 6).as_str(), "└──────────┘");
+        assert!(buffer[(1, 5)].modifier.contains(TextModifiers::REVERSED));
+        assert!(!buffer[(1, 4)].modifier.contains(TextModifiers::REVERSED));
+    }
+
+    #[test]
+    fn test_components_input_completions() {
+        let mut component = Input::default()
+            .completions(&["checkout", "cherry-pick", "commit"])
+            .value("git ch");
+        // Cycle through the completions for the token
+        assert_eq!(
+            component.perform(Cmd::Change),
+            CmdResult::Changed(State::One(StateValue::String(String::from("git checkout"))))
+        );
+        assert_eq!(component.states.cursor, 12);
+        assert_eq!(
+            component.perform(Cmd::Change),
+            CmdResult::Changed(State::One(StateValue::String(String::from(
+                "git cherry-pick"
+            ))))
+        );
+        assert_eq!(
+            component.perform(Cmd::Change),
+            CmdResult::Changed(State::One(StateValue::String(String::from("git checkout"))))
+        );
+        // Another key commits the completion
+        component.perform(Cmd::Type(' '));
+        assert_eq!(component.states.get_value(), "git checkout ");
+        // No token, no completion
+        assert_eq!(component.perform(Cmd::Change), CmdResult::None);
+        // No match
+        component.perform(Cmd::Type('x'));
+        assert_eq!(component.perform(Cmd::Change), CmdResult::None);
+        assert_eq!(component.states.get_value(), "git checkout x");
+        // Single match is committed immediately
+        component.perform(Cmd::Delete);
+        component.perform(Cmd::Type('c'));
+        component.perform(Cmd::Type('o'));
+        assert_eq!(
+            component.perform(Cmd::Change),
+            CmdResult::Changed(State::One(StateValue::String(String::from(
+                "git checkout commit"
+            ))))
+        );
+        assert!(component.completion.is_none());
+        // Token before the cursor is completed, the text after is kept
+        let mut component = Input::default()
+            .completions(&["checkout", "cherry-pick"])
+            .value("ch --force");
+        component.perform(Cmd::GoTo(Position::Begin));
+        component.perform(Cmd::Move(Direction::Right));
+        component.perform(Cmd::Move(Direction::Right));
+        component.perform(Cmd::Change);
+        component.perform(Cmd::Change);
+        assert_eq!(component.states.get_value(), "cherry-pick --force");
+        assert_eq!(component.states.cursor, 11);
+        // Completions are truncated to the input length
+        let mut component = Input::default()
+            .input_len(8)
+            .completions(&["checkout", "cherry-pick"])
+            .value("git ch");
+        assert_eq!(
+            component.perform(Cmd::Change),
+            CmdResult::Changed(State::One(StateValue::String(String::from("git chec"))))
+        );
+        assert_eq!(component.states.cursor, 8);
+        component.perform(Cmd::Change);
+        assert_eq!(component.states.get_value(), "git cher");
+        // and validated against the input type
+        let mut component = Input::default()
+            .input_type(InputType::UnsignedInteger)
+            .completions(&["12a3"])
+            .value("1");
+        component.perform(Cmd::Change);
+        assert_eq!(component.states.get_value(), "123");
+    }
 }
@@ -2,11 +2,18 @@
 //!
 //! `Label` represents a read-only text component without any container.
 
+use std::time::{Duration, Instant};
+
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::props::{Alignment, AttrValue, Attribute, Color, Props, Style, TextModifiers};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Color, PropPayload, PropValue, Props, Style, TextModifiers,
+};
 use tuirealm::ratatui::{layout::Rect, widgets::Paragraph};
 use tuirealm::{Frame, MockComponent, State};
 
+// -- Props
+use super::props::{LABEL_BLINK, LABEL_TRUNCATE};
+
 // -- Component
 
 /// ## Label
@@ -15,6 +22,7 @@ use tuirealm::{Frame, MockComponent, State};
 #[derive(Default)]
 pub struct Label {
     props: Props,
+    blink_since: Option<Instant>, // When blinking started
 }
 
 impl Label {
@@ -23,6 +31,7 @@ impl Label {
         self
     }
 
+    /// Set the background color, which fills the entire area of the label (e.g. for status bars)
     pub fn background(mut self, bg: Color) -> Self {
         self.attr(Attribute::Background, AttrValue::Color(bg));
         self
@@ -42,6 +51,57 @@ impl Label {
         self.attr(Attribute::Alignment, AttrValue::Alignment(alignment));
         self
     }
+
+    /// Make the label blink, alternating between `on` and `off` styles every `interval`.
+    /// The blinking style is applied over the label style
+    pub fn blink(mut self, on: Style, off: Style, interval: Duration) -> Self {
+        self.attr(
+            Attribute::Custom(LABEL_BLINK),
+            AttrValue::Payload(PropPayload::Tup3((
+                PropValue::Style(on),
+                PropValue::Style(off),
+                PropValue::U64(interval.as_millis() as u64),
+            ))),
+        );
+        self
+    }
+
+    /// Truncate the text with an ellipsis (`…`) if it's wider than the area
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.attr(Attribute::Custom(LABEL_TRUNCATE), AttrValue::Flag(truncate));
+        self
+    }
+
+    fn is_truncate(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(LABEL_TRUNCATE), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    /// ### blink_style
+    ///
+    /// Get the blinking style active at `now`, if blink is set
+    fn blink_style(&mut self, now: Instant) -> Option<Style> {
+        match self
+            .props
+            .get(Attribute::Custom(LABEL_BLINK))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Tup3((
+                PropValue::Style(on),
+                PropValue::Style(off),
+                PropValue::U64(interval),
+            ))) => {
+                let since = *self.blink_since.get_or_insert(now);
+                let elapsed = now.saturating_duration_since(since).as_millis() as u64;
+                match (elapsed / interval.max(1)) % 2 {
+                    0 => Some(on),
+                    _ => Some(off),
+                }
+            }
+            _ => None,
+        }
+    }
 }
 
 impl MockComponent for Label {
@@ -72,17 +132,18 @@ impl MockComponent for Label {
                     AttrValue::TextModifiers(TextModifiers::empty()),
                 )
                 .unwrap_text_modifiers();
-            render.render_widget(
-                Paragraph::new(text)
-                    .style(
-                        Style::default()
-                            .fg(foreground)
-                            .bg(background)
-                            .add_modifier(modifiers),
-                    )
-                    .alignment(alignment),
-                area,
-            );
+            let text = match self.is_truncate() {
+                true => crate::utils::truncate_with_ellipsis(&text, area.width as usize),
+                false => text,
+            };
+            let mut style = Style::default()
+                .fg(foreground)
+                .bg(background)
+                .add_modifier(modifiers);
+            if let Some(blink) = self.blink_style(Instant::now()) {
+                style = style.patch(blink);
+            }
+            render.render_widget(Paragraph::new(text).style(style).alignment(alignment), area);
         }
     }
 
@@ -91,6 +152,10 @@ impl MockComponent for Label {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        if attr == Attribute::Custom(LABEL_BLINK) {
+            // Restart blinking
+            self.blink_since = None;
+        }
         self.props.set(attr, value)
     }
 
@@ -123,6 +188,100 @@ mod tests {
     }
 
     #[test]
+    fn test_components_label_alignment_and_background() {
+        let render = |component: &mut Label| {
+            let mut terminal = tuirealm::ratatui::Terminal::new(
+                tuirealm::ratatui::backend::TestBackend::new(9, 2),
+            )
+            .unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 9, 2)))
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let row = |buffer: &tuirealm::ratatui::buffer::Buffer| -> String {
+            (0..9).map(|x| buffer[(x, 0)].symbol()).collect()
+        };
+        let mut component = Label::default().background(Color::Blue).text("ready");
+        let buffer = render(&mut component);
+        assert_eq!(row(&buffer).as_str(), "ready    ");
+        // Background fills the entire area, not just the text
+        for (x, y) in [(0, 0), (8, 0), (0, 1), (8, 1)] {
+            assert_eq!(buffer[(x, y)].bg, Color::Blue);
+        }
+        let mut component = component.alignment(Alignment::Center);
+        assert_eq!(row(&render(&mut component)).as_str(), "  ready  ");
+        let mut component = component.alignment(Alignment::Right);
+        assert_eq!(row(&render(&mut component)).as_str(), "    ready");
+    }
+
+    #[test]
+    fn test_components_label_truncate() {
+        let component = Label::default().text("foobar");
+        assert_eq!(component.is_truncate(), false);
+        let render = |component: &mut Label| -> String {
+            let mut terminal = tuirealm::ratatui::Terminal::new(
+                tuirealm::ratatui::backend::TestBackend::new(6, 1),
+            )
+            .unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 4, 1)))
+                .unwrap();
+            let buffer = terminal.backend().buffer();
+            (0..6).map(|x| buffer[(x, 0)].symbol()).collect()
+        };
+        // Clipped
+        let mut component = component;
+        assert_eq!(render(&mut component).as_str(), "foob  ");
+        // Truncated with an ellipsis
+        let mut component = component.truncate(true);
+        assert_eq!(component.is_truncate(), true);
+        assert_eq!(render(&mut component).as_str(), "foo…  ");
+        // Fits
+        let mut component = component.text("foo");
+        assert_eq!(render(&mut component).as_str(), "foo   ");
+    }
+
+    #[test]
+    fn test_components_label_blink() {
+        let now = Instant::now();
+        let mut component = Label::default().text("RECORDING");
+        assert_eq!(component.blink_style(now), None);
+        let on = Style::default().fg(Color::Red);
+        let off = Style::default().fg(Color::Reset);
+        let mut component = component.blink(on, off, Duration::from_millis(500));
+        assert_eq!(component.blink_style(now), Some(on));
+        assert_eq!(
+            component.blink_style(now + Duration::from_millis(499)),
+            Some(on)
+        );
+        assert_eq!(
+            component.blink_style(now + Duration::from_millis(500)),
+            Some(off)
+        );
+        assert_eq!(
+            component.blink_style(now + Duration::from_millis(1000)),
+            Some(on)
+        );
+        // Setting blink again restarts it
+        component.attr(
+            Attribute::Custom(LABEL_BLINK),
+            AttrValue::Payload(PropPayload::Tup3((
+                PropValue::Style(on),
+                PropValue::Style(off),
+                PropValue::U64(500),
+            ))),
+        );
+        let later = now + Duration::from_millis(700);
+        assert_eq!(component.blink_style(later), Some(on));
+    }
+
+    #[test]
+    #[allow(
+        clippy::deref_addrof,
+        clippy::needless_borrows_for_generic_args,
+        clippy::useless_format
+    )]
     fn test_various_text_inputs() {
         let _ = Label::default().text("str");
         let _ = Label::default().text(*&"*&str");
@@ -3,22 +3,28 @@
 //! `LineGauge` is a line gauge
 
 use super::props::{
-    LINE_GAUGE_STYLE_DOUBLE, LINE_GAUGE_STYLE_NORMAL, LINE_GAUGE_STYLE_ROUND,
-    LINE_GAUGE_STYLE_THICK,
+    LINE_GAUGE_STEP, LINE_GAUGE_STYLE_DOUBLE, LINE_GAUGE_STYLE_NORMAL, LINE_GAUGE_STYLE_ROUND,
+    LINE_GAUGE_STYLE_THICK, LINE_GAUGE_VERTICAL,
 };
 
-use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::command::{Cmd, CmdResult, Direction};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
     TextModifiers,
 };
 use tuirealm::ratatui::{
+    buffer::Buffer,
     layout::Rect,
-    symbols::line::{Set, DOUBLE, NORMAL, ROUNDED, THICK},
+    symbols::{
+        bar,
+        line::{Set, DOUBLE, NORMAL, ROUNDED, THICK},
+    },
     widgets::LineGauge as TuiLineGauge,
 };
 use tuirealm::{Frame, MockComponent, State};
 
+use crate::utils::{get_progress, step_progress};
+
 // -- Component
 
 /// ## LineGauge
@@ -78,6 +84,65 @@ impl LineGauge {
         self
     }
 
+    /// Draw the gauge vertically, filling it from bottom to top.
+    /// The label, if any, is rendered at the top
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.attr(
+            Attribute::Custom(LINE_GAUGE_VERTICAL),
+            AttrValue::Flag(vertical),
+        );
+        self
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(LINE_GAUGE_VERTICAL),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    /// ### render_vertical
+    ///
+    /// Render the vertical gauge into `area`, filling block characters proportionally to `ratio`
+    fn render_vertical(buf: &mut Buffer, area: Rect, ratio: f64, label: &str, style: Style) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let mut gauge_area = area;
+        if !label.is_empty() {
+            buf.set_stringn(area.x, area.y, label, area.width as usize, Style::default());
+            gauge_area.y += 1;
+            gauge_area.height -= 1;
+        }
+        // Measure the fill in eighths of a cell, to render the partial top cell
+        let eighths = (ratio * f64::from(gauge_area.height) * 8.0).round() as u16;
+        let (full, partial) = (eighths / 8, eighths % 8);
+        for row in 0..gauge_area.height {
+            let symbol = if row < full {
+                bar::FULL
+            } else if row == full {
+                match partial {
+                    1 => bar::ONE_EIGHTH,
+                    2 => bar::ONE_QUARTER,
+                    3 => bar::THREE_EIGHTHS,
+                    4 => bar::HALF,
+                    5 => bar::FIVE_EIGHTHS,
+                    6 => bar::THREE_QUARTERS,
+                    7 => bar::SEVEN_EIGHTHS,
+                    _ => break,
+                }
+            } else {
+                break;
+            };
+            let y = gauge_area.bottom() - 1 - row;
+            for x in gauge_area.left()..gauge_area.right() {
+                buf[(x, y)].set_symbol(symbol).set_style(style);
+            }
+        }
+    }
+
     fn line_set(&self) -> Set {
         match self
             .props
@@ -95,19 +160,27 @@ impl LineGauge {
     }
 
     fn assert_line_style(s: u8) {
-        if !(&[
+        if ![
             LINE_GAUGE_STYLE_DOUBLE,
             LINE_GAUGE_STYLE_NORMAL,
             LINE_GAUGE_STYLE_ROUND,
             LINE_GAUGE_STYLE_THICK,
         ]
-        .iter()
-        .any(|x| *x == s))
+        .contains(&s)
         {
             panic!("Invalid line style");
         }
     }
 
+    /// Set the amount the progress is increased or decreased by on `Cmd::Move`
+    pub fn step(mut self, step: f64) -> Self {
+        self.attr(
+            Attribute::Custom(LINE_GAUGE_STEP),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(step))),
+        );
+        self
+    }
+
     fn assert_progress(p: f64) {
         if !(0.0..=1.0).contains(&p) {
             panic!("Progress value must be in range [0.0, 1.0]");
@@ -145,26 +218,23 @@ impl MockComponent for LineGauge {
                 .unwrap_text_modifiers();
             let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
             // Get percentage
-            let percentage = self
-                .props
-                .get_or(
-                    Attribute::Value,
-                    AttrValue::Payload(PropPayload::One(PropValue::F64(0.0))),
-                )
-                .unwrap_payload()
-                .unwrap_one()
-                .unwrap_f64();
+            let percentage = get_progress(&self.props);
             let div = crate::utils::get_block(borders, title, true, None);
+            let style = Style::default()
+                .fg(foreground)
+                .bg(background)
+                .add_modifier(modifiers);
+            if self.is_vertical() {
+                let inner = div.inner(area);
+                render.render_widget(div, area);
+                Self::render_vertical(render.buffer_mut(), inner, percentage, &label, style);
+                return;
+            }
             // Make progress bar
             render.render_widget(
                 TuiLineGauge::default()
                     .block(div)
-                    .filled_style(
-                        Style::default()
-                            .fg(foreground)
-                            .bg(background)
-                            .add_modifier(modifiers),
-                    )
+                    .filled_style(style)
                     .line_set(self.line_set())
                     .label(label)
                     .ratio(percentage),
@@ -195,8 +265,16 @@ impl MockComponent for LineGauge {
         State::None
     }
 
-    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
-        CmdResult::None
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match cmd {
+            Cmd::Move(Direction::Right) | Cmd::Move(Direction::Up) => {
+                step_progress(self, LINE_GAUGE_STEP, true)
+            }
+            Cmd::Move(Direction::Left) | Cmd::Move(Direction::Down) => {
+                step_progress(self, LINE_GAUGE_STEP, false)
+            }
+            _ => CmdResult::None,
+        }
     }
 }
 
@@ -221,6 +299,46 @@ mod test {
         assert_eq!(component.state(), State::None);
     }
 
+    #[test]
+    fn test_components_line_gauge_vertical() {
+        let component = LineGauge::default();
+        assert_eq!(component.is_vertical(), false);
+        let component = component.vertical(true);
+        assert_eq!(component.is_vertical(), true);
+        // Half filled, with label
+        let area = Rect::new(0, 0, 2, 5);
+        let mut buf = Buffer::empty(area);
+        LineGauge::render_vertical(&mut buf, area, 0.5, "50%", Style::default());
+        assert_eq!(buf, Buffer::with_lines(["50", "  ", "  ", "██", "██"]));
+        // Partial top cell
+        let mut buf = Buffer::empty(area);
+        LineGauge::render_vertical(&mut buf, area, 0.3, "", Style::default());
+        assert_eq!(buf, Buffer::with_lines(["  ", "  ", "  ", "▄▄", "██"]));
+        // Empty and full
+        let mut buf = Buffer::empty(area);
+        LineGauge::render_vertical(&mut buf, area, 0.0, "", Style::default());
+        assert_eq!(buf, Buffer::empty(area));
+        let mut buf = Buffer::empty(area);
+        LineGauge::render_vertical(&mut buf, area, 1.0, "", Style::default());
+        assert_eq!(buf, Buffer::with_lines(["██", "██", "██", "██", "██"]));
+    }
+
+    #[test]
+    fn test_components_line_gauge_step() {
+        let mut component = LineGauge::default().progress(0.5).step(0.25);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(get_progress(&component.props), 0.75);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(get_progress(&component.props), 0.5);
+        // Other commands are ignored
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+    }
     #[test]
     #[should_panic]
     fn line_gauge_bad_prog() {
@@ -4,26 +4,46 @@
 
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
-    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style, Table,
-    TextModifiers,
+    Alignment, AttrValue, Attribute, BorderSides, Borders, Color, PropPayload, PropValue, Props,
+    Style, Table, TextModifiers, TextSpan,
 };
 use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::{
     layout::Rect,
     text::Span,
-    widgets::{List as TuiList, ListItem, ListState},
+    widgets::{HighlightSpacing, List as TuiList, ListItem, ListState},
 };
 use tuirealm::{Frame, MockComponent, State, StateValue};
 
+use crate::utils::Theme;
+
+// -- Props
+use super::props::{
+    BORDERS_SIDES, LIST_EMPTY_TEXT, LIST_FILTER_QUERY, LIST_HIGHLIGHT_MODIFIERS, LIST_PINNED_ROWS,
+    LIST_PINNED_SELECTABLE, LIST_SCROLLBAR, LIST_SCROLL_HINTS, LIST_SCROLL_MARGIN,
+    LIST_STRICT_BOUNDARY,
+};
+
 // -- States
 
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListStates {
-    pub list_index: usize, // Index of selected item in list
-    pub list_len: usize,   // Lines in text area
+    pub list_index: usize,  // Index of selected item in list
+    pub list_len: usize,    // Lines in text area
+    pub list_offset: usize, // Index of the first visible item
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub last_area: Option<Rect>, // Area the list has been last rendered in
 }
 
 impl ListStates {
+    /// ### last_area
+    ///
+    /// Get the area the component has been last rendered in, if any
+    pub fn last_area(&self) -> Option<Rect> {
+        self.last_area
+    }
+
     /// ### set_list_len
     ///
     /// Set list length
@@ -73,6 +93,15 @@ impl ListStates {
         self.list_index = 0;
     }
 
+    /// ### list_index_at
+    ///
+    /// Set list index to the provided index, if in range
+    pub fn list_index_at(&mut self, index: usize) {
+        if index < self.list_len {
+            self.list_index = index;
+        }
+    }
+
     /// ### list_index_at_last
     ///
     /// Set list index at the last item of the list
@@ -109,6 +138,37 @@ impl ListStates {
             self.list_index
         }
     }
+
+    /// ### visible_range
+    ///
+    /// Get the range `(start, end)`, end excluded, of the items visible in a viewport `height` items tall,
+    /// scrolling from the current offset just enough to keep the selected item and `margin` items around it visible,
+    /// as when rendering with the same scroll margin (0 if not set)
+    #[must_use]
+    pub fn visible_range(&self, height: usize, margin: usize) -> (usize, usize) {
+        if height == 0 {
+            return (self.list_index, self.list_index);
+        }
+        let start = crate::utils::scroll_offset(
+            self.list_index,
+            self.list_offset,
+            self.list_len,
+            height,
+            margin,
+        );
+        (start, (start + height).min(self.list_len))
+    }
+
+    /// ### scroll_hints
+    ///
+    /// Get whether there are items above and below a viewport `height` items tall, starting at the current offset
+    #[must_use]
+    pub fn scroll_hints(&self, height: usize) -> (bool, bool) {
+        (
+            self.list_offset > 0,
+            self.list_offset + height < self.list_len,
+        )
+    }
 }
 
 // -- Component
@@ -134,6 +194,23 @@ impl List {
         self
     }
 
+    /// Set colors, borders and styles from `theme`
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(theme.foreground));
+        self.attr(Attribute::Background, AttrValue::Color(theme.background));
+        self.attr(Attribute::Borders, AttrValue::Borders(theme.borders()));
+        self.attr(
+            Attribute::TextProps,
+            AttrValue::TextModifiers(theme.modifiers),
+        );
+        self.attr(Attribute::FocusStyle, AttrValue::Style(theme.inactive));
+        self.attr(
+            Attribute::HighlightedColor,
+            AttrValue::Color(theme.highlighted_color),
+        );
+        self
+    }
+
     pub fn modifiers(mut self, m: TextModifiers) -> Self {
         self.attr(Attribute::TextProps, AttrValue::TextModifiers(m));
         self
@@ -144,6 +221,16 @@ impl List {
         self
     }
 
+    /// Set which sides of the block have a border (default all), keeping the other border properties.
+    /// The sides replace the ones of `borders()`, regardless of the order they're set in
+    pub fn borders_sides(mut self, sides: BorderSides) -> Self {
+        self.attr(
+            Attribute::Custom(BORDERS_SIDES),
+            AttrValue::Number(sides.bits() as isize),
+        );
+        self
+    }
+
     pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
         self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
         self
@@ -159,6 +246,16 @@ impl List {
         self
     }
 
+    /// If true, a move which hits a boundary of the list returns `CmdResult::Changed` with the unchanged state,
+    /// instead of `CmdResult::None`
+    pub fn strict_boundary(mut self, strict: bool) -> Self {
+        self.attr(
+            Attribute::Custom(LIST_STRICT_BOUNDARY),
+            AttrValue::Flag(strict),
+        );
+        self
+    }
+
     pub fn step(mut self, step: usize) -> Self {
         self.attr(Attribute::ScrollStep, AttrValue::Length(step));
         self
@@ -169,6 +266,32 @@ impl List {
         self
     }
 
+    /// Render a scrollbar on the right edge, reflecting the position of the cursor (works only if `scrollable`)
+    pub fn scrollbar(mut self, scrollbar: bool) -> Self {
+        self.attr(
+            Attribute::Custom(LIST_SCROLLBAR),
+            AttrValue::Flag(scrollbar),
+        );
+        self
+    }
+
+    /// Keep at least `margin` items visible above and below the selected one while scrolling (works only if `scrollable`).
+    /// The margin is clamped to half the viewport
+    pub fn scroll_margin(mut self, margin: usize) -> Self {
+        self.attr(
+            Attribute::Custom(LIST_SCROLL_MARGIN),
+            AttrValue::Length(margin),
+        );
+        self
+    }
+
+    /// Render ▲/▼ markers on the top/bottom border when there are items above/below the visible ones.
+    /// Lighter than a scrollbar; markers are hidden when all the items fit
+    pub fn scroll_hints(mut self, hints: bool) -> Self {
+        self.attr(Attribute::Custom(LIST_SCROLL_HINTS), AttrValue::Flag(hints));
+        self
+    }
+
     pub fn highlighted_str<S: Into<String>>(mut self, s: S) -> Self {
         self.attr(Attribute::HighlightedStr, AttrValue::String(s.into()));
         self
@@ -179,11 +302,65 @@ impl List {
         self
     }
 
+    /// Set the modifiers applied to the highlighted item while focused, in place of `REVERSED`
+    pub fn highlight_modifiers(mut self, m: TextModifiers) -> Self {
+        self.attr(
+            Attribute::Custom(LIST_HIGHLIGHT_MODIFIERS),
+            AttrValue::TextModifiers(m),
+        );
+        self
+    }
+
+    fn get_highlight_modifiers(&self) -> TextModifiers {
+        self.props
+            .get_or(
+                Attribute::Custom(LIST_HIGHLIGHT_MODIFIERS),
+                AttrValue::TextModifiers(TextModifiers::REVERSED),
+            )
+            .unwrap_text_modifiers()
+    }
+
+    /// Set the text displayed, centered in the block, when the list has no rows
+    pub fn empty_text<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(
+            Attribute::Custom(LIST_EMPTY_TEXT),
+            AttrValue::String(text.into()),
+        );
+        self
+    }
+
+    /// Only show the rows containing `query` (case insensitive) in any of their spans.
+    /// The content is kept intact; set an empty query to show all the rows again
+    pub fn filter_query<S: Into<String>>(mut self, query: S) -> Self {
+        self.attr(
+            Attribute::Custom(LIST_FILTER_QUERY),
+            AttrValue::String(query.into()),
+        );
+        self
+    }
+
     pub fn rows(mut self, rows: Table) -> Self {
         self.attr(Attribute::Content, AttrValue::Table(rows));
         self
     }
 
+    /// Set rows pinned above the scrollable rows (e.g. `.` and `..` in a file manager), which are always visible.
+    /// The pinned rows are not filtered by the filter query
+    pub fn pinned_rows(mut self, rows: Table) -> Self {
+        self.attr(Attribute::Custom(LIST_PINNED_ROWS), AttrValue::Table(rows));
+        self
+    }
+
+    /// If true, the pinned rows can be selected, coming before the scrollable rows in the index space;
+    /// otherwise navigation skips them (default `false`)
+    pub fn pinned_selectable(mut self, selectable: bool) -> Self {
+        self.attr(
+            Attribute::Custom(LIST_PINNED_SELECTABLE),
+            AttrValue::Flag(selectable),
+        );
+        self
+    }
+
     /// Set initial selected line
     /// This method must be called after `rows` and `scrollable` in order to work
     pub fn selected_line(mut self, line: usize) -> Self {
@@ -194,21 +371,236 @@ impl List {
         self
     }
 
+    /// ### row_at
+    ///
+    /// Get the index of the item rendered at the provided screen position, if any.
+    /// Use this to map mouse clicks to items, then select the item with `Cmd::GoTo(Position::At(index))`
+    pub fn row_at(&self, x: u16, y: u16) -> Option<usize> {
+        let last_area = self.states.last_area?;
+        let area = self.inner_area(last_area);
+        if x < area.left() || x >= area.right() || y < area.top() || y >= area.bottom() {
+            return None;
+        }
+        let body = self.body_area(last_area);
+        // Pinned rows are rendered above the scrollable ones
+        if y < body.top() {
+            let index = (y - area.y) as usize;
+            return (index < self.selectable_pinned()).then_some(index);
+        }
+        let index = self.selectable_pinned() + self.states.list_offset + (y - body.y) as usize;
+        (index < self.states.list_len).then_some(index)
+    }
+
+    /// ### inner_area
+    ///
+    /// Get the area the items are rendered in, given the component area
+    fn inner_area(&self, area: Rect) -> Rect {
+        let title = self
+            .props
+            .get_or(
+                Attribute::Title,
+                AttrValue::Title((String::default(), Alignment::Center)),
+            )
+            .unwrap_title();
+        let borders = crate::utils::get_borders(&self.props);
+        crate::utils::get_block(borders, Some(title), true, None).inner(area)
+    }
+
+    /// ### body_area
+    ///
+    /// Get the area the scrollable items are rendered in, below the pinned rows, given the component area
+    fn body_area(&self, area: Rect) -> Rect {
+        let inner = self.inner_area(area);
+        let pinned = (self.pinned_count() as u16).min(inner.height);
+        Rect {
+            y: inner.y + pinned,
+            height: inner.height - pinned,
+            ..inner
+        }
+    }
+
+    fn pinned_count(&self) -> usize {
+        match self.props.get_ref(Attribute::Custom(LIST_PINNED_ROWS)) {
+            Some(AttrValue::Table(table)) => table.len(),
+            _ => 0,
+        }
+    }
+
+    /// ### selectable_pinned
+    ///
+    /// Get the amount of pinned rows which come before the scrollable rows in the index space
+    fn selectable_pinned(&self) -> usize {
+        match self
+            .props
+            .get_or(
+                Attribute::Custom(LIST_PINNED_SELECTABLE),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+        {
+            true => self.pinned_count(),
+            false => 0,
+        }
+    }
+
+    /// ### body_states
+    ///
+    /// Get the states of the scrollable rows alone, without the selectable pinned rows
+    fn body_states(&self) -> ListStates {
+        let pinned = self.selectable_pinned();
+        ListStates {
+            list_index: self.states.list_index.saturating_sub(pinned),
+            list_len: self.states.list_len.saturating_sub(pinned),
+            list_offset: self.states.list_offset,
+            last_area: None,
+        }
+    }
+
+    /// ### make_items
+    ///
+    /// Make a list item for each row
+    fn make_items<'a>(
+        &self,
+        rows: impl Iterator<Item = &'a Vec<TextSpan>>,
+    ) -> Vec<ListItem<'static>> {
+        rows.map(|row| {
+            let columns: Vec<Span> = row
+                .iter()
+                .map(|col| {
+                    let (fg, bg, modifiers) = crate::utils::use_or_default_styles(&self.props, col);
+                    Span::styled(
+                        col.content.clone(),
+                        Style::default().add_modifier(modifiers).fg(fg).bg(bg),
+                    )
+                })
+                .collect();
+            ListItem::new(Spans::from(columns))
+        })
+        .collect()
+    }
+
     fn scrollable(&self) -> bool {
         self.props
             .get_or(Attribute::Scroll, AttrValue::Flag(false))
             .unwrap_flag()
     }
 
+    fn has_scrollbar(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(LIST_SCROLLBAR), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    fn has_scroll_hints(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(LIST_SCROLL_HINTS), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    /// ### draw_scroll_hints
+    ///
+    /// Draw the markers of the items above and below the visible ones on the top and bottom borders, if any
+    fn draw_scroll_hints(&self, render: &mut Frame, area: Rect, style: Style) {
+        let inner = self.inner_area(area);
+        let body = self.body_area(area);
+        if area.width < 3 || body.height == 0 {
+            return;
+        }
+        let (above, below) = self.body_states().scroll_hints(body.height as usize);
+        let x = area.right() - 2;
+        if above && inner.top() > area.top() {
+            render.buffer_mut().set_string(x, area.top(), "▲", style);
+        }
+        if below && inner.bottom() < area.bottom() {
+            render
+                .buffer_mut()
+                .set_string(x, area.bottom() - 1, "▼", style);
+        }
+    }
+
+    fn get_scroll_margin(&self) -> Option<usize> {
+        self.props
+            .get(Attribute::Custom(LIST_SCROLL_MARGIN))
+            .map(|x| x.unwrap_length())
+    }
+
     fn rewindable(&self) -> bool {
         self.props
             .get_or(Attribute::Rewind, AttrValue::Flag(false))
             .unwrap_flag()
     }
+
+    /// ### has_rows
+    ///
+    /// Returns whether the list has at least a non-empty visible row
+    fn has_rows(&self) -> bool {
+        match self.props.get_ref(Attribute::Content) {
+            Some(AttrValue::Table(table)) => self
+                .visible_rows()
+                .into_iter()
+                .any(|i| !table[i].is_empty()),
+            _ => false,
+        }
+    }
+
+    /// ### visible_rows
+    ///
+    /// Get the indexes in the content of the rows matching the filter query
+    fn visible_rows(&self) -> Vec<usize> {
+        crate::utils::filter_rows(&self.props, LIST_FILTER_QUERY)
+    }
+
+    /// ### original_index
+    ///
+    /// Get the index in the content of the selected row, or `None` if no row is visible
+    fn original_index(&self) -> Option<usize> {
+        // Selectable pinned rows come first, followed by the rows of the content
+        let pinned = self.selectable_pinned();
+        match self.states.list_index.checked_sub(pinned) {
+            Some(index) => self.visible_rows().get(index).map(|x| pinned + x),
+            None => Some(self.states.list_index),
+        }
+    }
+
+    /// ### visible_index
+    ///
+    /// Get the position in the list of the first visible row at or after `index`, as reported by `state()`
+    fn visible_index(&self, index: usize) -> Option<usize> {
+        let pinned = self.selectable_pinned();
+        match index.checked_sub(pinned) {
+            Some(index) => {
+                crate::utils::visible_position(&self.visible_rows(), index).map(|x| pinned + x)
+            }
+            None => Some(index),
+        }
+    }
+
+    fn is_strict_boundary(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(LIST_STRICT_BOUNDARY),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    /// ### move_result
+    ///
+    /// Get the result for a move or a scroll; if the cursor hit a boundary, `None` is returned,
+    /// unless `strict_boundary` is set
+    fn move_result(&self, prev: usize) -> CmdResult {
+        let moved = prev != self.states.list_index;
+        let result = match moved {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        };
+        crate::utils::boundary_result(moved, self.is_strict_boundary(), self.state(), result)
+    }
 }
 
 impl MockComponent for List {
     fn view(&mut self, render: &mut Frame, area: Rect) {
+        self.states.last_area = Some(area);
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
             let foreground = self
                 .props
@@ -232,10 +624,7 @@ impl MockComponent for List {
                     AttrValue::Title((String::default(), Alignment::Center)),
                 )
                 .unwrap_title();
-            let borders = self
-                .props
-                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
-                .unwrap_borders();
+            let borders = crate::utils::get_borders(&self.props);
             let focus = self
                 .props
                 .get_or(Attribute::Focus, AttrValue::Flag(false))
@@ -250,62 +639,125 @@ impl MockComponent for List {
             };
             let div = crate::utils::get_block(borders, Some(title), active, inactive_style);
             // Make list entries
-            let list_items: Vec<ListItem> =
-                match self.props.get(Attribute::Content).map(|x| x.unwrap_table()) {
-                    Some(table) => table
-                        .iter()
-                        .map(|row| {
-                            let columns: Vec<Span> = row
-                                .iter()
-                                .map(|col| {
-                                    let (fg, bg, modifiers) =
-                                        crate::utils::use_or_default_styles(&self.props, col);
-                                    Span::styled(
-                                        col.content.clone(),
-                                        Style::default().add_modifier(modifiers).fg(fg).bg(bg),
-                                    )
-                                })
-                                .collect();
-                            ListItem::new(Spans::from(columns))
-                        })
-                        .collect(), // Make List item from TextSpan
+            let list_items: Vec<ListItem> = match self.props.get_ref(Attribute::Content) {
+                Some(AttrValue::Table(table)) => {
+                    self.make_items(self.visible_rows().into_iter().map(|i| &table[i]))
+                }
+                _ => Vec::new(),
+            };
+            let pinned_items: Vec<ListItem> =
+                match self.props.get_ref(Attribute::Custom(LIST_PINNED_ROWS)) {
+                    Some(AttrValue::Table(table)) => self.make_items(table.iter()),
                     _ => Vec::new(),
                 };
+            // Placeholder when there are no rows
+            if let Some(text) = self
+                .props
+                .get(Attribute::Custom(LIST_EMPTY_TEXT))
+                .filter(|_| !self.has_rows() && pinned_items.is_empty())
+                .map(|x| x.unwrap_string())
+            {
+                let style = Style::default()
+                    .fg(foreground)
+                    .bg(background)
+                    .add_modifier(modifiers);
+                crate::utils::draw_empty_text(render, div.style(style), area, &text, style);
+                self.states.list_offset = 0;
+                return;
+            }
             let highlighted_color = self
                 .props
                 .get(Attribute::HighlightedColor)
                 .map(|x| x.unwrap_color());
             let modifiers = match focus {
-                true => modifiers | TextModifiers::REVERSED,
+                true => modifiers | self.get_highlight_modifiers(),
                 false => modifiers,
             };
-            // Make list
-
-            let mut list = TuiList::new(list_items)
-                .block(div)
-                .style(Style::default().fg(foreground).bg(background))
-                .direction(tuirealm::ratatui::widgets::ListDirection::TopToBottom);
-            if let Some(highlighted_color) = highlighted_color {
-                list = list.highlight_style(
-                    Style::default()
-                        .fg(highlighted_color)
-                        .add_modifier(modifiers),
-                );
-            }
+            let style = Style::default().fg(foreground).bg(background);
+            let highlight_style =
+                highlighted_color.map(|x| Style::default().fg(x).add_modifier(modifiers));
             // Highlighted symbol
             self.hg_str = self
                 .props
                 .get(Attribute::HighlightedStr)
                 .map(|x| x.unwrap_string());
-            if let Some(hg_str) = &self.hg_str {
-                list = list.highlight_symbol(hg_str);
+            // Pinned and scrollable rows are aligned, whichever has the selected item
+            let highlight_spacing = match pinned_items.is_empty() {
+                true => HighlightSpacing::WhenSelected,
+                false => HighlightSpacing::Always,
+            };
+            let hg_str = self.hg_str.clone();
+            let make_list = |items: Vec<ListItem<'static>>| {
+                let mut list = TuiList::new(items)
+                    .style(style)
+                    .direction(tuirealm::ratatui::widgets::ListDirection::TopToBottom)
+                    .highlight_spacing(highlight_spacing.clone());
+                if let Some(highlight_style) = highlight_style {
+                    list = list.highlight_style(highlight_style);
+                }
+                if let Some(hg_str) = &hg_str {
+                    list = list.highlight_symbol(hg_str);
+                }
+                list
+            };
+            // Make list
+            let body = self.body_area(area);
+            render.render_widget(div.style(style), area);
+            let pinned = self.selectable_pinned();
+            if !pinned_items.is_empty() {
+                let inner = self.inner_area(area);
+                let mut state: ListState = ListState::default();
+                state.select(
+                    (self.scrollable() && self.states.list_index < pinned)
+                        .then_some(self.states.list_index),
+                );
+                render.render_stateful_widget(
+                    make_list(pinned_items),
+                    Rect {
+                        height: body.y - inner.y,
+                        ..inner
+                    },
+                    &mut state,
+                );
             }
+            let list = make_list(list_items);
             if self.scrollable() {
+                let body_states = self.body_states();
                 let mut state: ListState = ListState::default();
-                state.select(Some(self.states.list_index));
-                render.render_stateful_widget(list, area, &mut state);
+                match self.states.list_index.checked_sub(pinned) {
+                    Some(index) => {
+                        state.select(Some(index));
+                        if let Some(margin) = self.get_scroll_margin() {
+                            *state.offset_mut() = crate::utils::scroll_offset(
+                                body_states.list_index,
+                                body_states.list_offset,
+                                body_states.list_len,
+                                body.height as usize,
+                                margin,
+                            );
+                        }
+                    }
+                    // Keep the scrollable rows still while a pinned row is selected
+                    None => *state.offset_mut() = self.states.list_offset,
+                }
+                render.render_stateful_widget(list, body, &mut state);
+                self.states.list_offset = state.offset();
+                if self.has_scroll_hints() {
+                    self.draw_scroll_hints(render, area, style);
+                }
+                if self.has_scrollbar() {
+                    crate::utils::draw_scrollbar(
+                        render,
+                        area,
+                        body,
+                        body_states.list_len,
+                        body.height as usize,
+                        body_states.list_index,
+                    );
+                }
             } else {
-                render.render_widget(list, area);
+                render.render_widget(list, body);
+                self.states.list_offset = 0;
             }
         }
     }
@@ -315,30 +767,40 @@ impl MockComponent for List {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        // Keep the selected row selected when the filter changes, if it's still visible
+        let selected = match attr == Attribute::Custom(LIST_FILTER_QUERY) {
+            true => self.original_index(),
+            false => None,
+        };
         self.props.set(attr, value);
-        if matches!(attr, Attribute::Content) {
+        if matches!(attr, Attribute::Content)
+            || attr == Attribute::Custom(LIST_FILTER_QUERY)
+            || attr == Attribute::Custom(LIST_PINNED_ROWS)
+            || attr == Attribute::Custom(LIST_PINNED_SELECTABLE)
+        {
             // Update list len and fix index
-            self.states.set_list_len(
-                match self.props.get(Attribute::Content).map(|x| x.unwrap_table()) {
-                    Some(spans) => spans.len(),
-                    _ => 0,
-                },
-            );
+            self.states
+                .set_list_len(self.selectable_pinned() + self.visible_rows().len());
+            if let Some(index) = selected.and_then(|x| self.visible_index(x)) {
+                self.states.list_index = index;
+            }
             self.states.fix_list_index();
         } else if matches!(attr, Attribute::Value) && self.scrollable() {
-            self.states.list_index = self
+            let line = self
                 .props
                 .get(Attribute::Value)
                 .map(|x| x.unwrap_payload().unwrap_one().unwrap_usize())
                 .unwrap_or(0);
+            // Select the first visible row from the provided line
+            self.states.list_index = self.visible_index(line).unwrap_or(line);
             self.states.fix_list_index();
         }
     }
 
     fn state(&self) -> State {
-        match self.scrollable() {
-            true => State::One(StateValue::Usize(self.states.list_index)),
-            false => State::None,
+        match (self.scrollable(), self.original_index()) {
+            (true, Some(index)) => State::One(StateValue::Usize(index)),
+            _ => State::None,
         }
     }
 
@@ -347,20 +809,12 @@ impl MockComponent for List {
             Cmd::Move(Direction::Down) => {
                 let prev = self.states.list_index;
                 self.states.incr_list_index(self.rewindable());
-                if prev != self.states.list_index {
-                    CmdResult::Changed(self.state())
-                } else {
-                    CmdResult::None
-                }
+                self.move_result(prev)
             }
             Cmd::Move(Direction::Up) => {
                 let prev = self.states.list_index;
                 self.states.decr_list_index(self.rewindable());
-                if prev != self.states.list_index {
-                    CmdResult::Changed(self.state())
-                } else {
-                    CmdResult::None
-                }
+                self.move_result(prev)
             }
             Cmd::Scroll(Direction::Down) => {
                 let prev = self.states.list_index;
@@ -368,13 +822,12 @@ impl MockComponent for List {
                     .props
                     .get_or(Attribute::ScrollStep, AttrValue::Length(8))
                     .unwrap_length();
-                let step: usize = self.states.calc_max_step_ahead(step);
-                (0..step).for_each(|_| self.states.incr_list_index(false));
-                if prev != self.states.list_index {
-                    CmdResult::Changed(self.state())
-                } else {
-                    CmdResult::None
+                // Wrap around only if the cursor is already at the boundary
+                match self.states.calc_max_step_ahead(step) {
+                    0 if self.rewindable() => self.states.incr_list_index(true),
+                    step => (0..step).for_each(|_| self.states.incr_list_index(false)),
                 }
+                self.move_result(prev)
             }
             Cmd::Scroll(Direction::Up) => {
                 let prev = self.states.list_index;
@@ -382,13 +835,12 @@ impl MockComponent for List {
                     .props
                     .get_or(Attribute::ScrollStep, AttrValue::Length(8))
                     .unwrap_length();
-                let step: usize = self.states.calc_max_step_behind(step);
-                (0..step).for_each(|_| self.states.decr_list_index(false));
-                if prev != self.states.list_index {
-                    CmdResult::Changed(self.state())
-                } else {
-                    CmdResult::None
+                // Wrap around only if the cursor is already at the boundary
+                match self.states.calc_max_step_behind(step) {
+                    0 if self.rewindable() => self.states.decr_list_index(true),
+                    step => (0..step).for_each(|_| self.states.decr_list_index(false)),
                 }
+                self.move_result(prev)
             }
             Cmd::GoTo(Position::Begin) => {
                 let prev = self.states.list_index;
@@ -408,6 +860,21 @@ impl MockComponent for List {
                     CmdResult::None
                 }
             }
+            Cmd::GoTo(Position::At(index)) => {
+                let prev = self.states.list_index;
+                // Targets out of range are clamped to the last item
+                self.states
+                    .list_index_at(index.min(self.states.list_len.saturating_sub(1)));
+                if prev != self.states.list_index {
+                    CmdResult::Changed(self.state())
+                } else {
+                    CmdResult::None
+                }
+            }
+            Cmd::Submit if self.scrollable() => match self.state() {
+                State::None => CmdResult::None,
+                state => CmdResult::Submit(state),
+            },
             _ => CmdResult::None,
         }
     }
@@ -419,6 +886,19 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
     use tuirealm::props::{TableBuilder, TextSpan};
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
+
+    /// Make `n` rows with a single cell, whose text is `cell(index)`
+    fn make_rows(n: usize, cell: impl Fn(usize) -> String) -> Table {
+        let mut rows = TableBuilder::default();
+        for i in 0..n {
+            if i > 0 {
+                rows.add_row();
+            }
+            rows.add_col(TextSpan::from(cell(i)));
+        }
+        rows.build()
+    }
 
     #[test]
     fn list_states() {
@@ -454,6 +934,60 @@ mod tests {
         assert_eq!(states.list_index, 2);
     }
 
+    #[test]
+    fn list_states_visible_range() {
+        let mut states = ListStates::default();
+        // Empty
+        assert_eq!(states.visible_range(5, 0), (0, 0));
+        states.set_list_len(20);
+        // Top
+        assert_eq!(states.visible_range(5, 0), (0, 5));
+        states.list_index = 4;
+        assert_eq!(states.visible_range(5, 0), (0, 5));
+        // Middle
+        states.list_index = 10;
+        assert_eq!(states.visible_range(5, 0), (6, 11));
+        // Bottom
+        states.list_index = 19;
+        assert_eq!(states.visible_range(5, 0), (15, 20));
+        // Taller than the list
+        assert_eq!(states.visible_range(30, 0), (0, 20));
+        // No viewport
+        assert_eq!(states.visible_range(0, 0), (19, 19));
+        // From the current offset, keeping the margin
+        states.list_offset = 5;
+        states.list_index = 6;
+        assert_eq!(states.visible_range(5, 0), (5, 10));
+        assert_eq!(states.visible_range(5, 2), (4, 9));
+        // Same offset as the rendered list
+        let mut component = List::default()
+            .scroll(true)
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .rows(make_rows(20, |i| i.to_string()));
+        component.states.list_index = 10;
+        let mut terminal = Terminal::new(TestBackend::new(5, 5)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 5, 5)))
+            .unwrap();
+        assert_eq!(
+            component.states.visible_range(5, 0).0,
+            component.states.list_offset
+        );
+        // Also with a scroll margin
+        let mut component = component.scroll_margin(2);
+        for _ in 0..3 {
+            component.perform(Cmd::Move(Direction::Up));
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 5, 5)))
+                .unwrap();
+            assert_eq!(
+                component.states.visible_range(5, 2).0,
+                component.states.list_offset
+            );
+        }
+        assert_eq!(component.states.list_offset, 5);
+    }
+
     #[test]
     fn test_components_list_scrollable() {
         let mut component = List::default()
@@ -617,6 +1151,53 @@ mod tests {
         assert_eq!(component.state(), State::None);
     }
 
+    #[test]
+    fn test_components_list_row_at() {
+        let mut component = List::default()
+            .borders(Borders::default())
+            .scroll(true)
+            .rows(make_rows(10, |i| format!("item {i}")));
+        // Not rendered yet
+        assert_eq!(component.row_at(1, 1), None);
+        let mut terminal = Terminal::new(TestBackend::new(20, 6)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 20, 6)))
+            .unwrap();
+        // Borders and outside
+        assert_eq!(component.row_at(1, 0), None);
+        assert_eq!(component.row_at(0, 1), None);
+        assert_eq!(component.row_at(1, 5), None);
+        assert_eq!(component.row_at(25, 1), None);
+        assert_eq!(component.row_at(1, 1), Some(0));
+        assert_eq!(component.row_at(1, 4), Some(3));
+        // Scroll, then click
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(State::One(StateValue::Usize(9)))
+        );
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 20, 6)))
+            .unwrap();
+        assert_eq!(component.states.list_offset, 6);
+        let index = component.row_at(1, 1).unwrap();
+        assert_eq!(index, 6);
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(index))),
+            CmdResult::Changed(State::One(StateValue::Usize(6)))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(index))),
+            CmdResult::None
+        );
+        // Out of range
+        // Out of range is clamped
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(10))),
+            CmdResult::Changed(State::One(StateValue::Usize(9)))
+        );
+        assert_eq!(component.states.list_index, 9);
+    }
+
     #[test]
     fn should_init_list_value() {
         let mut component = List::default()
@@ -668,4 +1249,535 @@ mod tests {
         );
         assert_eq!(component.states.list_index, 6);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_components_list_states_serde() {
+        let mut states = ListStates::default();
+        states.set_list_len(5);
+        states.list_index = 3;
+        let json = serde_json::to_string(&states).unwrap();
+        let restored: ListStates = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.list_index, 3);
+        assert_eq!(restored.list_len, 5);
+    }
+
+    #[test]
+    fn test_components_list_theme() {
+        let theme = Theme {
+            highlighted_color: Color::LightYellow,
+            modifiers: TextModifiers::BOLD,
+            ..Theme::default()
+        };
+        let component = List::default().theme(&theme);
+        assert_eq!(
+            component.query(Attribute::TextProps),
+            Some(AttrValue::TextModifiers(TextModifiers::BOLD))
+        );
+        assert_eq!(
+            component.query(Attribute::HighlightedColor),
+            Some(AttrValue::Color(Color::LightYellow))
+        );
+    }
+
+    #[test]
+    fn test_components_list_highlight_modifiers() {
+        let render = |component: &mut List| {
+            let mut terminal = Terminal::new(TestBackend::new(10, 4)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 10, 4)))
+                .unwrap();
+            terminal.backend().buffer()[(1, 1)].modifier
+        };
+        let mut component = List::default()
+            .highlighted_color(Color::Yellow)
+            .scroll(true)
+            .rows(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("row"))
+                    .build(),
+            );
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        // Default is unchanged
+        assert_eq!(component.get_highlight_modifiers(), TextModifiers::REVERSED);
+        assert!(render(&mut component).contains(TextModifiers::REVERSED));
+        let mut component =
+            component.highlight_modifiers(TextModifiers::BOLD | TextModifiers::UNDERLINED);
+        assert_eq!(
+            component.query(Attribute::Custom(LIST_HIGHLIGHT_MODIFIERS)),
+            Some(AttrValue::TextModifiers(
+                TextModifiers::BOLD | TextModifiers::UNDERLINED
+            ))
+        );
+        let modifier = render(&mut component);
+        assert!(modifier.contains(TextModifiers::BOLD | TextModifiers::UNDERLINED));
+        assert!(!modifier.contains(TextModifiers::REVERSED));
+    }
+
+    #[test]
+    fn test_components_list_strict_boundary() {
+        let mut component = List::default().scroll(true).step(4).rows(
+            TableBuilder::default()
+                .add_col(TextSpan::from("first"))
+                .add_row()
+                .add_col(TextSpan::from("second"))
+                .build(),
+        );
+        // Boundaries return None by default
+        assert_eq!(component.perform(Cmd::Move(Direction::Up)), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::None
+        );
+        let mut component = component.strict_boundary(true);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        // GoTo is not affected
+        assert_eq!(component.perform(Cmd::GoTo(Position::End)), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_list_empty_text() {
+        let render = |component: &mut List| {
+            let mut terminal = Terminal::new(TestBackend::new(14, 5)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 14, 5)))
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let row = |buffer: &tuirealm::ratatui::buffer::Buffer, y: u16| -> String {
+            (0..14).map(|x| buffer[(x, y)].symbol()).collect()
+        };
+        let mut component = List::default()
+            .rows(TableBuilder::default().build())
+            .empty_text("No results");
+        assert_eq!(
+            component.query(Attribute::Custom(LIST_EMPTY_TEXT)),
+            Some(AttrValue::String("No results".to_string()))
+        );
+        // Placeholder is centered in the block
+        let buffer = render(&mut component);
+        assert_eq!(row(&buffer, 2).as_str(), "│ No results │");
+        // Rows are rendered when there are any
+        let mut component = component.rows(
+            TableBuilder::default()
+                .add_col(TextSpan::from("first"))
+                .build(),
+        );
+        let buffer = render(&mut component);
+        assert_eq!(row(&buffer, 1).as_str(), "│first       │");
+        assert_eq!(row(&buffer, 2).as_str(), "│            │");
+    }
+
+    #[test]
+    fn test_components_list_scrollbar() {
+        let render = |component: &mut List| {
+            let mut terminal = Terminal::new(TestBackend::new(8, 5)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 8, 5)))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (1..4)
+                .map(|y| buffer[(7, y)].symbol().to_string())
+                .collect::<Vec<String>>()
+                .concat()
+        };
+        let rows = |n: usize| make_rows(n, |i| i.to_string());
+        let mut component = List::default().scroll(true).scrollbar(true).rows(rows(9));
+        assert_eq!(render(&mut component).as_str(), "█║║");
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(render(&mut component).as_str(), "║║█");
+        // Hidden if the content fits
+        let mut component = component.rows(rows(3));
+        assert_eq!(render(&mut component).as_str(), "│││");
+        // Hidden if not set
+        let mut component = component.scrollbar(false).rows(rows(9));
+        assert_eq!(render(&mut component).as_str(), "│││");
+    }
+
+    #[test]
+    fn test_components_list_submit() {
+        let rows = TableBuilder::default()
+            .add_col(TextSpan::from("first"))
+            .add_row()
+            .add_col(TextSpan::from("second"))
+            .build();
+        let mut component = List::default().scroll(true).rows(rows.clone());
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(1)))
+        );
+        // Not scrollable
+        let mut component = List::default().rows(rows);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_list_borders_sides() {
+        // Sides are kept regardless of the order
+        let mut component = List::default()
+            .borders_sides(BorderSides::BOTTOM)
+            .borders(Borders::default().color(Color::Red))
+            .rows(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("row"))
+                    .build(),
+            );
+        let borders = crate::utils::get_borders(&component.props);
+        assert_eq!(borders.sides, BorderSides::BOTTOM);
+        assert_eq!(borders.color, Color::Red);
+        let mut terminal = Terminal::new(TestBackend::new(5, 2)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 5, 2)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(0, 0)].symbol(), "r");
+        assert_eq!(buffer[(0, 1)].symbol(), "─");
+    }
+
+    #[test]
+    fn test_components_list_goto_at() {
+        let mut component = List::default().scroll(true).rows(
+            TableBuilder::default()
+                .add_col(TextSpan::from("first"))
+                .add_row()
+                .add_col(TextSpan::from("second"))
+                .add_row()
+                .add_col(TextSpan::from("third"))
+                .build(),
+        );
+        // In range
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(1))),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(1))),
+            CmdResult::None
+        );
+        // Out of range is clamped to the last item
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(100))),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(3))),
+            CmdResult::None
+        );
+        assert_eq!(component.states.list_index, 2);
+        // Empty
+        let mut component = List::default().scroll(true);
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(4))),
+            CmdResult::None
+        );
+        assert_eq!(component.states.list_index, 0);
+    }
+
+    #[test]
+    fn test_components_list_filter_query() {
+        let mut component = List::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .scroll(true)
+            .rows(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("apple"))
+                    .add_row()
+                    .add_col(TextSpan::from("banana"))
+                    .add_row()
+                    .add_col(TextSpan::from("cherry"))
+                    .add_row()
+                    .add_col(TextSpan::from("Avocado"))
+                    .build(),
+            )
+            .filter_query("a");
+        assert_eq!(component.states.list_len, 3);
+        // Only matching rows are rendered
+        let mut terminal = Terminal::new(TestBackend::new(8, 4)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 8, 4)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(0, 0)].symbol(), "a");
+        assert_eq!(buffer[(0, 1)].symbol(), "b");
+        assert_eq!(buffer[(0, 2)].symbol(), "A");
+        assert_eq!(buffer[(0, 3)].symbol(), " ");
+        // State reports the index in the original content
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.list_index, 2);
+        assert_eq!(component.state(), State::One(StateValue::Usize(3)));
+        // Filter changes keep the selected row selected
+        component.attr(
+            Attribute::Custom(LIST_FILTER_QUERY),
+            AttrValue::String(String::from("O")),
+        );
+        assert_eq!(component.states.list_len, 1);
+        assert_eq!(component.states.list_index, 0);
+        assert_eq!(component.state(), State::One(StateValue::Usize(3)));
+        // Nothing visible
+        let mut component = component.filter_query("kiwi");
+        assert_eq!(component.states.list_len, 0);
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        // Clearing the filter shows all the rows again
+        let mut component = component.filter_query("ch");
+        component.attr(
+            Attribute::Custom(LIST_FILTER_QUERY),
+            AttrValue::String(String::new()),
+        );
+        assert_eq!(component.states.list_len, 4);
+        assert_eq!(component.states.list_index, 2);
+        assert_eq!(component.state(), State::One(StateValue::Usize(2)));
+    }
+
+    #[test]
+    fn test_components_list_scroll_margin() {
+        // Rendering applies the margin
+        let mut component = List::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .scroll(true)
+            .scroll_margin(2)
+            .rows(make_rows(20, |i| i.to_string()));
+        let mut terminal = Terminal::new(TestBackend::new(10, 5)).unwrap();
+        for _ in 0..3 {
+            component.perform(Cmd::Move(Direction::Down));
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+                .unwrap();
+        }
+        assert_eq!(component.states.list_offset, 1);
+        component.perform(Cmd::GoTo(Position::End));
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+            .unwrap();
+        assert_eq!(component.states.list_offset, 15);
+        component.perform(Cmd::Move(Direction::Up));
+        component.perform(Cmd::Move(Direction::Up));
+        component.perform(Cmd::Move(Direction::Up));
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+            .unwrap();
+        assert_eq!(component.states.list_offset, 14);
+    }
+
+    #[test]
+    fn test_components_list_same_content() {
+        let rows = |n: usize| make_rows(n, |i| i.to_string());
+        let mut component = List::default().scroll(true).rows(rows(10));
+        component.perform(Cmd::GoTo(Position::At(8)));
+        let mut terminal = Terminal::new(TestBackend::new(10, 5)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+            .unwrap();
+        let offset = component.states.list_offset;
+        assert!(offset > 0);
+        // Setting the same content again keeps index and offset
+        component.attr(Attribute::Content, AttrValue::Table(rows(10)));
+        assert_eq!(component.states.list_index, 8);
+        assert_eq!(component.states.list_offset, offset);
+        assert_eq!(component.state(), State::One(StateValue::Usize(8)));
+        // Different content fixes the index
+        component.attr(Attribute::Content, AttrValue::Table(rows(5)));
+        assert_eq!(component.state(), State::One(StateValue::Usize(4)));
+    }
+
+    #[test]
+    fn test_components_list_scroll_rewind() {
+        let rows = make_rows(10, |i| i.to_string());
+        let mut component = List::default().scroll(true).step(4).rows(rows);
+        // Without rewind the step is clamped at the boundaries
+        component.perform(Cmd::GoTo(Position::At(8)));
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(9)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::None
+        );
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::None
+        );
+        // With rewind the cursor wraps, once at the boundary
+        let mut component = component.rewind(true);
+        component.perform(Cmd::GoTo(Position::At(8)));
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(9)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(9)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(5)))
+        );
+    }
+
+    #[test]
+    fn test_components_list_scroll_hints() {
+        let mut component = List::default()
+            .borders(Borders::default())
+            .scroll(true)
+            .scroll_hints(true)
+            .rows(make_rows(10, |i| format!("row {i}")));
+        let mut terminal = Terminal::new(TestBackend::new(10, 5)).unwrap();
+        let mut hints = |component: &mut List| {
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+                .unwrap();
+            let buffer = terminal.backend().buffer();
+            (
+                buffer[(8, 0)].symbol() == "▲",
+                buffer[(8, 4)].symbol() == "▼",
+            )
+        };
+        // Top
+        assert_eq!(hints(&mut component), (false, true));
+        // Middle
+        component.perform(Cmd::GoTo(Position::At(5)));
+        assert_eq!(hints(&mut component), (true, true));
+        // Bottom
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(hints(&mut component), (true, false));
+        // All the items fit
+        let mut component = component.rows(
+            TableBuilder::default()
+                .add_col(TextSpan::from("row"))
+                .build(),
+        );
+        assert_eq!(hints(&mut component), (false, false));
+        assert_eq!(component.states.scroll_hints(3), (false, false));
+    }
+
+    fn pinned_list(selectable: bool) -> List {
+        List::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .highlighted_color(Color::Yellow)
+            .scroll(true)
+            .rows(make_rows(6, |i| format!("file{i}")))
+            .pinned_rows(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("."))
+                    .add_row()
+                    .add_col(TextSpan::from(".."))
+                    .build(),
+            )
+            .pinned_selectable(selectable)
+    }
+
+    fn render_rows(component: &mut List, height: u16) -> Vec<(String, bool)> {
+        let mut terminal = Terminal::new(TestBackend::new(8, height)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 8, height)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .map(|y| {
+                let row: String = (0..8).map(|x| buffer[(x, y)].symbol()).collect();
+                (
+                    row.trim_end().to_string(),
+                    buffer[(0, y)].fg == Color::Yellow,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_components_list_pinned_rows() {
+        let mut component = pinned_list(false);
+        assert_eq!(component.states.list_len, 6);
+        // Pinned rows stay on top, the scrollable area shrinks
+        assert_eq!(
+            render_rows(&mut component, 4),
+            vec![
+                (String::from("."), false),
+                (String::from(".."), false),
+                (String::from("file0"), true),
+                (String::from("file1"), false),
+            ]
+        );
+        // Navigation skips the pinned rows
+        assert_eq!(component.perform(Cmd::Move(Direction::Up)), CmdResult::None);
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.state(), State::One(StateValue::Usize(5)));
+        assert_eq!(
+            render_rows(&mut component, 4),
+            vec![
+                (String::from("."), false),
+                (String::from(".."), false),
+                (String::from("file4"), false),
+                (String::from("file5"), true),
+            ]
+        );
+        // Pinned rows can't be clicked
+        assert_eq!(component.row_at(0, 0), None);
+        assert_eq!(component.row_at(0, 3), Some(5));
+    }
+
+    #[test]
+    fn test_components_list_pinned_rows_selectable() {
+        let mut component = pinned_list(true);
+        assert_eq!(component.states.list_len, 8);
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+        assert_eq!(render_rows(&mut component, 4)[0], (String::from("."), true));
+        // Pinned and scrollable rows are a single index space
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(
+            render_rows(&mut component, 4),
+            vec![
+                (String::from("."), false),
+                (String::from(".."), false),
+                (String::from("file0"), true),
+                (String::from("file1"), false),
+            ]
+        );
+        // Content indexes are mapped after the pinned rows
+        let mut component = component.filter_query("3");
+        assert_eq!(component.states.list_len, 3);
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.state(), State::One(StateValue::Usize(5)));
+        render_rows(&mut component, 4);
+        assert_eq!(component.row_at(0, 1), Some(1));
+        assert_eq!(component.row_at(0, 2), Some(2));
+        assert_eq!(component.row_at(0, 3), None);
+        // Initial selection in the same index space
+        let component = pinned_list(true).selected_line(4);
+        assert_eq!(component.state(), State::One(StateValue::Usize(4)));
+        let component = pinned_list(true).selected_line(1);
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
+    }
 }
@@ -28,11 +28,11 @@ pub mod states;
 
 // Exports
 pub use bar_chart::BarChart;
-pub use canvas::Canvas;
+pub use canvas::{Canvas, CanvasPainter};
 pub use chart::Chart;
 pub use checkbox::Checkbox;
 pub use container::Container;
-pub use input::Input;
+pub use input::{CursorStyle, Input};
 pub use label::Label;
 pub use line_gauge::LineGauge;
 pub use list::List;
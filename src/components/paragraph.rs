@@ -3,13 +3,13 @@
 //! `Paragraph` represents a read-only text component inside a container, the text is wrapped inside the container automatically
 //! using the [textwrap](https://docs.rs/textwrap/0.13.4/textwrap/) crate.
 //! The textarea supports multi-style spans.
-//! The component is not scrollable and doesn't handle any input. The text must then fit into the area.
-//! If you want scroll support, use a `Textarea` instead.
+//! The component is not scrollable by default; scrolling can be enabled with the `scroll` property.
+//! If wrapping is disabled, left-aligned text can be scrolled horizontally too.
 
-use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
-    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
-    TextModifiers, TextSpan,
+    Alignment, AttrValue, Attribute, BorderSides, Borders, Color, PropPayload, PropValue, Props,
+    Style, TextModifiers, TextSpan,
 };
 use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::{
@@ -18,6 +18,116 @@ use tuirealm::ratatui::{
     widgets::{Paragraph as TuiParagraph, Wrap},
 };
 use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+// -- Props
+use super::props::{
+    BORDERS_SIDES, PARAGRAPH_FOLLOW_TAIL, PARAGRAPH_HYPERLINKS, PARAGRAPH_LINE_ALIGNMENTS,
+    PARAGRAPH_LINK, PARAGRAPH_MARKDOWN, PARAGRAPH_WRAP,
+};
+
+// -- States
+
+#[derive(Default)]
+pub struct ParagraphStates {
+    pub offset: usize,   // Index of the first visible line
+    pub lines: usize,    // Lines in paragraph, after wrapping
+    pub height: usize,   // Lines visible in the paragraph area
+    pub h_offset: usize, // Index of the first visible column
+    pub columns: usize,  // Width of the longest line
+    pub width: usize,    // Columns visible in the paragraph area
+}
+
+impl ParagraphStates {
+    /// ### set_lines
+    ///
+    /// Set the amount of lines and the visible height and fix offset
+    pub fn set_lines(&mut self, lines: usize, height: usize) {
+        self.lines = lines;
+        self.height = height;
+        self.fix_offset();
+    }
+
+    /// ### scroll_down
+    ///
+    /// Scroll down by `step` lines, without moving past the last line
+    pub fn scroll_down(&mut self, step: usize) {
+        self.offset = (self.offset + step).min(self.max_offset());
+    }
+
+    /// ### scroll_up
+    ///
+    /// Scroll up by `step` lines
+    pub fn scroll_up(&mut self, step: usize) {
+        self.offset = self.offset.saturating_sub(step);
+    }
+
+    /// ### scroll_at_first
+    ///
+    /// Scroll to the first line
+    pub fn scroll_at_first(&mut self) {
+        self.offset = 0;
+    }
+
+    /// ### scroll_at_last
+    ///
+    /// Scroll so that the last line is at the bottom of the area
+    pub fn scroll_at_last(&mut self) {
+        self.offset = self.max_offset();
+    }
+
+    /// ### fix_offset
+    ///
+    /// Keep offset if possible, otherwise set it to the max offset
+    pub fn fix_offset(&mut self) {
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    /// ### set_columns
+    ///
+    /// Set the width of the longest line and the visible width and fix horizontal offset
+    pub fn set_columns(&mut self, columns: usize, width: usize) {
+        self.columns = columns;
+        self.width = width;
+        self.fix_h_offset();
+    }
+
+    /// ### scroll_right
+    ///
+    /// Scroll right by `step` columns, without moving past the end of the longest line
+    pub fn scroll_right(&mut self, step: usize) {
+        self.h_offset = (self.h_offset + step).min(self.max_h_offset());
+    }
+
+    /// ### scroll_left
+    ///
+    /// Scroll left by `step` columns
+    pub fn scroll_left(&mut self, step: usize) {
+        self.h_offset = self.h_offset.saturating_sub(step);
+    }
+
+    /// ### fix_h_offset
+    ///
+    /// Keep horizontal offset if possible, otherwise set it to the max horizontal offset
+    pub fn fix_h_offset(&mut self) {
+        self.h_offset = self.h_offset.min(self.max_h_offset());
+    }
+
+    /// ### is_at_bottom
+    ///
+    /// Whether the last line is visible
+    pub fn is_at_bottom(&self) -> bool {
+        self.offset >= self.max_offset()
+    }
+
+    fn max_offset(&self) -> usize {
+        self.lines.saturating_sub(self.height)
+    }
+
+    fn max_h_offset(&self) -> usize {
+        self.columns.saturating_sub(self.width)
+    }
+}
 
 // -- Component
 
@@ -27,6 +137,7 @@ use tuirealm::{Frame, MockComponent, State};
 #[derive(Default)]
 pub struct Paragraph {
     props: Props,
+    pub states: ParagraphStates,
 }
 
 impl Paragraph {
@@ -50,11 +161,37 @@ impl Paragraph {
         self
     }
 
+    /// Set which sides of the block have a border (default all), keeping the other border properties.
+    /// The sides replace the ones of `borders()`, regardless of the order they're set in
+    pub fn borders_sides(mut self, sides: BorderSides) -> Self {
+        self.attr(
+            Attribute::Custom(BORDERS_SIDES),
+            AttrValue::Number(sides.bits() as isize),
+        );
+        self
+    }
+
     pub fn alignment(mut self, a: Alignment) -> Self {
         self.attr(Attribute::Alignment, AttrValue::Alignment(a));
         self
     }
 
+    /// Set the alignment for each line of text, by position.
+    /// Lines without an alignment use the paragraph alignment
+    pub fn line_alignments(mut self, alignments: &[Alignment]) -> Self {
+        self.attr(
+            Attribute::Custom(PARAGRAPH_LINE_ALIGNMENTS),
+            AttrValue::Payload(PropPayload::Vec(
+                alignments
+                    .iter()
+                    .copied()
+                    .map(PropValue::Alignment)
+                    .collect(),
+            )),
+        );
+        self
+    }
+
     pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
         self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
         self
@@ -70,78 +207,279 @@ impl Paragraph {
         self
     }
 
+    /// Set whether to wrap the text into the area (default `true`).
+    /// If not wrapped, left-aligned text can be scrolled horizontally
     pub fn wrap(mut self, wrap: bool) -> Self {
-        self.attr(Attribute::TextWrap, AttrValue::Flag(wrap));
+        self.attr(Attribute::Custom(PARAGRAPH_WRAP), AttrValue::Flag(wrap));
+        self
+    }
+
+    /// Set whether to trim the leading whitespaces of rows when wrapping (default `false`)
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.attr(Attribute::TextWrap, AttrValue::Flag(trim));
+        self
+    }
+
+    /// Render each line as inline markdown (`**bold**`, `*italic*` and `` `code` ``)
+    pub fn markdown(mut self, markdown: bool) -> Self {
+        self.attr(
+            Attribute::Custom(PARAGRAPH_MARKDOWN),
+            AttrValue::Flag(markdown),
+        );
+        self
+    }
+
+    pub fn scroll(mut self, scrollable: bool) -> Self {
+        self.attr(Attribute::Scroll, AttrValue::Flag(scrollable));
         self
     }
+
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
+    /// Keep the view pinned to the last line when the text is updated, if it was at the bottom before the update
+    /// (e.g. to tail logs). If the user has scrolled up, the offset is preserved instead (works only if `scroll`)
+    pub fn follow_tail(mut self, follow: bool) -> Self {
+        self.attr(
+            Attribute::Custom(PARAGRAPH_FOLLOW_TAIL),
+            AttrValue::Flag(follow),
+        );
+        self
+    }
+
+    /// Make the text a hyperlink to `url`, using the OSC 8 escape sequence.
+    /// The link is rendered only if hyperlinks have been enabled with `enable_hyperlinks`
+    pub fn link<S: Into<String>>(mut self, url: S) -> Self {
+        self.attr(
+            Attribute::Custom(PARAGRAPH_LINK),
+            AttrValue::String(url.into()),
+        );
+        self
+    }
+
+    /// Enable hyperlinks rendering. Enable this only on terminals which support OSC 8 hyperlinks,
+    /// otherwise the escape sequences will corrupt the output
+    pub fn enable_hyperlinks(mut self, enable: bool) -> Self {
+        self.attr(
+            Attribute::Custom(PARAGRAPH_HYPERLINKS),
+            AttrValue::Flag(enable),
+        );
+        self
+    }
+
+    /// ### desired_height
+    ///
+    /// Get the height the paragraph needs to show all its text at `width`, borders and title included.
+    /// Lines are wrapped as when rendering, so the result can be used as a `Constraint::Length`
+    pub fn desired_height(&self, width: u16) -> u16 {
+        let borders = crate::utils::get_borders(&self.props);
+        let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+        let area = Rect::new(0, 0, width, u16::MAX);
+        let inner = crate::utils::get_block(borders, title, true, None).inner(area);
+        let lines = u16::try_from(self.wrapped_lines(inner.width as usize)).unwrap_or(u16::MAX);
+        lines.saturating_add(area.height - inner.height)
+    }
+
+    /// ### get_link
+    ///
+    /// Get the url to link the text to, if any and if hyperlinks are enabled
+    fn get_link(&self) -> Option<String> {
+        let enabled = self
+            .props
+            .get_or(
+                Attribute::Custom(PARAGRAPH_HYPERLINKS),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag();
+        match enabled {
+            true => self
+                .props
+                .get(Attribute::Custom(PARAGRAPH_LINK))
+                .map(|x| x.unwrap_string()),
+            false => None,
+        }
+    }
+
+    fn is_follow_tail(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(PARAGRAPH_FOLLOW_TAIL),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn scrollable(&self) -> bool {
+        self.props
+            .get_or(Attribute::Scroll, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    /// ### line_alignment
+    ///
+    /// Get the alignment for the line at `index`, if set
+    fn line_alignment(&self, index: usize) -> Option<Alignment> {
+        match self
+            .props
+            .get(Attribute::Custom(PARAGRAPH_LINE_ALIGNMENTS))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(alignments)) => match alignments.get(index) {
+                Some(PropValue::Alignment(alignment)) => Some(*alignment),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn is_markdown(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(PARAGRAPH_MARKDOWN),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn is_wrap(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(PARAGRAPH_WRAP), AttrValue::Flag(true))
+            .unwrap_flag()
+    }
+
+    fn is_trim(&self) -> bool {
+        self.props
+            .get_or(Attribute::TextWrap, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    /// ### longest_line
+    ///
+    /// Get the width of the longest line in the text
+    fn longest_line(&self) -> usize {
+        match self.props.get(Attribute::Text).map(|x| x.unwrap_payload()) {
+            Some(PropPayload::Vec(spans)) => spans
+                .iter()
+                .cloned()
+                .map(|x| x.unwrap_text_span().content.width())
+                .max()
+                .unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    /// ### make_paragraph
+    ///
+    /// Build the widget rendering the text, with the text properties and the wrap settings, but without block and scroll.
+    /// It's used to both render and measure the text, so that the wrapped lines are counted as they're rendered
+    fn make_paragraph(&self) -> TuiParagraph<'static> {
+        // Make text items
+        let markdown = self.is_markdown();
+        let text: Vec<Spans> = match self.props.get(Attribute::Text).map(|x| x.unwrap_payload()) {
+            Some(PropPayload::Vec(spans)) => spans
+                .iter()
+                .cloned()
+                .map(|x| x.unwrap_text_span())
+                .enumerate()
+                .map(|(i, x)| {
+                    let (fg, bg, modifiers) = crate::utils::use_or_default_styles(&self.props, &x);
+                    let style = Style::default().add_modifier(modifiers).fg(fg).bg(bg);
+                    let line = match markdown {
+                        true => Spans::from(
+                            crate::utils::parse_inline_markdown(&x.content)
+                                .into_iter()
+                                .map(|span| Span::styled(span.content, style.patch(span.style)))
+                                .collect::<Vec<Span>>(),
+                        ),
+                        false => Spans::from(vec![Span::styled(x.content, style)]),
+                    };
+                    // Lines without alignment inherit the paragraph alignment
+                    match self.line_alignment(i) {
+                        Some(alignment) => line.alignment(alignment),
+                        None => line,
+                    }
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        // Text properties
+        let alignment: Alignment = self
+            .props
+            .get_or(Attribute::Alignment, AttrValue::Alignment(Alignment::Left))
+            .unwrap_alignment();
+        let foreground = self
+            .props
+            .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+            .unwrap_color();
+        let background = self
+            .props
+            .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+            .unwrap_color();
+        let modifiers = self
+            .props
+            .get_or(
+                Attribute::TextProps,
+                AttrValue::TextModifiers(TextModifiers::empty()),
+            )
+            .unwrap_text_modifiers();
+        let paragraph = TuiParagraph::new(text)
+            .style(
+                Style::default()
+                    .fg(foreground)
+                    .bg(background)
+                    .add_modifier(modifiers),
+            )
+            .alignment(alignment);
+        match self.is_wrap() {
+            true => paragraph.wrap(Wrap {
+                trim: self.is_trim(),
+            }),
+            false => paragraph,
+        }
+    }
+
+    /// ### wrapped_lines
+    ///
+    /// Get the amount of lines the text takes once wrapped at `width`, as rendered
+    fn wrapped_lines(&self, width: usize) -> usize {
+        self.make_paragraph()
+            .line_count(u16::try_from(width).unwrap_or(u16::MAX))
+    }
 }
 
 impl MockComponent for Paragraph {
     fn view(&mut self, render: &mut Frame, area: Rect) {
         // Make a Span
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
-            // Make text items
-            let text: Vec<Spans> = match self.props.get(Attribute::Text).map(|x| x.unwrap_payload())
-            {
-                Some(PropPayload::Vec(spans)) => spans
-                    .iter()
-                    .cloned()
-                    .map(|x| x.unwrap_text_span())
-                    .map(|x| {
-                        let (fg, bg, modifiers) =
-                            crate::utils::use_or_default_styles(&self.props, &x);
-                        Spans::from(vec![Span::styled(
-                            x.content,
-                            Style::default().add_modifier(modifiers).fg(fg).bg(bg),
-                        )])
-                    })
-                    .collect(),
-                _ => Vec::new(),
-            };
-            // Text properties
+            let paragraph = self.make_paragraph();
             let alignment: Alignment = self
                 .props
                 .get_or(Attribute::Alignment, AttrValue::Alignment(Alignment::Left))
                 .unwrap_alignment();
-            // Wrap
-            let trim = self
-                .props
-                .get_or(Attribute::TextWrap, AttrValue::Flag(false))
-                .unwrap_flag();
-            let foreground = self
-                .props
-                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
-                .unwrap_color();
-            let background = self
-                .props
-                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
-                .unwrap_color();
-            let modifiers = self
-                .props
-                .get_or(
-                    Attribute::TextProps,
-                    AttrValue::TextModifiers(TextModifiers::empty()),
-                )
-                .unwrap_text_modifiers();
-            let borders = self
-                .props
-                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
-                .unwrap_borders();
+            let wrap = self.is_wrap();
+            let borders = crate::utils::get_borders(&self.props);
             let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
             let div = crate::utils::get_block(borders, title, true, None);
-            render.render_widget(
-                TuiParagraph::new(text)
-                    .block(div)
-                    .style(
-                        Style::default()
-                            .fg(foreground)
-                            .bg(background)
-                            .add_modifier(modifiers),
-                    )
-                    .alignment(alignment)
-                    .wrap(Wrap { trim }),
-                area,
-            );
+            // Update lines and fix scroll offset
+            let inner = div.inner(area);
+            self.states
+                .set_lines(paragraph.line_count(inner.width), inner.height as usize);
+            // NOTE: horizontal offset is only applied by ratatui to left-aligned, unwrapped lines
+            let columns = match wrap || alignment != Alignment::Left {
+                true => 0,
+                false => self.longest_line(),
+            };
+            self.states.set_columns(columns, inner.width as usize);
+            let (offset, h_offset) = match self.scrollable() {
+                true => (self.states.offset as u16, self.states.h_offset as u16),
+                false => (0, 0),
+            };
+            render.render_widget(paragraph.block(div).scroll((offset, h_offset)), area);
+            if let Some(url) = self.get_link() {
+                crate::utils::hyperlink_area(render.buffer_mut(), inner, &url);
+            }
         }
     }
 
@@ -150,14 +488,42 @@ impl MockComponent for Paragraph {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
-        self.props.set(attr, value)
+        let follow_tail = attr == Attribute::Text && self.scrollable() && self.is_follow_tail();
+        let at_bottom = self.states.is_at_bottom();
+        self.props.set(attr, value);
+        if follow_tail {
+            // Wrap the new text at the last rendered width
+            self.states
+                .set_lines(self.wrapped_lines(self.states.width), self.states.height);
+            if at_bottom {
+                self.states.scroll_at_last();
+            }
+        }
     }
 
     fn state(&self) -> State {
         State::None
     }
 
-    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if !self.scrollable() {
+            return CmdResult::None;
+        }
+        let step = self
+            .props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length();
+        match cmd {
+            Cmd::Move(Direction::Down) => self.states.scroll_down(1),
+            Cmd::Move(Direction::Up) => self.states.scroll_up(1),
+            Cmd::Move(Direction::Right) => self.states.scroll_right(1),
+            Cmd::Move(Direction::Left) => self.states.scroll_left(1),
+            Cmd::Scroll(Direction::Down) => self.states.scroll_down(step),
+            Cmd::Scroll(Direction::Up) => self.states.scroll_up(step),
+            Cmd::GoTo(Position::Begin) => self.states.scroll_at_first(),
+            Cmd::GoTo(Position::End) => self.states.scroll_at_last(),
+            _ => {}
+        }
         CmdResult::None
     }
 }
@@ -168,6 +534,7 @@ mod tests {
     use super::*;
 
     use pretty_assertions::assert_eq;
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
 
     #[test]
     fn test_components_paragraph() {
@@ -186,4 +553,273 @@ mod tests {
         // Get value
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    fn test_components_paragraph_trim() {
+        let component = || {
+            Paragraph::default()
+                .borders(Borders::default().sides(BorderSides::NONE))
+                .text(&[TextSpan::from("abc     def")])
+        };
+        let row = |mut component: Paragraph| {
+            let mut terminal = Terminal::new(TestBackend::new(5, 3)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 5, 3)))
+                .unwrap();
+            let buffer = terminal.backend().buffer();
+            (0..5).map(|x| buffer[(x, 1)].symbol()).collect::<String>()
+        };
+        assert_eq!(row(component()), "  def");
+        assert_eq!(row(component().trim(true)), "def  ");
+    }
+
+    #[test]
+    fn test_components_paragraph_line_alignments() {
+        let component = Paragraph::default()
+            .alignment(Alignment::Right)
+            .line_alignments(&[Alignment::Center, Alignment::Left, Alignment::Right]);
+        assert_eq!(
+            component.query(Attribute::Custom(PARAGRAPH_LINE_ALIGNMENTS)),
+            Some(AttrValue::Payload(PropPayload::Vec(vec![
+                PropValue::Alignment(Alignment::Center),
+                PropValue::Alignment(Alignment::Left),
+                PropValue::Alignment(Alignment::Right),
+            ])))
+        );
+        assert_eq!(component.line_alignment(0), Some(Alignment::Center));
+        assert_eq!(component.line_alignment(1), Some(Alignment::Left));
+        assert_eq!(component.line_alignment(2), Some(Alignment::Right));
+        assert_eq!(component.line_alignment(3), None);
+    }
+
+    #[test]
+    fn test_components_paragraph_markdown() {
+        let component = Paragraph::default().text(&[TextSpan::from("press **ESC**")]);
+        assert_eq!(component.is_markdown(), false);
+        let component = component.markdown(true);
+        assert_eq!(component.is_markdown(), true);
+    }
+
+    #[test]
+    fn test_components_paragraph_link() {
+        let component = Paragraph::default()
+            .link("https://github.com/veeso/tui-realm")
+            .enable_hyperlinks(false);
+        assert_eq!(component.get_link(), None);
+        let component = component.enable_hyperlinks(true);
+        assert_eq!(
+            component.get_link().as_deref(),
+            Some("https://github.com/veeso/tui-realm")
+        );
+    }
+
+    #[test]
+    fn test_components_paragraph_states() {
+        let mut states = ParagraphStates::default();
+        states.set_lines(10, 4);
+        assert_eq!(states.offset, 0);
+        states.scroll_down(1);
+        assert_eq!(states.offset, 1);
+        states.scroll_down(8);
+        assert_eq!(states.offset, 6);
+        states.scroll_up(2);
+        assert_eq!(states.offset, 4);
+        states.scroll_up(8);
+        assert_eq!(states.offset, 0);
+        states.scroll_at_last();
+        assert_eq!(states.offset, 6);
+        // Fix offset when lines shrink
+        states.set_lines(8, 4);
+        assert_eq!(states.offset, 4);
+        states.scroll_at_first();
+        assert_eq!(states.offset, 0);
+        // Text fits into area
+        states.set_lines(3, 4);
+        states.scroll_down(1);
+        assert_eq!(states.offset, 0);
+    }
+
+    #[test]
+    fn test_components_paragraph_h_scroll() {
+        let mut component = Paragraph::default()
+            .text(&[
+                TextSpan::from("lorem ipsum dolor sit amet"),
+                TextSpan::from("consectetur"),
+            ])
+            .wrap(false)
+            .scroll(true);
+        assert_eq!(component.longest_line(), 26);
+        // Not wrapped
+        assert_eq!(component.wrapped_lines(12), 2);
+        component.states.set_lines(component.wrapped_lines(12), 1);
+        component.states.set_columns(component.longest_line(), 20);
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.h_offset, 1);
+        (0..10).for_each(|_| {
+            component.perform(Cmd::Move(Direction::Right));
+        });
+        assert_eq!(component.states.h_offset, 6);
+        component.perform(Cmd::Move(Direction::Left));
+        assert_eq!(component.states.h_offset, 5);
+        // Composes with vertical scroll
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(component.states.offset, 1);
+        assert_eq!(component.states.h_offset, 5);
+        // Fix horizontal offset when area grows
+        component.states.set_columns(component.longest_line(), 24);
+        assert_eq!(component.states.h_offset, 2);
+    }
+
+    #[test]
+    fn test_components_paragraph_scroll() {
+        let mut component = Paragraph::default()
+            .text(&[
+                TextSpan::from("lorem ipsum dolor sit amet"),
+                TextSpan::from("consectetur"),
+            ])
+            .step(4);
+        assert_eq!(component.wrapped_lines(12), 4);
+        assert_eq!(component.wrapped_lines(80), 2);
+        component.states.set_lines(component.wrapped_lines(12), 2);
+        // Not scrollable
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.offset, 0);
+        // Scrollable
+        let mut component = component.scroll(true);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.offset, 1);
+        component.perform(Cmd::Scroll(Direction::Down));
+        assert_eq!(component.states.offset, 2);
+        component.perform(Cmd::Move(Direction::Up));
+        assert_eq!(component.states.offset, 1);
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.offset, 2);
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(component.states.offset, 0);
+    }
+
+    #[test]
+    fn test_components_paragraph_borders_sides() {
+        let mut component = Paragraph::default()
+            .borders_sides(BorderSides::LEFT)
+            .text(&[TextSpan::from("text")]);
+        assert_eq!(
+            crate::utils::get_borders(&component.props).sides,
+            BorderSides::LEFT
+        );
+        let mut terminal = Terminal::new(TestBackend::new(6, 1)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 6, 1)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(0, 0)].symbol(), "│");
+        assert_eq!(buffer[(1, 0)].symbol(), "t");
+    }
+
+    #[test]
+    fn test_components_paragraph_desired_height() {
+        let component = Paragraph::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .text(&[TextSpan::from("hello world foo"), TextSpan::from("bar")]);
+        // Narrow
+        assert_eq!(component.desired_height(7), 4);
+        // Wide
+        assert_eq!(component.desired_height(40), 2);
+        // Without wrapping each line takes a row
+        let component = component.wrap(false);
+        assert_eq!(component.desired_height(7), 2);
+        // Borders and title
+        let mut component = component
+            .wrap(true)
+            .borders(Borders::default())
+            .title("title", Alignment::Left);
+        assert_eq!(component.desired_height(9), 6);
+        assert_eq!(component.desired_height(40), 4);
+        // Matches the rendered text
+        let mut terminal = Terminal::new(TestBackend::new(9, 8)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 9, 6)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(1, 4)].symbol(), "b");
+        assert_eq!(buffer[(0, 5)].symbol(), "└");
+        // Markdown markers aren't rendered
+        let mut component = Paragraph::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .markdown(true)
+            .text(&[TextSpan::from("**ab** **cd**")]);
+        assert_eq!(component.desired_height(5), 1);
+        let mut terminal = Terminal::new(TestBackend::new(5, 1)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 5, 1)))
+            .unwrap();
+        let row: String = (0..5)
+            .map(|x| terminal.backend().buffer()[(x, 0)].symbol().to_string())
+            .collect();
+        assert_eq!(row.as_str(), "ab cd");
+        // Empty
+        let component = Paragraph::default();
+        assert_eq!(component.desired_height(10), 2);
+    }
+
+    #[test]
+    fn test_components_paragraph_follow_tail() {
+        let lines = |n: usize| -> Vec<TextSpan> {
+            (0..n)
+                .map(|x| TextSpan::from(format!("line {x}")))
+                .collect()
+        };
+        let mut component = Paragraph::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .scroll(true)
+            .follow_tail(true)
+            .text(&lines(8));
+        let mut terminal = Terminal::new(TestBackend::new(10, 4)).unwrap();
+        let mut render = |component: &mut Paragraph| {
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 10, 4)))
+                .unwrap();
+            terminal.backend().buffer()[(5, 3)].symbol().to_string()
+        };
+        render(&mut component);
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(render(&mut component).as_str(), "7");
+        // Pinned to the bottom
+        component.attr(
+            Attribute::Text,
+            AttrValue::Payload(PropPayload::Vec(
+                lines(10).into_iter().map(PropValue::TextSpan).collect(),
+            )),
+        );
+        assert_eq!(component.states.offset, 6);
+        assert_eq!(render(&mut component).as_str(), "9");
+        // Unpinned once scrolled up
+        component.perform(Cmd::Move(Direction::Up));
+        component.attr(
+            Attribute::Text,
+            AttrValue::Payload(PropPayload::Vec(
+                lines(12).into_iter().map(PropValue::TextSpan).collect(),
+            )),
+        );
+        assert_eq!(component.states.offset, 5);
+        assert_eq!(render(&mut component).as_str(), "8");
+        // Not following
+        let mut component = Paragraph::default().scroll(true).text(&lines(8));
+        component.states.set_lines(8, 4);
+        component.states.set_columns(0, 10);
+        component.perform(Cmd::GoTo(Position::End));
+        component.attr(
+            Attribute::Text,
+            AttrValue::Payload(PropPayload::Vec(
+                lines(10).into_iter().map(PropValue::TextSpan).collect(),
+            )),
+        );
+        assert_eq!(component.states.offset, 4);
+    }
 }
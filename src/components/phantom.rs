@@ -5,11 +5,19 @@
 //!
 //! An example would be a listener for `<ESC>` key to terminate the application.
 //! The Phantom allows you not to write a listener for each component for the `ESC` key, but just to subscribe the phantom to it.
+//! It can also work as an app-wide heartbeat, returning `CmdResult::Changed` on `Cmd::Tick` once every interval,
+//! and recognize key chords (e.g. `g g`), to implement vim-style keybindings across the whole application.
+
+use std::time::{Duration, Instant};
 
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::props::{AttrValue, Attribute, Props};
+use tuirealm::event::Key;
+use tuirealm::props::{AttrValue, Attribute, PropPayload, PropValue, Props};
 use tuirealm::ratatui::layout::Rect;
-use tuirealm::{Frame, MockComponent, State};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+// -- Props
+use super::props::{PHANTOM_CHORD_TIMEOUT, PHANTOM_INTERVAL};
 
 // -- Component
 
@@ -20,6 +28,133 @@ use tuirealm::{Frame, MockComponent, State};
 #[derive(Default)]
 pub struct Phantom {
     props: Props,
+    last_beat: Option<Instant>, // When the last heartbeat happened
+    beats: u64,                 // Heartbeats since the interval has been set
+    chords: Vec<(Vec<Key>, Cmd)>,
+    pending: Vec<Key>,              // Keys typed so far for a chord
+    pending_since: Option<Instant>, // When the first pending key has been typed
+}
+
+impl Phantom {
+    /// Custom command result returned by `perform` when a chord is completed, with the index of the chord
+    pub const CMD_RESULT_CHORD: &'static str = "chord";
+
+    /// Make the phantom return `CmdResult::Changed` on `Cmd::Tick`, once every `interval`
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.attr(
+            Attribute::Custom(PHANTOM_INTERVAL),
+            AttrValue::Payload(PropPayload::One(
+                PropValue::U64(interval.as_millis() as u64),
+            )),
+        );
+        self
+    }
+
+    /// Set the key chords to recognize, each one with the command it maps to.
+    /// Keys are fed to the phantom with `Cmd::Type`, so only `Key::Char` keys can be part of a chord
+    pub fn chords(mut self, chords: &[(&[Key], Cmd)]) -> Self {
+        self.chords = chords
+            .iter()
+            .map(|(keys, cmd)| (keys.to_vec(), *cmd))
+            .collect();
+        self.pending.clear();
+        self
+    }
+
+    /// Set the time to wait for the next key of a chord (default 1 second)
+    pub fn chord_timeout(mut self, timeout: Duration) -> Self {
+        self.attr(
+            Attribute::Custom(PHANTOM_CHORD_TIMEOUT),
+            AttrValue::Payload(PropPayload::One(PropValue::U64(timeout.as_millis() as u64))),
+        );
+        self
+    }
+
+    /// ### chord
+    ///
+    /// Get the command the chord at `index` maps to, if any
+    pub fn chord(&self, index: usize) -> Option<Cmd> {
+        self.chords.get(index).map(|(_, cmd)| *cmd)
+    }
+
+    /// ### key_at
+    ///
+    /// Feed a key typed at `now` to the chords recognizer.
+    /// Returns the index of the chord, when a chord is completed
+    fn key_at(&mut self, key: Key, now: Instant) -> Option<usize> {
+        // Reset buffer on timeout
+        if let Some(since) = self.pending_since {
+            if now.saturating_duration_since(since) > self.get_chord_timeout() {
+                self.pending.clear();
+            }
+        }
+        self.pending.push(key);
+        if !self.is_chord_prefix() {
+            // Mismatch; the key may start a new chord
+            self.pending = vec![key];
+        }
+        if self.pending.len() == 1 {
+            self.pending_since = Some(now);
+        }
+        let chord = self
+            .chords
+            .iter()
+            .position(|(keys, _)| keys == &self.pending);
+        if chord.is_some() || !self.is_chord_prefix() {
+            self.pending.clear();
+            self.pending_since = None;
+        }
+        chord
+    }
+
+    /// Returns whether pending keys are the beginning of a chord
+    fn is_chord_prefix(&self) -> bool {
+        self.chords
+            .iter()
+            .any(|(keys, _)| keys.starts_with(&self.pending))
+    }
+
+    fn get_chord_timeout(&self) -> Duration {
+        match self
+            .props
+            .get(Attribute::Custom(PHANTOM_CHORD_TIMEOUT))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::U64(timeout))) => Duration::from_millis(timeout),
+            _ => Duration::from_secs(1),
+        }
+    }
+
+    fn get_interval(&self) -> Option<Duration> {
+        match self
+            .props
+            .get(Attribute::Custom(PHANTOM_INTERVAL))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::U64(interval))) => {
+                Some(Duration::from_millis(interval))
+            }
+            _ => None,
+        }
+    }
+
+    /// ### tick
+    ///
+    /// Handle a tick at `now`; the first tick starts the timer
+    fn tick(&mut self, now: Instant) -> CmdResult {
+        let interval = match self.get_interval() {
+            Some(interval) => interval,
+            None => return CmdResult::None,
+        };
+        let last_beat = *self.last_beat.get_or_insert(now);
+        if now.saturating_duration_since(last_beat) >= interval && now > last_beat {
+            self.last_beat = Some(now);
+            self.beats += 1;
+            CmdResult::Changed(self.state())
+        } else {
+            CmdResult::None
+        }
+    }
 }
 
 impl MockComponent for Phantom {
@@ -30,15 +165,32 @@ impl MockComponent for Phantom {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        if attr == Attribute::Custom(PHANTOM_INTERVAL) {
+            // Restart heartbeat
+            self.last_beat = None;
+            self.beats = 0;
+        }
         self.props.set(attr, value)
     }
 
     fn state(&self) -> State {
-        State::None
+        match self.get_interval() {
+            Some(_) => State::One(StateValue::U64(self.beats)),
+            None => State::None,
+        }
     }
 
-    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
-        CmdResult::None
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match cmd {
+            Cmd::Tick => self.tick(Instant::now()),
+            Cmd::Type(ch) => match self.key_at(Key::Char(ch), Instant::now()) {
+                Some(chord) => {
+                    CmdResult::Custom(Self::CMD_RESULT_CHORD, State::One(StateValue::Usize(chord)))
+                }
+                None => CmdResult::None,
+            },
+            _ => CmdResult::None,
+        }
     }
 }
 
@@ -48,6 +200,7 @@ mod tests {
     use super::*;
 
     use pretty_assertions::assert_eq;
+    use tuirealm::command::Position;
 
     #[test]
     fn test_components_phantom() {
@@ -55,4 +208,90 @@ mod tests {
         // Get value
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    fn test_components_phantom_heartbeat() {
+        let mut component = Phantom::default();
+        // No interval
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        let mut component = component.interval(Duration::from_millis(500));
+        assert_eq!(component.state(), State::One(StateValue::U64(0)));
+        let now = Instant::now();
+        // First tick starts the timer
+        assert_eq!(component.tick(now), CmdResult::None);
+        assert_eq!(
+            component.tick(now + Duration::from_millis(200)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.tick(now + Duration::from_millis(500)),
+            CmdResult::Changed(State::One(StateValue::U64(1)))
+        );
+        assert_eq!(
+            component.tick(now + Duration::from_millis(700)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.tick(now + Duration::from_millis(1000)),
+            CmdResult::Changed(State::One(StateValue::U64(2)))
+        );
+        // Other commands are ignored
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        // Setting interval restarts heartbeat
+        let mut component = component.interval(Duration::from_millis(100));
+        assert_eq!(component.state(), State::One(StateValue::U64(0)));
+        assert_eq!(component.tick(now), CmdResult::None);
+        assert_eq!(
+            component.tick(now + Duration::from_millis(100)),
+            CmdResult::Changed(State::One(StateValue::U64(1)))
+        );
+    }
+
+    #[test]
+    fn test_components_phantom_chords() {
+        let mut component = Phantom::default()
+            .chords(&[
+                (
+                    &[Key::Char('g'), Key::Char('g')],
+                    Cmd::GoTo(Position::Begin),
+                ),
+                (&[Key::Char('d'), Key::Char('d')], Cmd::Delete),
+                (&[Key::Char('G')], Cmd::GoTo(Position::End)),
+            ])
+            .chord_timeout(Duration::from_millis(500));
+        assert_eq!(component.chord(0), Some(Cmd::GoTo(Position::Begin)));
+        assert_eq!(component.chord(3), None);
+        // Chords are recognized through perform
+        assert_eq!(component.perform(Cmd::Type('g')), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::Type('g')),
+            CmdResult::Custom(Phantom::CMD_RESULT_CHORD, State::One(StateValue::Usize(0)))
+        );
+        assert!(component.pending.is_empty());
+        let now = Instant::now();
+        // Single key chord
+        assert_eq!(component.key_at(Key::Char('G'), now), Some(2));
+        // Mismatch resets buffer, but the key can start a new chord
+        assert_eq!(component.key_at(Key::Char('g'), now), None);
+        assert_eq!(component.key_at(Key::Char('d'), now), None);
+        assert_eq!(component.pending, vec![Key::Char('d')]);
+        assert_eq!(component.key_at(Key::Char('d'), now), Some(1));
+        // Keys not in any chord are ignored
+        assert_eq!(component.key_at(Key::Char('x'), now), None);
+        assert!(component.pending.is_empty());
+        assert_eq!(component.key_at(Key::Esc, now), None);
+        // Timeout
+        assert_eq!(component.key_at(Key::Char('g'), now), None);
+        assert_eq!(
+            component.key_at(Key::Char('g'), now + Duration::from_millis(600)),
+            None
+        );
+        assert_eq!(component.pending, vec![Key::Char('g')]);
+        assert_eq!(
+            component.key_at(Key::Char('g'), now + Duration::from_millis(700)),
+            Some(0)
+        );
+        // No chords
+        assert_eq!(Phantom::default().perform(Cmd::Type('g')), CmdResult::None);
+    }
 }
@@ -2,14 +2,23 @@
 //!
 //! `ProgressBar` provides a component which shows the progress. It is possible to set the style for the progress bar and the text shown above it.
 
-use tuirealm::command::{Cmd, CmdResult};
+use std::collections::LinkedList;
+use tuirealm::command::{Cmd, CmdResult, Direction};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
     TextModifiers,
 };
-use tuirealm::ratatui::{layout::Rect, widgets::Gauge};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect, symbols::block, widgets::Gauge};
 use tuirealm::{Frame, MockComponent, State};
 
+use crate::utils::{get_progress, step_progress};
+
+// -- Props
+use super::props::{
+    PROGRESS_BAR_ANIMATE, PROGRESS_BAR_ANIMATED_LABEL, PROGRESS_BAR_ANIMATION_STEP,
+    PROGRESS_BAR_SEGMENTS, PROGRESS_BAR_STEP, PROGRESS_BAR_THRESHOLDS,
+};
+
 // -- Component
 
 /// ## ProgressBar
@@ -18,6 +27,7 @@ use tuirealm::{Frame, MockComponent, State};
 #[derive(Default)]
 pub struct ProgressBar {
     props: Props,
+    displayed_progress: f64, // Progress rendered at the last view; approaches the actual progress when animated
 }
 
 impl ProgressBar {
@@ -60,6 +70,202 @@ impl ProgressBar {
         self
     }
 
+    /// Set the fill colors to use when progress reaches the provided ratios.
+    /// The color of the highest threshold reached by the progress overrides the foreground
+    pub fn thresholds(mut self, thresholds: &[(f64, Color)]) -> Self {
+        let mut thresholds = thresholds.to_vec();
+        thresholds.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let list: LinkedList<PropPayload> = thresholds
+            .into_iter()
+            .map(|(ratio, color)| {
+                PropPayload::Tup2((PropValue::F64(ratio), PropValue::Color(color)))
+            })
+            .collect();
+        self.attr(
+            Attribute::Custom(PROGRESS_BAR_THRESHOLDS),
+            AttrValue::Payload(PropPayload::Linked(list)),
+        );
+        self
+    }
+
+    /// ### fill_color
+    ///
+    /// Get the fill color for the provided progress; `default` is returned if no threshold is reached
+    fn fill_color(&self, progress: f64, default: Color) -> Color {
+        match self
+            .props
+            .get(Attribute::Custom(PROGRESS_BAR_THRESHOLDS))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Linked(thresholds)) => thresholds
+                .iter()
+                .filter_map(|x| match x {
+                    PropPayload::Tup2((PropValue::F64(ratio), PropValue::Color(color))) => {
+                        Some((*ratio, *color))
+                    }
+                    _ => None,
+                })
+                .rev()
+                .find(|(ratio, _)| progress >= *ratio)
+                .map(|(_, color)| color)
+                .unwrap_or(default),
+            _ => default,
+        }
+    }
+
+    /// Split the bar into colored segments, rendered one after the other, as `(ratio, color)`.
+    /// Ratios exceeding the sum of 1.0 are clamped; the rest of the bar is the empty track.
+    /// When set, segments are rendered in place of the progress
+    pub fn segments(mut self, segments: &[(f64, Color)]) -> Self {
+        let mut total = 0.0;
+        let list: LinkedList<PropPayload> = segments
+            .iter()
+            .map(|(ratio, color)| {
+                let ratio = ratio.clamp(0.0, 1.0 - total);
+                total += ratio;
+                PropPayload::Tup2((PropValue::F64(ratio), PropValue::Color(*color)))
+            })
+            .collect();
+        self.attr(
+            Attribute::Custom(PROGRESS_BAR_SEGMENTS),
+            AttrValue::Payload(PropPayload::Linked(list)),
+        );
+        self
+    }
+
+    fn get_segments(&self) -> Option<Vec<(f64, Color)>> {
+        match self
+            .props
+            .get_ref(Attribute::Custom(PROGRESS_BAR_SEGMENTS))
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Linked(segments)) => Some(
+                segments
+                    .iter()
+                    .filter_map(|x| match x {
+                        PropPayload::Tup2((PropValue::F64(ratio), PropValue::Color(color))) => {
+                            Some((*ratio, *color))
+                        }
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// ### render_segments
+    ///
+    /// Render the segments into `area`, one after the other, with the label centered above them
+    fn render_segments(
+        buf: &mut Buffer,
+        area: Rect,
+        segments: &[(f64, Color)],
+        label: &str,
+        style: Style,
+    ) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        // Segments end at the cell nearest to their cumulative ratio, so that they never overlap
+        let mut total = 0.0;
+        let mut start = area.left();
+        for (ratio, color) in segments {
+            total += ratio;
+            let end = area.left() + (total * f64::from(area.width)).round() as u16;
+            for x in start..end.min(area.right()) {
+                for y in area.top()..area.bottom() {
+                    buf[(x, y)]
+                        .set_symbol(block::FULL)
+                        .set_style(style.fg(*color));
+                }
+            }
+            start = start.max(end);
+        }
+        let width = label.chars().count().min(area.width as usize) as u16;
+        buf.set_stringn(
+            area.x + (area.width - width) / 2,
+            area.y + area.height / 2,
+            label,
+            area.width as usize,
+            style,
+        );
+    }
+
+    /// Set the amount the progress is increased or decreased by on `Cmd::Move`
+    pub fn step(mut self, step: f64) -> Self {
+        self.attr(
+            Attribute::Custom(PROGRESS_BAR_STEP),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(step))),
+        );
+        self
+    }
+
+    /// Animate the fill towards the progress, moving it by the animation step at each `view()`,
+    /// instead of snapping to the progress (default `false`)
+    pub fn animate(mut self, animate: bool) -> Self {
+        self.attr(
+            Attribute::Custom(PROGRESS_BAR_ANIMATE),
+            AttrValue::Flag(animate),
+        );
+        self
+    }
+
+    /// Set the amount the displayed fill moves by at each `view()` while animated (default 0.05)
+    pub fn animation_step(mut self, step: f64) -> Self {
+        self.attr(
+            Attribute::Custom(PROGRESS_BAR_ANIMATION_STEP),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(step))),
+        );
+        self
+    }
+
+    /// If true, the label shows the percentage of the displayed fill, following the animation,
+    /// in place of the text set with `label()` (default `false`)
+    pub fn animated_label(mut self, animated: bool) -> Self {
+        self.attr(
+            Attribute::Custom(PROGRESS_BAR_ANIMATED_LABEL),
+            AttrValue::Flag(animated),
+        );
+        self
+    }
+
+    fn is_animated(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(PROGRESS_BAR_ANIMATE),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn get_animation_step(&self) -> f64 {
+        self.props
+            .get_or(
+                Attribute::Custom(PROGRESS_BAR_ANIMATION_STEP),
+                AttrValue::Payload(PropPayload::One(PropValue::F64(0.05))),
+            )
+            .unwrap_payload()
+            .unwrap_one()
+            .unwrap_f64()
+    }
+
+    /// ### next_displayed_progress
+    ///
+    /// Move the displayed progress towards the progress by the animation step, or snap it if not animated
+    fn next_displayed_progress(&mut self) -> f64 {
+        let target = get_progress(&self.props);
+        self.displayed_progress = match self.is_animated() {
+            true => {
+                let step = self.get_animation_step().abs();
+                let delta = (target - self.displayed_progress).clamp(-step, step);
+                self.displayed_progress + delta
+            }
+            false => target,
+        };
+        self.displayed_progress
+    }
+
     fn assert_progress(p: f64) {
         if !(0.0..=1.0).contains(&p) {
             panic!("Progress value must be in range [0.0, 1.0]");
@@ -97,23 +303,41 @@ impl MockComponent for ProgressBar {
                 .unwrap_borders();
             let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
             // Get percentage
-            let percentage = self
+            let percentage = self.next_displayed_progress();
+            let label = match self
                 .props
                 .get_or(
-                    Attribute::Value,
-                    AttrValue::Payload(PropPayload::One(PropValue::F64(0.0))),
+                    Attribute::Custom(PROGRESS_BAR_ANIMATED_LABEL),
+                    AttrValue::Flag(false),
                 )
-                .unwrap_payload()
-                .unwrap_one()
-                .unwrap_f64();
+                .unwrap_flag()
+            {
+                true => format!("{}%", (percentage * 100.0).round()),
+                false => label,
+            };
             let div = crate::utils::get_block(borders, title, true, None);
+            if let Some(segments) = self.get_segments() {
+                let inner = div.inner(area);
+                render.render_widget(div, area);
+                Self::render_segments(
+                    render.buffer_mut(),
+                    inner,
+                    &segments,
+                    &label,
+                    Style::default()
+                        .fg(foreground)
+                        .bg(background)
+                        .add_modifier(modifiers),
+                );
+                return;
+            }
             // Make progress bar
             render.render_widget(
                 Gauge::default()
                     .block(div)
                     .gauge_style(
                         Style::default()
-                            .fg(foreground)
+                            .fg(self.fill_color(percentage, foreground))
                             .bg(background)
                             .add_modifier(modifiers),
                     )
@@ -141,8 +365,16 @@ impl MockComponent for ProgressBar {
         State::None
     }
 
-    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
-        CmdResult::None
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match cmd {
+            Cmd::Move(Direction::Right) | Cmd::Move(Direction::Up) => {
+                step_progress(self, PROGRESS_BAR_STEP, true)
+            }
+            Cmd::Move(Direction::Left) | Cmd::Move(Direction::Down) => {
+                step_progress(self, PROGRESS_BAR_STEP, false)
+            }
+            _ => CmdResult::None,
+        }
     }
 }
 
@@ -166,6 +398,80 @@ mod test {
         assert_eq!(component.state(), State::None);
     }
 
+    #[test]
+    fn test_components_progress_bar_thresholds() {
+        let component = ProgressBar::default().foreground(Color::Green);
+        // No thresholds
+        assert_eq!(component.fill_color(0.95, Color::Green), Color::Green);
+        let component = component.thresholds(&[(0.9, Color::Red), (0.75, Color::Yellow)]);
+        assert_eq!(component.fill_color(0.0, Color::Green), Color::Green);
+        assert_eq!(component.fill_color(0.7, Color::Green), Color::Green);
+        assert_eq!(component.fill_color(0.75, Color::Green), Color::Yellow);
+        assert_eq!(component.fill_color(0.8, Color::Green), Color::Yellow);
+        assert_eq!(component.fill_color(0.9, Color::Green), Color::Red);
+        assert_eq!(component.fill_color(1.0, Color::Green), Color::Red);
+        // Empty thresholds
+        let component = component.thresholds(&[]);
+        assert_eq!(component.fill_color(0.95, Color::Green), Color::Green);
+    }
+
+    #[test]
+    fn test_components_progress_bar_step() {
+        let mut component = ProgressBar::default().progress(0.5).step(0.25);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(get_progress(&component.props), 0.75);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(get_progress(&component.props), 0.5);
+        // Other commands are ignored
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_progress_bar_animate() {
+        let mut component = ProgressBar::default()
+            .borders(Borders::default().sides(tuirealm::props::BorderSides::NONE))
+            .animate(true)
+            .animation_step(0.25)
+            .animated_label(true)
+            .progress(1.0);
+        let mut terminal =
+            tuirealm::ratatui::Terminal::new(tuirealm::ratatui::backend::TestBackend::new(8, 1))
+                .unwrap();
+        let mut render = |component: &mut ProgressBar| {
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 8, 1)))
+                .unwrap();
+            let label: String = (0..8)
+                .map(|x| terminal.backend().buffer()[(x, 0)].symbol().to_string())
+                .collect();
+            (
+                component.displayed_progress,
+                label.trim_matches([' ', '█']).to_string(),
+            )
+        };
+        // Approaches the target monotonically
+        let mut prev = 0.0;
+        for expected in [0.25, 0.5, 0.75, 1.0, 1.0] {
+            let (displayed, _) = render(&mut component);
+            assert!(displayed >= prev);
+            assert_eq!(displayed, expected);
+            prev = displayed;
+        }
+        // Back down; the label follows the displayed progress
+        let mut component = component.progress(0.5);
+        assert_eq!(render(&mut component), (0.75, "75%".to_string()));
+        assert_eq!(render(&mut component), (0.5, "50%".to_string()));
+        // Snap when not animated
+        let mut component = component.animate(false).progress(0.1);
+        assert_eq!(render(&mut component), (0.1, "10%".to_string()));
+    }
+
     #[test]
     #[should_panic]
     fn test_components_progress_bar_bad_prog() {
@@ -177,4 +483,32 @@ mod test {
             .label("60% - ETA 00:20")
             .borders(Borders::default());
     }
+
+    #[test]
+    fn test_components_progress_bar_segments() {
+        let mut component = ProgressBar::default()
+            .borders(Borders::default().sides(tuirealm::props::BorderSides::NONE))
+            .segments(&[(0.4, Color::Green), (0.2, Color::Yellow)]);
+        let mut terminal =
+            tuirealm::ratatui::Terminal::new(tuirealm::ratatui::backend::TestBackend::new(10, 1))
+                .unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 1)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let filled = |color: Color| {
+            (0..10)
+                .filter(|x| buffer[(*x, 0)].symbol() == block::FULL && buffer[(*x, 0)].fg == color)
+                .count()
+        };
+        assert_eq!(filled(Color::Green), 4);
+        assert_eq!(filled(Color::Yellow), 2);
+        assert_eq!(buffer[(6, 0)].symbol(), " ");
+        // Overflow is clamped
+        let component = component.segments(&[(0.75, Color::Green), (0.5, Color::Yellow)]);
+        assert_eq!(
+            component.get_segments(),
+            Some(vec![(0.75, Color::Green), (0.25, Color::Yellow)])
+        );
+    }
 }
@@ -6,10 +6,18 @@
 
 pub const BAR_CHART_BARS_GAP: &str = "bar-chart-bars-gap";
 pub const BAR_CHART_BARS_STYLE: &str = "bar-chart-bars-style";
+pub const BAR_CHART_GROUPED_DATA: &str = "bar-chart-grouped-data";
+pub const BAR_CHART_LABEL_ELLIPSIS: &str = "bar-chart-label-ellipsis";
 pub const BAR_CHART_LABEL_STYLE: &str = "bar-chart-label-style";
+pub const BAR_CHART_LABEL_TRUNCATE: &str = "bar-chart-label-truncate";
 pub const BAR_CHART_MAX_BARS: &str = "bar-chart-max-bars";
+pub const BAR_CHART_SERIES_COLORS: &str = "bar-chart-series-colors";
 pub const BAR_CHART_VALUES_STYLE: &str = "bar-chart-values-style";
 
+// -- borders
+
+pub const BORDERS_SIDES: &str = "borders-sides";
+
 // -- canvas
 
 pub const CANVAS_X_BOUNDS: &str = "x-bounds";
@@ -20,9 +28,14 @@ pub const CANVAS_MARKER_DOT: isize = 1;
 pub const CANVAS_MARKER_BLOCK: isize = 2;
 pub const CANVAS_MARKER_BAR: isize = 3;
 pub const CANVAS_MARKER_HALF_BLOCK: isize = 4;
+pub const CANVAS_WORLD_MAP: &str = "world-map";
+pub const CANVAS_GRID: &str = "grid";
+pub const CANVAS_AXES: &str = "axes";
 
 // -- chart
 
+pub const CHART_ANNOTATE_CURSOR: &str = "chart-annotate-cursor";
+pub const CHART_ANNOTATED_DATASET: &str = "chart-annotated-dataset";
 pub const CHART_X_BOUNDS: &str = "x-bounds";
 pub const CHART_Y_BOUNDS: &str = "y-bounds";
 pub const CHART_X_LABELS: &str = "x-labels";
@@ -31,12 +44,42 @@ pub const CHART_X_STYLE: &str = "x-style";
 pub const CHART_Y_STYLE: &str = "y-style";
 pub const CHART_X_TITLE: &str = "x-title";
 pub const CHART_Y_TITLE: &str = "y-titles";
+pub const CHART_Y_LOG: &str = "y-log";
+
+// -- checkbox
+
+pub const CHECKBOX_REPORT_LABELS: &str = "checkbox-report-labels";
+pub const CHECKBOX_STRICT_BOUNDARY: &str = "checkbox-strict-boundary";
+
+// -- container
+
+pub const CONTAINER_AGGREGATE_STATES: &str = "container-aggregate-states";
+pub const CONTAINER_SCROLL: &str = "container-scroll";
+pub const CONTAINER_SCROLL_STEP: &str = "container-scroll-step";
+pub const CONTAINER_SECTION_TITLES: &str = "container-section-titles";
 
 // -- input
 
+pub const INPUT_COMPLETIONS: &str = "completions";
+pub const INPUT_CURSOR_STYLE: &str = "cursor-style";
+pub const INPUT_DEBOUNCE: &str = "debounce";
+pub const INPUT_ERROR_MESSAGE: &str = "error-message";
+pub const INPUT_GHOST_SUFFIX: &str = "ghost-suffix";
+pub const INPUT_GHOST_SUFFIX_STYLE: &str = "ghost-suffix-style";
 pub const INPUT_INVALID_STYLE: &str = "invalid-style";
+pub const INPUT_MASK_EXCEPT_LAST: &str = "mask-except-last";
 pub const INPUT_PLACEHOLDER: &str = "placeholder";
 pub const INPUT_PLACEHOLDER_STYLE: &str = "placeholder-style";
+pub const INPUT_REPORT_CURSOR: &str = "report-cursor";
+pub const INPUT_REPORT_ON_VALUE_CHANGE: &str = "report-on-value-change";
+pub const INPUT_STEPPER: &str = "stepper";
+pub const INPUT_SUGGESTIONS: &str = "suggestions";
+pub const INPUT_WORD_BOUNDARIES: &str = "word-boundaries";
+
+// -- label
+
+pub const LABEL_BLINK: &str = "label-blink";
+pub const LABEL_TRUNCATE: &str = "label-truncate";
 
 // -- line gauge
 
@@ -44,7 +87,97 @@ pub const LINE_GAUGE_STYLE_NORMAL: u8 = 0;
 pub const LINE_GAUGE_STYLE_DOUBLE: u8 = 1;
 pub const LINE_GAUGE_STYLE_ROUND: u8 = 2;
 pub const LINE_GAUGE_STYLE_THICK: u8 = 3;
+pub const LINE_GAUGE_STEP: &str = "line-gauge-step";
+pub const LINE_GAUGE_VERTICAL: &str = "line-gauge-vertical";
+
+// -- list
+
+pub const LIST_EMPTY_TEXT: &str = "list-empty-text";
+pub const LIST_FILTER_QUERY: &str = "list-filter-query";
+pub const LIST_HIGHLIGHT_MODIFIERS: &str = "list-highlight-modifiers";
+pub const LIST_PINNED_ROWS: &str = "list-pinned-rows";
+pub const LIST_PINNED_SELECTABLE: &str = "list-pinned-selectable";
+pub const LIST_SCROLLBAR: &str = "list-scrollbar";
+pub const LIST_SCROLL_HINTS: &str = "list-scroll-hints";
+pub const LIST_SCROLL_MARGIN: &str = "list-scroll-margin";
+pub const LIST_STRICT_BOUNDARY: &str = "list-strict-boundary";
+
+// -- paragraph
+
+pub const PARAGRAPH_FOLLOW_TAIL: &str = "paragraph-follow-tail";
+pub const PARAGRAPH_HYPERLINKS: &str = "paragraph-hyperlinks";
+pub const PARAGRAPH_LINE_ALIGNMENTS: &str = "paragraph-line-alignments";
+pub const PARAGRAPH_LINK: &str = "paragraph-link";
+pub const PARAGRAPH_MARKDOWN: &str = "paragraph-markdown";
+pub const PARAGRAPH_WRAP: &str = "paragraph-wrap";
+
+// -- phantom
+
+pub const PHANTOM_CHORD_TIMEOUT: &str = "phantom-chord-timeout";
+pub const PHANTOM_INTERVAL: &str = "phantom-interval";
+
+// -- progress bar
+
+pub const PROGRESS_BAR_ANIMATE: &str = "progress-bar-animate";
+pub const PROGRESS_BAR_ANIMATED_LABEL: &str = "progress-bar-animated-label";
+pub const PROGRESS_BAR_ANIMATION_STEP: &str = "progress-bar-animation-step";
+pub const PROGRESS_BAR_SEGMENTS: &str = "progress-bar-segments";
+pub const PROGRESS_BAR_STEP: &str = "progress-bar-step";
+pub const PROGRESS_BAR_THRESHOLDS: &str = "progress-bar-thresholds";
+
+// -- radio
+
+pub const RADIO_REPORT_LABELS: &str = "radio-report-labels";
+
+// -- select
+
+pub const SELECT_CHOICE_STYLES: &str = "select-choice-styles";
+pub const SELECT_EMPTY_TEXT: &str = "select-empty-text";
+pub const SELECT_GROUPS: &str = "select-groups";
+pub const SELECT_QUICK_JUMP: &str = "select-quick-jump";
+pub const SELECT_STATE_AS_STRING: &str = "select-state-as-string";
+
+// -- span
+
+pub const SPAN_BADGE: &str = "span-badge";
+pub const SPAN_HYPERLINKS: &str = "span-hyperlinks";
+pub const SPAN_ICON: &str = "span-icon";
+pub const SPAN_LINK: &str = "span-link";
+pub const SPAN_TRUNCATE: &str = "span-truncate";
+
+// -- sparkline
+
+pub const SPARKLINE_MAX: &str = "sparkline-max";
+pub const SPARKLINE_RESOLUTION: &str = "sparkline-resolution";
+pub const SPARKLINE_REVERSED: &str = "sparkline-reversed";
+pub const SPARKLINE_THRESHOLD: &str = "sparkline-threshold";
+
+// -- spinner
+
+pub const SPINNER_DONE: &str = "spinner-done";
+pub const SPINNER_FRAMES: &str = "spinner-frames";
+pub const SPINNER_LABEL: &str = "spinner-label";
+pub const SPINNER_PROGRESS: &str = "spinner-progress";
+pub const SPINNER_TICKS_PER_FRAME: &str = "spinner-ticks-per-frame";
 
 // -- table
 
+pub const TABLE_ACTIONS: &str = "table-actions";
+pub const TABLE_ACTION_HINTS: &str = "table-action-hints";
 pub const TABLE_COLUMN_SPACING: &str = "col-spacing";
+pub const TABLE_EMPTY_TEXT: &str = "table-empty-text";
+pub const TABLE_FILTER_QUERY: &str = "table-filter-query";
+pub const TABLE_HIGHLIGHT_MODIFIERS: &str = "table-highlight-modifiers";
+pub const TABLE_REPORT_ROW: &str = "table-report-row";
+pub const TABLE_RESIZABLE_COLUMNS: &str = "table-resizable-columns";
+pub const TABLE_SCROLLBAR: &str = "table-scrollbar";
+pub const TABLE_SCROLL_MARGIN: &str = "table-scroll-margin";
+pub const TABLE_STRICT_BOUNDARY: &str = "table-strict-boundary";
+pub const TABLE_UNFOCUSED_HIGHLIGHT: &str = "table-unfocused-highlight";
+pub const TABLE_WRAP_CELLS: &str = "table-wrap-cells";
+
+// -- textarea
+
+pub const TEXTAREA_SCROLLBAR: &str = "textarea-scrollbar";
+pub const TEXTAREA_TAB_WIDTH: &str = "textarea-tab-width";
+pub const TEXTAREA_WRAP_MARKER: &str = "textarea-wrap-marker";
@@ -34,12 +34,18 @@ use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::{layout::Rect, widgets::Tabs};
 use tuirealm::{Frame, MockComponent, State, StateValue};
 
+use crate::utils::Theme;
+
+// -- Props
+use super::props::RADIO_REPORT_LABELS;
+
 // -- states
 
 /// ## RadioStates
 ///
 /// RadioStates contains states for this component
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RadioStates {
     pub choice: usize,        // Selected option
     pub choices: Vec<String>, // Available choices
@@ -113,6 +119,15 @@ impl Radio {
         self
     }
 
+    /// Set colors, borders and styles from `theme`
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(theme.foreground));
+        self.attr(Attribute::Background, AttrValue::Color(theme.background));
+        self.attr(Attribute::Borders, AttrValue::Borders(theme.borders()));
+        self.attr(Attribute::FocusStyle, AttrValue::Style(theme.inactive));
+        self
+    }
+
     pub fn borders(mut self, b: Borders) -> Self {
         self.attr(Attribute::Borders, AttrValue::Borders(b));
         self
@@ -128,11 +143,32 @@ impl Radio {
         self
     }
 
+    /// If true, the radio group is rendered as inactive and ignores all commands
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    /// If true, choices which don't fit the width are wrapped on the next lines, instead of being clipped
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.attr(Attribute::TextWrap, AttrValue::Flag(wrap));
+        self
+    }
+
     pub fn rewind(mut self, r: bool) -> Self {
         self.attr(Attribute::Rewind, AttrValue::Flag(r));
         self
     }
 
+    /// Make the state report the text of the selected choice, instead of its index (default `false`)
+    pub fn report_labels(mut self, report: bool) -> Self {
+        self.attr(
+            Attribute::Custom(RADIO_REPORT_LABELS),
+            AttrValue::Flag(report),
+        );
+        self
+    }
+
     pub fn choices<S: AsRef<str>>(mut self, choices: &[S]) -> Self {
         self.attr(
             Attribute::Content,
@@ -155,11 +191,39 @@ impl Radio {
         self
     }
 
+    fn is_wrap(&self) -> bool {
+        self.props
+            .get_or(Attribute::TextWrap, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
     fn is_rewind(&self) -> bool {
         self.props
             .get_or(Attribute::Rewind, AttrValue::Flag(false))
             .unwrap_flag()
     }
+
+    fn is_report_labels(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(RADIO_REPORT_LABELS),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    /// ### move_result
+    ///
+    /// Get the result for a move; moves always return `Changed`, even if the choice hit a boundary
+    fn move_result(&self) -> CmdResult {
+        CmdResult::Changed(self.state())
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.props
+            .get_or(Attribute::Disabled, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
 }
 
 impl MockComponent for Radio {
@@ -185,10 +249,12 @@ impl MockComponent for Radio {
                 .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
                 .unwrap_borders();
             let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            // Disabled components are rendered as inactive
             let focus = self
                 .props
                 .get_or(Attribute::Focus, AttrValue::Flag(false))
-                .unwrap_flag();
+                .unwrap_flag()
+                && !self.is_disabled();
             let inactive_style = self
                 .props
                 .get(Attribute::FocusStyle)
@@ -203,11 +269,33 @@ impl MockComponent for Radio {
                 true => TextModifiers::REVERSED,
                 false => TextModifiers::empty(),
             };
+            let style = Style::default().fg(block_color).bg(background);
+            let highlight_style = Style::default().fg(fg).add_modifier(modifiers);
+            if self.is_wrap() {
+                let inner = div.inner(area);
+                render.render_widget(div.style(style), area);
+                let widths: Vec<usize> = choices.iter().map(|x| x.width()).collect();
+                let lines = crate::utils::wrap_tabs(&widths, inner.width as usize);
+                for (row, range) in lines.into_iter().take(inner.height as usize).enumerate() {
+                    let selected = range
+                        .contains(&self.states.choice)
+                        .then(|| self.states.choice - range.start);
+                    let radio: Tabs = Tabs::new(choices[range].to_vec())
+                        .select(selected)
+                        .style(style)
+                        .highlight_style(highlight_style);
+                    render.render_widget(
+                        radio,
+                        Rect::new(inner.x, inner.y + row as u16, inner.width, 1),
+                    );
+                }
+                return;
+            }
             let radio: Tabs = Tabs::new(choices)
                 .block(div)
                 .select(self.states.choice)
-                .style(Style::default().fg(block_color).bg(background))
-                .highlight_style(Style::default().fg(fg).add_modifier(modifiers));
+                .style(style)
+                .highlight_style(highlight_style);
             render.render_widget(radio, area);
         }
     }
@@ -239,22 +327,33 @@ impl MockComponent for Radio {
     }
 
     fn state(&self) -> State {
-        State::One(StateValue::Usize(self.states.choice))
+        match self.is_report_labels() {
+            true => self
+                .states
+                .choices
+                .get(self.states.choice)
+                .map(|x| State::One(StateValue::String(x.clone())))
+                .unwrap_or(State::None),
+            false => State::One(StateValue::Usize(self.states.choice)),
+        }
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if self.is_disabled() {
+            return CmdResult::None;
+        }
         match cmd {
             Cmd::Move(Direction::Right) => {
                 // Increment choice
                 self.states.next_choice(self.is_rewind());
                 // Return CmdResult On Change
-                CmdResult::Changed(self.state())
+                self.move_result()
             }
             Cmd::Move(Direction::Left) => {
                 // Decrement choice
                 self.states.prev_choice(self.is_rewind());
                 // Return CmdResult On Change
-                CmdResult::Changed(self.state())
+                self.move_result()
             }
             Cmd::Submit => {
                 // Return Submit
@@ -272,6 +371,7 @@ mod test {
 
     use pretty_assertions::assert_eq;
     use tuirealm::props::{PropPayload, PropValue};
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
 
     #[test]
     fn test_components_radio_states() {
@@ -386,4 +486,80 @@ mod test {
             CmdResult::Submit(State::One(StateValue::Usize(2))),
         );
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_components_radio_states_serde() {
+        let mut states = RadioStates::default();
+        states.set_choices(&["lemon".to_string(), "strawberry".to_string()]);
+        states.select(1);
+        let json = serde_json::to_string(&states).unwrap();
+        let restored: RadioStates = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.choices, states.choices);
+        assert_eq!(restored.choice, 1);
+    }
+
+    #[test]
+    fn test_components_radio_disabled() {
+        let mut component = Radio::default().choices(&["Oui!", "Non"]).disabled(true);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+        let mut component = component.disabled(false);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+    }
+
+    #[test]
+    fn test_components_radio_report_labels() {
+        let mut component = Radio::default()
+            .choices(&["Oui!", "Non", "Peut-être"])
+            .value(1);
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
+        component = component.report_labels(true);
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(String::from("Non")))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("Peut-être"))))
+        );
+        // No choices
+        let component = Radio::default().report_labels(true);
+        assert_eq!(component.state(), State::None);
+    }
+
+    #[test]
+    fn test_components_radio_wrap() {
+        let mut component = Radio::default()
+            .borders(Borders::default())
+            .choices(&["alpha", "beta", "gamma", "delta"])
+            .wrap(true);
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        // " alpha │ beta " fits 16 columns, " gamma │ delta " goes on the next line
+        let mut terminal = Terminal::new(TestBackend::new(18, 4)).unwrap();
+        let mut render = |component: &mut Radio| {
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 18, 4)))
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let buffer = render(&mut component);
+        let row = |y: u16| -> String { (1..17).map(|x| buffer[(x, y)].symbol()).collect() };
+        assert_eq!(row(1).trim_end(), " alpha │ beta");
+        assert_eq!(row(2).trim_end(), " gamma │ delta");
+        assert!(buffer[(2, 1)].modifier.contains(TextModifiers::REVERSED));
+        // Selection flows to the next line
+        component.perform(Cmd::Move(Direction::Right));
+        component.perform(Cmd::Move(Direction::Right));
+        let buffer = render(&mut component);
+        assert!(!buffer[(2, 1)].modifier.contains(TextModifiers::REVERSED));
+        assert!(buffer[(2, 2)].modifier.contains(TextModifiers::REVERSED));
+    }
 }
@@ -15,12 +15,21 @@ use tuirealm::ratatui::{
 };
 use tuirealm::{Frame, MockComponent, State, StateValue};
 
+use crate::utils::Theme;
+
+// -- Props
+use super::props::{
+    SELECT_CHOICE_STYLES, SELECT_EMPTY_TEXT, SELECT_GROUPS, SELECT_QUICK_JUMP,
+    SELECT_STATE_AS_STRING,
+};
+
 // -- states
 
 /// ## SelectStates
 ///
 /// Component states
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelectStates {
     /// Available choices
     pub choices: Vec<String>,
@@ -80,6 +89,21 @@ impl SelectStates {
         }
     }
 
+    /// ### select_next_starting_with
+    ///
+    /// Select the next choice, after the selected one and cycling to the first, which starts with `ch` (case insensitive)
+    pub fn select_next_starting_with(&mut self, ch: char) {
+        let len = self.choices.len();
+        if let Some(index) = (1..=len).map(|i| (self.selected + i) % len).find(|i| {
+            self.choices[*i]
+                .chars()
+                .next()
+                .is_some_and(|x| x.to_lowercase().eq(ch.to_lowercase()))
+        }) {
+            self.selected = index;
+        }
+    }
+
     /// ### close_tab
     ///
     /// Close tab
@@ -129,6 +153,19 @@ impl Select {
         self
     }
 
+    /// Set colors, borders and styles from `theme`
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(theme.foreground));
+        self.attr(Attribute::Background, AttrValue::Color(theme.background));
+        self.attr(Attribute::Borders, AttrValue::Borders(theme.borders()));
+        self.attr(Attribute::FocusStyle, AttrValue::Style(theme.inactive));
+        self.attr(
+            Attribute::HighlightedColor,
+            AttrValue::Color(theme.highlighted_color),
+        );
+        self
+    }
+
     pub fn borders(mut self, b: Borders) -> Self {
         self.attr(Attribute::Borders, AttrValue::Borders(b));
         self
@@ -154,11 +191,64 @@ impl Select {
         self
     }
 
+    /// If true, the select is rendered as inactive and ignores all commands
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
     pub fn rewind(mut self, r: bool) -> Self {
         self.attr(Attribute::Rewind, AttrValue::Flag(r));
         self
     }
 
+    /// If true, typing a character while the tab is open selects the next choice starting with it (default `false`)
+    pub fn quick_jump(mut self, quick_jump: bool) -> Self {
+        self.attr(
+            Attribute::Custom(SELECT_QUICK_JUMP),
+            AttrValue::Flag(quick_jump),
+        );
+        self
+    }
+
+    fn is_quick_jump(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(SELECT_QUICK_JUMP), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    /// Make the state report the text of the selected choice, instead of its index (default `false`)
+    pub fn state_as_string(mut self, as_string: bool) -> Self {
+        self.attr(
+            Attribute::Custom(SELECT_STATE_AS_STRING),
+            AttrValue::Flag(as_string),
+        );
+        self
+    }
+
+    /// ### selected_state
+    ///
+    /// Get the state for the selected choice; either its index or its text.
+    /// If the state is reported as string and there are no choices, returns `State::None`
+    fn selected_state(&self) -> State {
+        match self
+            .props
+            .get_or(
+                Attribute::Custom(SELECT_STATE_AS_STRING),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+        {
+            true => self
+                .states
+                .choices
+                .get(self.states.selected)
+                .map(|x| State::One(StateValue::String(x.clone())))
+                .unwrap_or(State::None),
+            false => State::One(StateValue::Usize(self.states.selected)),
+        }
+    }
+
     pub fn choices<S: AsRef<str>>(mut self, choices: &[S]) -> Self {
         self.attr(
             Attribute::Content,
@@ -181,17 +271,135 @@ impl Select {
         self
     }
 
+    /// Set the choices grouped by category, as `(header, choices)`, like HTML `<optgroup>`.
+    /// Headers are rendered dimmed in the open tab and can't be selected;
+    /// the state refers to the choices as if they weren't grouped
+    pub fn groups(mut self, groups: &[(&str, &[&str])]) -> Self {
+        let choices: Vec<&str> = groups
+            .iter()
+            .flat_map(|(_, choices)| choices.iter().copied())
+            .collect();
+        self = self.choices(&choices);
+        self.attr(
+            Attribute::Custom(SELECT_GROUPS),
+            AttrValue::Payload(PropPayload::Linked(
+                groups
+                    .iter()
+                    .map(|(header, choices)| {
+                        PropPayload::Tup2((
+                            PropValue::Str(header.to_string()),
+                            PropValue::Usize(choices.len()),
+                        ))
+                    })
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// ### group_headers
+    ///
+    /// Get the group headers along with the index of the first choice of their group
+    fn group_headers(&self) -> Vec<(usize, &str)> {
+        let mut start = 0;
+        match self
+            .props
+            .get_ref(Attribute::Custom(SELECT_GROUPS))
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Linked(groups)) => groups
+                .iter()
+                .filter_map(|x| match x {
+                    PropPayload::Tup2((PropValue::Str(header), PropValue::Usize(len))) => {
+                        let header = (start, header.as_str());
+                        start += len;
+                        Some(header)
+                    }
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Set the style of each choice in the open tab, by position.
+    /// Choices without a style use the foreground color; the highlighted choice always uses the highlight style
+    pub fn choice_styles(mut self, styles: &[Style]) -> Self {
+        self.attr(
+            Attribute::Custom(SELECT_CHOICE_STYLES),
+            AttrValue::Payload(PropPayload::Vec(
+                styles.iter().map(|x| PropValue::Style(*x)).collect(),
+            )),
+        );
+        self
+    }
+
+    /// ### choice_style
+    ///
+    /// Get the style for the choice at `index`, if any
+    fn choice_style(&self, index: usize) -> Option<Style> {
+        match self
+            .props
+            .get_ref(Attribute::Custom(SELECT_CHOICE_STYLES))
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Vec(styles)) => match styles.get(index) {
+                Some(PropValue::Style(style)) => Some(*style),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Set the text displayed, centered in the block, when there are no choices
+    pub fn empty_text<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(
+            Attribute::Custom(SELECT_EMPTY_TEXT),
+            AttrValue::String(text.into()),
+        );
+        self
+    }
+
+    /// ### empty_text_placeholder
+    ///
+    /// Get the placeholder to display, if there are no choices and it is set
+    fn empty_text_placeholder(&self) -> Option<String> {
+        self.props
+            .get(Attribute::Custom(SELECT_EMPTY_TEXT))
+            .filter(|_| self.states.choices.is_empty())
+            .map(|x| x.unwrap_string())
+    }
+
     /// ### render_open_tab
     ///
     /// Render component when tab is open
     fn render_open_tab(&mut self, render: &mut Frame, area: Rect) {
-        // Make choices
-        let choices: Vec<ListItem> = self
-            .states
-            .choices
-            .iter()
-            .map(|x| ListItem::new(Spans::from(x.clone())))
-            .collect();
+        // Make choices, preceded by the headers of their group
+        let headers = self.group_headers();
+        let mut choices: Vec<ListItem> = Vec::with_capacity(self.states.choices.len());
+        for (i, x) in self.states.choices.iter().enumerate() {
+            headers
+                .iter()
+                .filter(|(start, _)| *start == i)
+                .for_each(|(_, header)| {
+                    choices.push(
+                        ListItem::new(Spans::from(header.to_string()))
+                            .style(Style::default().add_modifier(TextModifiers::DIM)),
+                    )
+                });
+            let item = ListItem::new(Spans::from(x.clone()));
+            // The highlight style wins over the choice style
+            choices.push(match self.choice_style(i) {
+                Some(style) if i != self.states.selected => item.style(style),
+                _ => item,
+            });
+        }
+        // Headers before the selected choice shift its position in the list
+        let selected_row = self.states.selected
+            + headers
+                .iter()
+                .filter(|(start, _)| *start <= self.states.selected)
+                .count();
         let foreground = self
             .props
             .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
@@ -229,10 +437,12 @@ impl Select {
             Some((text, alignment)) => block.title(text).title_alignment(alignment),
             None => block,
         };
+        // Disabled components are rendered as inactive
         let focus = self
             .props
             .get_or(Attribute::Focus, AttrValue::Flag(false))
-            .unwrap_flag();
+            .unwrap_flag()
+            && !self.is_disabled();
         let inactive_style = self
             .props
             .get(Attribute::FocusStyle)
@@ -245,18 +455,22 @@ impl Select {
             .block(block);
         render.render_widget(p, chunks[0]);
         // Render the list of elements in chunks [1]
+        let list_block = Block::default()
+            .borders(BorderSides::LEFT | BorderSides::BOTTOM | BorderSides::RIGHT)
+            .border_style(match focus {
+                true => borders.style(),
+                false => Style::default(),
+            })
+            .border_type(borders.modifiers)
+            .style(Style::default().bg(background));
+        if let Some(text) = self.empty_text_placeholder() {
+            let style = Style::default().fg(foreground).bg(background);
+            crate::utils::draw_empty_text(render, list_block, chunks[1], &text, style);
+            return;
+        }
         // Make list
         let mut list = List::new(choices)
-            .block(
-                Block::default()
-                    .borders(BorderSides::LEFT | BorderSides::BOTTOM | BorderSides::RIGHT)
-                    .border_style(match focus {
-                        true => borders.style(),
-                        false => Style::default(),
-                    })
-                    .border_type(borders.modifiers)
-                    .style(Style::default().bg(background)),
-            )
+            .block(list_block)
             .direction(tuirealm::ratatui::widgets::ListDirection::TopToBottom)
             .style(Style::default().fg(foreground).bg(background))
             .highlight_style(
@@ -273,7 +487,7 @@ impl Select {
             list = list.highlight_symbol(hg_str);
         }
         let mut state: ListState = ListState::default();
-        state.select(Some(self.states.selected));
+        state.select(Some(selected_row));
         render.render_stateful_widget(list, chunks[1], &mut state);
     }
 
@@ -293,10 +507,12 @@ impl Select {
             .props
             .get(Attribute::FocusStyle)
             .map(|x| x.unwrap_style());
+        // Disabled components are rendered as inactive
         let focus = self
             .props
             .get_or(Attribute::Focus, AttrValue::Flag(false))
-            .unwrap_flag();
+            .unwrap_flag()
+            && !self.is_disabled();
         let style = match focus {
             true => Style::default().bg(background).fg(foreground),
             false => inactive_style.unwrap_or_default(),
@@ -319,6 +535,10 @@ impl Select {
             Some((text, alignment)) => block.title(text).title_alignment(alignment),
             None => block,
         };
+        if let Some(text) = self.empty_text_placeholder() {
+            crate::utils::draw_empty_text(render, block, area, &text, style);
+            return;
+        }
         let selected_text: String = match self.states.choices.get(self.states.selected) {
             None => String::default(),
             Some(s) => s.clone(),
@@ -332,6 +552,23 @@ impl Select {
             .get_or(Attribute::Rewind, AttrValue::Flag(false))
             .unwrap_flag()
     }
+
+    /// ### move_result
+    ///
+    /// Get the result for a move; `None` is returned if the tab is closed, `Changed` otherwise,
+    /// even if the choice hit a boundary
+    fn move_result(&self) -> CmdResult {
+        match self.states.is_tab_open() {
+            false => CmdResult::None,
+            true => CmdResult::Changed(self.selected_state()),
+        }
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.props
+            .get_or(Attribute::Disabled, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
 }
 
 impl MockComponent for Select {
@@ -370,6 +607,12 @@ impl MockComponent for Select {
                 }
                 self.props.set(attr, value);
             }
+            Attribute::Disabled if self.states.is_tab_open() => {
+                if let AttrValue::Flag(true) = value {
+                    self.states.cancel_tab();
+                }
+                self.props.set(attr, value);
+            }
             attr => {
                 self.props.set(attr, value);
             }
@@ -380,29 +623,26 @@ impl MockComponent for Select {
         if self.states.is_tab_open() {
             State::None
         } else {
-            State::One(StateValue::Usize(self.states.selected))
+            self.selected_state()
         }
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if self.is_disabled() {
+            return CmdResult::None;
+        }
         match cmd {
             Cmd::Move(Direction::Down) => {
                 // Increment choice
                 self.states.next_choice(self.rewindable());
                 // Return CmdResult On Change or None if tab is closed
-                match self.states.is_tab_open() {
-                    false => CmdResult::None,
-                    true => CmdResult::Changed(State::One(StateValue::Usize(self.states.selected))),
-                }
+                self.move_result()
             }
             Cmd::Move(Direction::Up) => {
-                // Increment choice
+                // Decrement choice
                 self.states.prev_choice(self.rewindable());
                 // Return CmdResult On Change or None if tab is closed
-                match self.states.is_tab_open() {
-                    false => CmdResult::None,
-                    true => CmdResult::Changed(State::One(StateValue::Usize(self.states.selected))),
-                }
+                self.move_result()
             }
             Cmd::Cancel => {
                 self.states.cancel_tab();
@@ -418,6 +658,14 @@ impl MockComponent for Select {
                     CmdResult::None
                 }
             }
+            Cmd::Type(ch) if self.states.is_tab_open() && self.is_quick_jump() => {
+                let prev = self.states.selected;
+                self.states.select_next_starting_with(ch);
+                match prev != self.states.selected {
+                    true => CmdResult::Changed(self.selected_state()),
+                    false => CmdResult::None,
+                }
+            }
             _ => CmdResult::None,
         }
     }
@@ -431,6 +679,7 @@ mod test {
     use pretty_assertions::assert_eq;
 
     use tuirealm::props::{PropPayload, PropValue};
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
 
     #[test]
     fn test_components_select_states() {
@@ -444,7 +693,7 @@ mod test {
             "vanilla".to_string(),
             "chocolate".to_string(),
         ];
-        states.set_choices(&choices);
+        states.set_choices(choices);
         assert_eq!(states.selected, 0);
         assert_eq!(states.choices.len(), 4);
         // Move
@@ -473,7 +722,7 @@ mod test {
         assert_eq!(states.selected, 2);
         // Update
         let choices: &[String] = &["lemon".to_string(), "strawberry".to_string()];
-        states.set_choices(&choices);
+        states.set_choices(choices);
         assert_eq!(states.selected, 1); // Move to first index available
         assert_eq!(states.choices.len(), 2);
         let choices = vec![];
@@ -588,4 +837,255 @@ mod test {
         );
         assert_eq!(component.perform(Cmd::Move(Direction::Up)), CmdResult::None);
     }
+
+    #[test]
+    fn test_components_select_empty_text() {
+        let render = |component: &mut Select, height: u16| {
+            let mut terminal = Terminal::new(TestBackend::new(14, height)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 14, height)))
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let row = |buffer: &tuirealm::ratatui::buffer::Buffer, y: u16| -> String {
+            (0..14).map(|x| buffer[(x, y)].symbol()).collect()
+        };
+        let mut component = Select::default().empty_text("No choices");
+        assert_eq!(
+            component.query(Attribute::Custom(SELECT_EMPTY_TEXT)),
+            Some(AttrValue::String("No choices".to_string()))
+        );
+        // Closed tab
+        let buffer = render(&mut component, 3);
+        assert_eq!(row(&buffer, 1).as_str(), "│ No choices │");
+        // Open tab
+        component.perform(Cmd::Submit);
+        let buffer = render(&mut component, 5);
+        assert_eq!(row(&buffer, 2).as_str(), "│ No choices │");
+        // Choices are rendered when there are any
+        let mut component = component.choices(&["lemon"]);
+        component.perform(Cmd::Submit);
+        let buffer = render(&mut component, 3);
+        assert_eq!(row(&buffer, 1).as_str(), "│lemon       │");
+    }
+
+    #[test]
+    fn test_components_select_choice_styles() {
+        let mut component = Select::default()
+            .foreground(Color::White)
+            .highlighted_color(Color::Yellow)
+            .choices(&["lemon", "strawberry", "vanilla"])
+            .choice_styles(&[
+                Style::default().fg(Color::Green),
+                Style::default().fg(Color::Red),
+            ]);
+        assert_eq!(
+            component.query(Attribute::Custom(SELECT_CHOICE_STYLES)),
+            Some(AttrValue::Payload(PropPayload::Vec(vec![
+                PropValue::Style(Style::default().fg(Color::Green)),
+                PropValue::Style(Style::default().fg(Color::Red)),
+            ])))
+        );
+        assert_eq!(
+            component.choice_style(1),
+            Some(Style::default().fg(Color::Red))
+        );
+        assert_eq!(component.choice_style(2), None);
+        component.perform(Cmd::Submit);
+        let mut terminal = Terminal::new(TestBackend::new(14, 6)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 14, 6)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        // Highlight wins over the choice style
+        assert_eq!(buffer[(1, 2)].fg, Color::Yellow);
+        assert_eq!(buffer[(1, 3)].fg, Color::Red);
+        // Missing styles use the foreground
+        assert_eq!(buffer[(1, 4)].fg, Color::White);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_components_select_states_serde() {
+        let mut states = SelectStates::default();
+        states.set_choices(&[
+            "lemon".to_string(),
+            "strawberry".to_string(),
+            "vanilla".to_string(),
+        ]);
+        states.select(2);
+        let json = serde_json::to_string(&states).unwrap();
+        let restored: SelectStates = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.choices, states.choices);
+        assert_eq!(restored.selected, 2);
+        assert_eq!(restored.tab_open, false);
+    }
+
+    #[test]
+    fn test_components_select_theme() {
+        let theme = Theme {
+            highlighted_color: Color::LightYellow,
+            modifiers: TextModifiers::BOLD,
+            ..Theme::default()
+        };
+        let component = Select::default().theme(&theme);
+        assert_eq!(component.query(Attribute::TextProps), None);
+        assert_eq!(
+            component.query(Attribute::HighlightedColor),
+            Some(AttrValue::Color(Color::LightYellow))
+        );
+    }
+
+    #[test]
+    fn test_components_select_state_as_string() {
+        let component = Select::default().choices(&["lemon", "strawberry", "vanilla"]);
+        // Default is index
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+        let mut component = component.state_as_string(true);
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(String::from("lemon")))
+        );
+        // Commands report the choice text as well
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("strawberry"))))
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::String(String::from("strawberry"))))
+        );
+        // Changing choices doesn't desync the state
+        component.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Vec(vec![
+                PropValue::Str(String::from("mint")),
+                PropValue::Str(String::from("pistachio")),
+            ])),
+        );
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(String::from("pistachio")))
+        );
+        // No choices
+        component.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Vec(vec![])),
+        );
+        assert_eq!(component.state(), State::None);
+        // Back to index
+        let component = component.state_as_string(false);
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+    }
+
+    #[test]
+    fn test_components_select_disabled() {
+        let mut component = Select::default()
+            .choices(&["lemon", "strawberry"])
+            .disabled(true);
+        // Tab can't be opened
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(component.states.is_tab_open(), false);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+        // Disabling closes the tab
+        component.attr(Attribute::Disabled, AttrValue::Flag(false));
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(component.states.is_tab_open(), true);
+        component.attr(Attribute::Disabled, AttrValue::Flag(true));
+        assert_eq!(component.states.is_tab_open(), false);
+    }
+
+    #[test]
+    fn test_components_select_quick_jump() {
+        let mut component = Select::default()
+            .choices(&["banana", "apple", "Blueberry", "cherry", "blackberry"])
+            .quick_jump(true);
+        // Ignored while the tab is closed
+        assert_eq!(component.perform(Cmd::Type('b')), CmdResult::None);
+        assert_eq!(component.states.selected, 0);
+        component.perform(Cmd::Submit);
+        // Cycles through the choices sharing the initial letter, case insensitive
+        assert_eq!(
+            component.perform(Cmd::Type('b')),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(component.states.selected, 2);
+        component.perform(Cmd::Type('b'));
+        assert_eq!(component.states.selected, 4);
+        component.perform(Cmd::Type('B'));
+        assert_eq!(component.states.selected, 0);
+        // Single match
+        assert_eq!(
+            component.perform(Cmd::Type('c')),
+            CmdResult::Changed(State::One(StateValue::Usize(3)))
+        );
+        assert_eq!(component.states.selected, 3);
+        assert_eq!(component.perform(Cmd::Type('c')), CmdResult::None);
+        // No match
+        assert_eq!(component.perform(Cmd::Type('z')), CmdResult::None);
+        assert_eq!(component.states.selected, 3);
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(3)))
+        );
+        // Disabled by default
+        let mut component = Select::default().choices(&["banana", "blueberry"]);
+        component.perform(Cmd::Submit);
+        assert_eq!(component.perform(Cmd::Type('b')), CmdResult::None);
+        assert_eq!(component.states.selected, 0);
+        // No choices
+        let mut component = Select::default().quick_jump(true);
+        component.perform(Cmd::Submit);
+        assert_eq!(component.perform(Cmd::Type('b')), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_select_groups() {
+        let mut component = Select::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .highlighted_color(Color::Yellow)
+            .groups(&[
+                ("Fruits", &["apple", "banana"]),
+                ("Vegetables", &["carrot"]),
+            ]);
+        assert_eq!(component.states.choices.len(), 3);
+        assert_eq!(
+            component.group_headers(),
+            vec![(0, "Fruits"), (2, "Vegetables")]
+        );
+        component.perform(Cmd::Submit);
+        // Navigation skips the headers
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        // Headers are dimmed and the selected choice is highlighted
+        let mut terminal = Terminal::new(TestBackend::new(12, 8)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 8)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (1..11).map(|x| buffer[(x, y)].symbol()).collect() };
+        assert_eq!(row(2).trim_end(), "Fruits");
+        assert_eq!(row(3).trim_end(), "apple");
+        assert_eq!(row(5).trim_end(), "Vegetables");
+        assert_eq!(row(6).trim_end(), "carrot");
+        assert!(buffer[(1, 2)].modifier.contains(TextModifiers::DIM));
+        assert_eq!(buffer[(1, 6)].fg, Color::Yellow);
+        // The selection maps to the item
+        let mut component = component.state_as_string(true);
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::String(String::from("carrot"))))
+        );
+    }
 }
@@ -16,6 +16,9 @@ use tuirealm::ratatui::{
 };
 use tuirealm::{Frame, MockComponent, State};
 
+// -- Props
+use super::props::{SPAN_BADGE, SPAN_HYPERLINKS, SPAN_ICON, SPAN_LINK, SPAN_TRUNCATE};
+
 // -- Component
 
 /// ## Span
@@ -56,6 +59,138 @@ impl Span {
         );
         self
     }
+
+    /// Make the text a hyperlink to `url`, using the OSC 8 escape sequence.
+    /// The link is rendered only if hyperlinks have been enabled with `enable_hyperlinks`
+    pub fn link<S: Into<String>>(mut self, url: S) -> Self {
+        self.attr(Attribute::Custom(SPAN_LINK), AttrValue::String(url.into()));
+        self
+    }
+
+    /// Enable hyperlinks rendering. Enable this only on terminals which support OSC 8 hyperlinks,
+    /// otherwise the escape sequences will corrupt the output
+    pub fn enable_hyperlinks(mut self, enable: bool) -> Self {
+        self.attr(Attribute::Custom(SPAN_HYPERLINKS), AttrValue::Flag(enable));
+        self
+    }
+
+    /// Set an icon rendered before the text, separated by a space
+    pub fn icon<S: Into<String>>(mut self, icon: S) -> Self {
+        self.attr(Attribute::Custom(SPAN_ICON), AttrValue::String(icon.into()));
+        self
+    }
+
+    /// Set a badge rendered with `style` after the text, separated by a space.
+    /// If the area is too narrow, the text between the icon and the badge is truncated
+    pub fn badge<S: Into<String>>(mut self, badge: S, style: Style) -> Self {
+        self.attr(
+            Attribute::Custom(SPAN_BADGE),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::Str(badge.into()),
+                PropValue::Style(style),
+            ))),
+        );
+        self
+    }
+
+    /// Truncate the text with an ellipsis (`…`) if it's wider than the area
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.attr(Attribute::Custom(SPAN_TRUNCATE), AttrValue::Flag(truncate));
+        self
+    }
+
+    fn is_truncate(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(SPAN_TRUNCATE), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    /// ### truncate_spans
+    ///
+    /// Truncate spans so that their overall width doesn't exceed `width`, ending with an ellipsis
+    fn truncate_spans(mut spans: Vec<TuiSpan>, width: usize) -> Vec<TuiSpan> {
+        let total: usize = spans.iter().map(|x| x.width()).sum();
+        if total <= width {
+            return spans;
+        }
+        let mut used: usize = 0;
+        for (i, span) in spans.iter_mut().enumerate() {
+            let span_width = span.width();
+            // Keep a column for the ellipsis
+            if used + span_width < width {
+                used += span_width;
+                continue;
+            }
+            let content = format!("{}…", span.content);
+            span.content = crate::utils::truncate_with_ellipsis(&content, width - used).into();
+            spans.truncate(i + 1);
+            break;
+        }
+        spans
+    }
+
+    /// ### decorate_spans
+    ///
+    /// Put the icon and the badge, if any, around the spans, truncating the spans so that the line fits `width`
+    fn decorate_spans(&self, spans: Vec<TuiSpan<'static>>, width: usize) -> Vec<TuiSpan<'static>> {
+        let icon = self
+            .props
+            .get_ref(Attribute::Custom(SPAN_ICON))
+            .and_then(|x| x.as_string());
+        let badge = match self
+            .props
+            .get_ref(Attribute::Custom(SPAN_BADGE))
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Tup2((PropValue::Str(text), PropValue::Style(style)))) => {
+                Some((text, *style))
+            }
+            _ => None,
+        };
+        if icon.is_none() && badge.is_none() {
+            return spans;
+        }
+        let modifiers = self
+            .props
+            .get_or(
+                Attribute::TextProps,
+                AttrValue::TextModifiers(TextModifiers::empty()),
+            )
+            .unwrap_text_modifiers();
+        let mut head: Vec<TuiSpan> = Vec::new();
+        if let Some(icon) = icon {
+            head.push(TuiSpan::styled(
+                format!("{icon} "),
+                Style::default().add_modifier(modifiers),
+            ));
+        }
+        let mut tail: Vec<TuiSpan> = Vec::new();
+        if let Some((text, style)) = badge {
+            tail.push(TuiSpan::raw(" "));
+            tail.push(TuiSpan::styled(text.clone(), style));
+        }
+        let reserved: usize = head.iter().chain(tail.iter()).map(|x| x.width()).sum();
+        head.extend(Self::truncate_spans(spans, width.saturating_sub(reserved)));
+        head.extend(tail);
+        head
+    }
+
+    /// ### get_link
+    ///
+    /// Get the url to link the text to, if any and if hyperlinks are enabled
+    fn get_link(&self) -> Option<String> {
+        let enabled = self
+            .props
+            .get_or(Attribute::Custom(SPAN_HYPERLINKS), AttrValue::Flag(false))
+            .unwrap_flag();
+        match enabled {
+            true => self
+                .props
+                .get(Attribute::Custom(SPAN_LINK))
+                .map(|x| x.unwrap_string()),
+            false => None,
+        }
+    }
 }
 
 impl MockComponent for Span {
@@ -89,6 +224,11 @@ impl MockComponent for Span {
                         .collect(),
                     _ => Vec::new(),
                 };
+            let spans = self.decorate_spans(spans, area.width as usize);
+            let spans = match self.is_truncate() {
+                true => Self::truncate_spans(spans, area.width as usize),
+                false => spans,
+            };
             let text: Text = Text::from(Spans::from(spans));
             // Text properties
             let alignment: Alignment = self
@@ -101,6 +241,9 @@ impl MockComponent for Span {
                     .style(Style::default().bg(background).fg(foreground)),
                 area,
             );
+            if let Some(url) = self.get_link() {
+                crate::utils::hyperlink_area(render.buffer_mut(), area, &url);
+            }
         }
     }
 
@@ -127,6 +270,7 @@ mod tests {
     use super::*;
 
     use pretty_assertions::assert_eq;
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
 
     #[test]
     fn test_components_span() {
@@ -143,4 +287,92 @@ mod tests {
         // Get value
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    fn test_components_span_truncate() {
+        let component = Span::default().truncate(true);
+        assert_eq!(component.is_truncate(), true);
+        let spans = || {
+            vec![
+                TuiSpan::raw("Press "),
+                TuiSpan::raw("<ESC>"),
+                TuiSpan::raw(" to quit"),
+            ]
+        };
+        let content = |spans: Vec<TuiSpan>| -> Vec<String> {
+            spans.into_iter().map(|x| x.content.to_string()).collect()
+        };
+        // Fits
+        assert_eq!(
+            content(Span::truncate_spans(spans(), 19)),
+            vec!["Press ", "<ESC>", " to quit"]
+        );
+        // Truncated in the middle of a span
+        assert_eq!(
+            content(Span::truncate_spans(spans(), 9)),
+            vec!["Press ", "<E…"]
+        );
+        // Truncated at the end of a span
+        assert_eq!(
+            content(Span::truncate_spans(spans(), 11)),
+            vec!["Press ", "<ESC…"]
+        );
+        assert_eq!(
+            content(Span::truncate_spans(spans(), 12)),
+            vec!["Press ", "<ESC>", "…"]
+        );
+        // Smaller than the ellipsis
+        assert_eq!(content(Span::truncate_spans(spans(), 1)), vec!["…"]);
+        assert_eq!(content(Span::truncate_spans(spans(), 0)), vec![""]);
+    }
+
+    #[test]
+    fn test_components_span_link() {
+        let component = Span::default().link("https://github.com/veeso/tui-realm");
+        // Hyperlinks disabled
+        assert_eq!(component.get_link(), None);
+        let component = component.enable_hyperlinks(true);
+        assert_eq!(
+            component.get_link().as_deref(),
+            Some("https://github.com/veeso/tui-realm")
+        );
+    }
+
+    #[test]
+    fn test_components_span_icon_and_badge() {
+        let content = |spans: Vec<TuiSpan>| -> String {
+            spans.into_iter().map(|x| x.content.to_string()).collect()
+        };
+        let spans = || vec![TuiSpan::raw("Inbox")];
+        // Plain text is untouched
+        let component = Span::default();
+        assert_eq!(content(component.decorate_spans(spans(), 3)), "Inbox");
+        // Icon, text and badge on the same line
+        let component = Span::default()
+            .icon("@")
+            .badge("(12)", Style::default().fg(Color::Yellow));
+        let decorated = component.decorate_spans(spans(), 20);
+        assert_eq!(content(decorated.clone()), "@ Inbox (12)");
+        assert_eq!(
+            decorated.last().unwrap().style,
+            Style::default().fg(Color::Yellow)
+        );
+        // Only the text is truncated when the area is too narrow
+        assert_eq!(content(component.decorate_spans(spans(), 10)), "@ In… (12)");
+        // Icon only
+        let component = Span::default().icon("@");
+        assert_eq!(content(component.decorate_spans(spans(), 6)), "@ Inb…");
+        // Rendered
+        let mut component = Span::default()
+            .icon("@")
+            .badge("(12)", Style::default())
+            .spans(&[TextSpan::from("Inbox")]);
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 1)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let line: String = (0..10).map(|x| buffer[(x, 0)].symbol()).collect();
+        assert_eq!(line, "@ In… (12)");
+    }
 }
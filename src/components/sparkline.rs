@@ -2,21 +2,93 @@
 //!
 //! A sparkline over more lines
 
-use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
 };
-use tuirealm::ratatui::{layout::Rect, widgets::Sparkline as TuiSparkline};
+use tuirealm::ratatui::{
+    layout::Rect,
+    widgets::{Sparkline as TuiSparkline, SparklineBar},
+};
 use tuirealm::{Frame, MockComponent, State};
 
+// -- Props
+use super::props::{SPARKLINE_MAX, SPARKLINE_RESOLUTION, SPARKLINE_REVERSED, SPARKLINE_THRESHOLD};
+
+// -- states
+
+/// ### SparklineStates
+///
+/// Sparkline states
+#[derive(Default)]
+pub struct SparklineStates {
+    /// Amount of entries scrolled back from the most recent one
+    pub cursor: usize,
+}
+
+impl SparklineStates {
+    /// ### move_cursor_left
+    ///
+    /// Move cursor to the left (towards older entries)
+    pub fn move_cursor_left(&mut self, data_len: usize) {
+        if self.cursor + 1 < data_len {
+            self.cursor += 1;
+        }
+    }
+
+    /// ### move_cursor_right
+    ///
+    /// Move cursor to the right (towards the most recent entries)
+    pub fn move_cursor_right(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+        }
+    }
+
+    /// ### reset_cursor
+    ///
+    /// Reset cursor to 0, showing the most recent entries
+    pub fn reset_cursor(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// ### cursor_at_begin
+    ///
+    /// Move cursor to the first entry of the sparkline
+    pub fn cursor_at_begin(&mut self, data_len: usize) {
+        if data_len > 0 {
+            self.cursor = data_len - 1;
+        } else {
+            self.cursor = 0;
+        }
+    }
+
+    /// ### fix_cursor
+    ///
+    /// Keep cursor if possible, otherwise set it to the oldest position which still fills `width` entries
+    pub fn fix_cursor(&mut self, data_len: usize, width: usize) {
+        self.cursor = std::cmp::min(self.cursor, data_len.saturating_sub(width));
+    }
+}
+
 // -- component
 
 /// ## Sparkline
 ///
-/// A sparkline over more lines
+/// A sparkline over more lines.
+/// The sparkline can work both in "active" and "disabled" mode.
+///
+/// #### Disabled mode
+///
+/// When in disabled mode, the sparkline won't be interactive and will always display the most recent entries.
+///
+/// #### Active mode
+///
+/// While in active mode (default) you can move back through the history with arrows and END/HOME keys
 #[derive(Default)]
 pub struct Sparkline {
     props: Props,
+    pub states: SparklineStates,
 }
 
 impl Sparkline {
@@ -45,6 +117,46 @@ impl Sparkline {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    /// Set the value which corresponds to a full bar.
+    /// If unset, bars are scaled to the maximum value of the displayed data.
+    /// With `data_f64`, the value is scaled by the resolution as the data
+    pub fn max(mut self, max: u64) -> Self {
+        self.attr(
+            Attribute::Custom(SPARKLINE_MAX),
+            AttrValue::Payload(PropPayload::One(PropValue::U64(max))),
+        );
+        self
+    }
+
+    /// Render data from the last entry to the first one.
+    /// The data is reversed after being windowed to the entries to display
+    pub fn reversed(mut self, reversed: bool) -> Self {
+        self.attr(
+            Attribute::Custom(SPARKLINE_REVERSED),
+            AttrValue::Flag(reversed),
+        );
+        self
+    }
+
+    /// Render bars whose value exceeds `threshold` with the provided color.
+    /// Bars below or equal to the threshold keep the foreground color.
+    /// With `data_f64`, the threshold is scaled by the resolution as the data
+    pub fn threshold(mut self, threshold: u64, color: Color) -> Self {
+        self.attr(
+            Attribute::Custom(SPARKLINE_THRESHOLD),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::U64(threshold),
+                PropValue::Color(color),
+            ))),
+        );
+        self
+    }
+
     pub fn data(mut self, data: &[u64]) -> Self {
         self.attr(
             Attribute::Dataset,
@@ -55,6 +167,74 @@ impl Sparkline {
         self
     }
 
+    /// Set data as floating point values, which are normalized to integers over the resolution before being rendered,
+    /// preserving the shape of fractional data. Negative and NaN values are clamped to 0
+    pub fn data_f64(mut self, data: &[f64]) -> Self {
+        self.attr(
+            Attribute::Dataset,
+            AttrValue::Payload(PropPayload::Vec(
+                data.iter().map(|x| PropValue::F64(*x)).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the factor floating point data is multiplied by, when normalized to integers (default 1000)
+    pub fn resolution(mut self, resolution: u64) -> Self {
+        self.attr(
+            Attribute::Custom(SPARKLINE_RESOLUTION),
+            AttrValue::Payload(PropPayload::One(PropValue::U64(resolution))),
+        );
+        self
+    }
+
+    /// ### scale
+    ///
+    /// Get the factor which data, max and threshold are multiplied by when rendered:
+    /// the resolution for floating point data, 1 otherwise
+    fn scale(&self) -> u64 {
+        match self
+            .props
+            .get_ref(Attribute::Dataset)
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Vec(list)) if matches!(list.first(), Some(PropValue::F64(_))) => self
+                .props
+                .get(Attribute::Custom(SPARKLINE_RESOLUTION))
+                .map(|x| x.unwrap_payload().unwrap_one().unwrap_u64())
+                .unwrap_or(1000),
+            _ => 1,
+        }
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.props
+            .get_or(Attribute::Disabled, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    fn is_reversed(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(SPARKLINE_REVERSED),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn get_threshold(&self) -> Option<(u64, Color)> {
+        match self
+            .props
+            .get(Attribute::Custom(SPARKLINE_THRESHOLD))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Tup2((PropValue::U64(threshold), PropValue::Color(color)))) => {
+                Some((threshold, color))
+            }
+            _ => None,
+        }
+    }
+
     /// ### data_len
     ///
     /// Retrieve current data len from properties
@@ -67,20 +247,28 @@ impl Sparkline {
 
     /// ### data
     ///
-    /// Get data to be displayed, starting from provided index at `start` with a max length of `len`
-    fn get_data(&self, max: usize) -> Vec<u64> {
+    /// Get data to be displayed, starting from provided index at `start` with a max length of `len`.
+    /// If the sparkline is reversed, the returned data is reversed too
+    fn get_data(&self, start: usize, len: usize) -> Vec<u64> {
         match self
             .props
             .get(Attribute::Dataset)
             .map(|x| x.unwrap_payload())
         {
             Some(PropPayload::Vec(list)) => {
-                let mut data: Vec<u64> = Vec::with_capacity(max);
+                let scale = self.scale();
+                let mut data: Vec<u64> = Vec::with_capacity(len);
                 list.iter()
-                    .take(max)
-                    .cloned()
-                    .map(|x| x.unwrap_u64())
+                    .skip(start)
+                    .take(len)
+                    .map(|x| match x {
+                        PropValue::F64(x) => normalize(*x, scale),
+                        x => x.clone().unwrap_u64(),
+                    })
                     .for_each(|x| data.push(x));
+                if self.is_reversed() {
+                    data.reverse();
+                }
                 data
             }
             _ => Vec::new(),
@@ -88,6 +276,16 @@ impl Sparkline {
     }
 }
 
+/// ### normalize
+///
+/// Convert `value` to an integer, multiplying it by `scale`; negative and NaN values are clamped to 0
+fn normalize(value: f64, scale: u64) -> u64 {
+    match value.is_nan() {
+        true => 0,
+        false => (value.max(0.0) * scale as f64).round() as u64,
+    }
+}
+
 impl MockComponent for Sparkline {
     fn view(&mut self, render: &mut Frame, area: Rect) {
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
@@ -110,18 +308,45 @@ impl MockComponent for Sparkline {
                 .props
                 .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
                 .unwrap_borders();
+            let div = crate::utils::get_block(borders, Some(title), false, None);
             let max_entries = self
                 .props
-                .get_or(Attribute::Width, AttrValue::Length(self.data_len()))
+                .get_or(
+                    Attribute::Width,
+                    AttrValue::Length(div.inner(area).width as usize),
+                )
                 .unwrap_length();
-            // Get data
-            let data: Vec<u64> = self.get_data(max_entries);
+            // Get data; keep window filled when scrolled back too much
+            let data_len = self.data_len();
+            self.states.fix_cursor(data_len, max_entries);
+            let start = data_len.saturating_sub(max_entries + self.states.cursor);
+            let data: Vec<u64> = self.get_data(start, max_entries);
+            // ratatui's sparkline has a single style, so bars above threshold get their own style
+            let scale = self.scale();
+            let threshold = self
+                .get_threshold()
+                .map(|(threshold, color)| (threshold.saturating_mul(scale), color));
+            let bars: Vec<SparklineBar> = data
+                .iter()
+                .map(|x| match threshold {
+                    Some((threshold, color)) if *x > threshold => {
+                        SparklineBar::from(*x).style(Style::default().fg(color).bg(background))
+                    }
+                    _ => SparklineBar::from(*x),
+                })
+                .collect();
             // Create widget
-            let widget: TuiSparkline = TuiSparkline::default()
-                .block(crate::utils::get_block(borders, Some(title), false, None))
-                .data(data.as_slice())
-                .max(max_entries as u64)
+            let mut widget: TuiSparkline = TuiSparkline::default()
+                .block(div)
+                .data(bars)
                 .style(Style::default().fg(foreground).bg(background));
+            if let Some(max) = self
+                .props
+                .get(Attribute::Custom(SPARKLINE_MAX))
+                .map(|x| x.unwrap_payload().unwrap_one().unwrap_u64())
+            {
+                widget = widget.max(max.saturating_mul(scale));
+            }
             // Render
             render.render_widget(widget, area);
         }
@@ -139,8 +364,29 @@ impl MockComponent for Sparkline {
         State::None
     }
 
-    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
-        CmdResult::None
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        let prev = self.states.cursor;
+        if !self.is_disabled() {
+            match cmd {
+                Cmd::Move(Direction::Left) => {
+                    self.states.move_cursor_left(self.data_len());
+                }
+                Cmd::Move(Direction::Right) => {
+                    self.states.move_cursor_right();
+                }
+                Cmd::GoTo(Position::Begin) => {
+                    self.states.cursor_at_begin(self.data_len());
+                }
+                Cmd::GoTo(Position::End) => {
+                    self.states.reset_cursor();
+                }
+                _ => {}
+            }
+        }
+        match prev != self.states.cursor {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
     }
 }
 
@@ -151,9 +397,39 @@ mod test {
 
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn test_components_sparkline_states() {
+        let mut states: SparklineStates = SparklineStates::default();
+        assert_eq!(states.cursor, 0);
+        // Back
+        states.move_cursor_left(3);
+        assert_eq!(states.cursor, 1);
+        states.move_cursor_left(3);
+        assert_eq!(states.cursor, 2);
+        // At begin
+        states.move_cursor_left(3);
+        assert_eq!(states.cursor, 2);
+        // Forward
+        states.move_cursor_right();
+        assert_eq!(states.cursor, 1);
+        states.reset_cursor();
+        assert_eq!(states.cursor, 0);
+        // At end
+        states.move_cursor_right();
+        assert_eq!(states.cursor, 0);
+        // Begin
+        states.cursor_at_begin(10);
+        assert_eq!(states.cursor, 9);
+        // Fix with window
+        states.fix_cursor(10, 4);
+        assert_eq!(states.cursor, 6);
+        states.fix_cursor(10, 16);
+        assert_eq!(states.cursor, 0);
+    }
+
     #[test]
     fn test_components_sparkline() {
-        let component = Sparkline::default()
+        let mut component = Sparkline::default()
             .background(Color::White)
             .foreground(Color::Black)
             .title("bandwidth", Alignment::Center)
@@ -164,8 +440,107 @@ mod test {
             ]);
         // Commands
         assert_eq!(component.state(), State::None);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(component.states.cursor, 1);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(component.states.cursor, 0);
+        // Already on the most recent entry
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::Begin)),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(component.states.cursor, 17);
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(component.states.cursor, 0);
+        // Disabled
+        component.attr(Attribute::Disabled, AttrValue::Flag(true));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.cursor, 0);
         // component funcs
         assert_eq!(component.data_len(), 18);
-        assert_eq!(component.get_data(4), vec![60, 80, 90, 88]);
+        assert_eq!(component.get_data(0, 4), vec![60, 80, 90, 88]);
+        assert_eq!(component.get_data(16, 4), vec![67, 102]);
+        assert!(component.query(Attribute::Custom(SPARKLINE_MAX)).is_none());
+    }
+
+    #[test]
+    fn test_components_sparkline_max() {
+        let component = Sparkline::default().max(128).data(&[60, 80, 90]);
+        assert_eq!(
+            component.query(Attribute::Custom(SPARKLINE_MAX)),
+            Some(AttrValue::Payload(PropPayload::One(PropValue::U64(128))))
+        );
+    }
+
+    #[test]
+    fn test_components_sparkline_threshold() {
+        let component = Sparkline::default().data(&[60, 80, 90]);
+        assert_eq!(component.get_threshold(), None);
+        let component = component.threshold(85, Color::Red);
+        assert_eq!(
+            component.query(Attribute::Custom(SPARKLINE_THRESHOLD)),
+            Some(AttrValue::Payload(PropPayload::Tup2((
+                PropValue::U64(85),
+                PropValue::Color(Color::Red)
+            ))))
+        );
+        assert_eq!(component.get_threshold(), Some((85, Color::Red)));
+    }
+
+    #[test]
+    fn test_components_sparkline_reversed() {
+        let component = Sparkline::default()
+            .reversed(true)
+            .data(&[60, 80, 90, 88, 76, 101]);
+        assert_eq!(component.get_data(0, 4), vec![88, 90, 80, 60]);
+        assert_eq!(component.get_data(2, 4), vec![101, 76, 88, 90]);
+    }
+
+    #[test]
+    fn test_components_sparkline_data_f64() {
+        let mut component = Sparkline::default()
+            .borders(Borders::default().sides(tuirealm::props::BorderSides::NONE))
+            .data_f64(&[0.25, 0.5, 0.75, 1.0, f64::NAN, -1.0]);
+        assert_eq!(component.scale(), 1000);
+        assert_eq!(component.get_data(0, 6), vec![250, 500, 750, 1000, 0, 0]);
+        // Bars keep the shape of the data
+        let mut terminal =
+            tuirealm::ratatui::Terminal::new(tuirealm::ratatui::backend::TestBackend::new(6, 1))
+                .unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 6, 1)))
+            .unwrap();
+        let bars: Vec<String> = (0..6)
+            .map(|x| terminal.backend().buffer()[(x, 0)].symbol().to_string())
+            .collect();
+        assert_eq!(bars, vec!["▂", "▄", "▆", "█", " ", " "]);
+        // Max is scaled as the data
+        let mut component = component.max(2);
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 6, 1)))
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(3, 0)].symbol(), "▄");
+        // Resolution
+        let component = component.resolution(10);
+        assert_eq!(component.get_data(0, 4), vec![3, 5, 8, 10]);
+        // Integer data is not scaled
+        let component = component.data(&[1, 2]);
+        assert_eq!(component.scale(), 1);
     }
 }
@@ -3,7 +3,9 @@
 //! A loading spinner. You can provide the "spinning sequence". At each `view()` call, the sequence step is increased
 
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::props::{Alignment, AttrValue, Attribute, Color, Props, Style};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Color, PropPayload, PropValue, Props, Style,
+};
 use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::{
     layout::Rect,
@@ -11,36 +13,66 @@ use tuirealm::ratatui::{
     widgets::Paragraph,
 };
 use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+// -- Props
+use super::props::{
+    SPINNER_DONE, SPINNER_FRAMES, SPINNER_LABEL, SPINNER_PROGRESS, SPINNER_TICKS_PER_FRAME,
+};
 
 // -- states
 
 #[derive(Default)]
 pub struct SpinnerStates {
-    pub sequence: Vec<char>,
+    pub sequence: Vec<String>,
     pub step: usize,
+    /// Amount of ticks (`view()` calls) to wait before advancing to the next frame
+    pub ticks_per_frame: usize,
+    /// Ticks elapsed since the last advance
+    pub ticks: usize,
 }
 
 impl SpinnerStates {
     /// ### reset
     ///
-    /// Re initialize sequence
+    /// Re initialize sequence; each char of the sequence is a frame
     pub fn reset(&mut self, sequence: &str) {
-        self.sequence = sequence.chars().collect();
+        self.sequence = sequence.chars().map(|x| x.to_string()).collect();
+        self.step = 0;
+        self.ticks = 0;
+    }
+
+    /// ### set_frames
+    ///
+    /// Re initialize sequence with the provided frames
+    pub fn set_frames<S: AsRef<str>>(&mut self, frames: &[S]) {
+        self.sequence = frames.iter().map(|x| x.as_ref().to_string()).collect();
         self.step = 0;
+        self.ticks = 0;
     }
 
     /// ### step
     ///
-    /// Get current step char and increments step
-    pub fn step(&mut self) -> char {
-        let ch = self.sequence.get(self.step).cloned().unwrap_or(' ');
+    /// Get current step frame and increments step once `ticks_per_frame` ticks have elapsed
+    pub fn step(&mut self) -> String {
+        let frame = self
+            .sequence
+            .get(self.step)
+            .cloned()
+            .unwrap_or_else(|| String::from(" "));
+        // Wait for interval
+        self.ticks += 1;
+        if self.ticks < self.ticks_per_frame {
+            return frame;
+        }
+        self.ticks = 0;
         // Incr step
         if self.step + 1 >= self.sequence.len() {
             self.step = 0;
         } else {
             self.step += 1;
         }
-        ch
+        frame
     }
 }
 
@@ -56,6 +88,13 @@ pub struct Spinner {
 }
 
 impl Spinner {
+    /// Dots frames preset
+    pub const DOTS: &'static [&'static str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+    /// Line frames preset
+    pub const LINE: &'static [&'static str] = &["-", "\\", "|", "/"];
+    /// Braille frames preset
+    pub const BRAILLE: &'static [&'static str] = &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"];
+
     pub fn foreground(mut self, fg: Color) -> Self {
         self.attr(Attribute::Foreground, AttrValue::Color(fg));
         self
@@ -70,6 +109,99 @@ impl Spinner {
         self.attr(Attribute::Text, AttrValue::String(s.into()));
         self
     }
+
+    /// Set a label to display after the spinner frame
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.attr(
+            Attribute::Custom(SPINNER_LABEL),
+            AttrValue::String(label.into()),
+        );
+        self
+    }
+
+    /// Set the amount of `view()` calls to wait before advancing to the next frame.
+    /// E.g. with a 10ms redraw loop, 10 ticks per frame give a ~100ms frame.
+    pub fn ticks_per_frame(mut self, ticks: usize) -> Self {
+        self.attr(
+            Attribute::Custom(SPINNER_TICKS_PER_FRAME),
+            AttrValue::Length(ticks),
+        );
+        self
+    }
+
+    /// Set the frames to cycle through. Unlike `sequence`, each frame can be made of more characters
+    pub fn frames<S: AsRef<str>>(mut self, frames: &[S]) -> Self {
+        self.attr(
+            Attribute::Custom(SPINNER_FRAMES),
+            AttrValue::Payload(PropPayload::Vec(
+                frames
+                    .iter()
+                    .map(|x| PropValue::Str(x.as_ref().to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Mark the spinner as done: frames stop advancing and `glyph` is rendered with `color` instead (e.g. `✓` or `✗`).
+    /// To make it spin again, set `Custom(SPINNER_DONE)` to `Payload(None)`
+    pub fn finish<S: Into<String>>(mut self, glyph: S, color: Color) -> Self {
+        self.attr(
+            Attribute::Custom(SPINNER_DONE),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::Str(glyph.into()),
+                PropValue::Color(color),
+            ))),
+        );
+        self
+    }
+
+    /// Set the progress of the task, in range [0.0, 1.0], once known: the percentage is rendered after the frame
+    /// and the label, while the spinner keeps spinning. With `None`, the spinner just spins
+    pub fn progress(mut self, progress: Option<f64>) -> Self {
+        self.attr(
+            Attribute::Custom(SPINNER_PROGRESS),
+            AttrValue::Payload(match progress {
+                Some(progress) => PropPayload::One(PropValue::F64(progress)),
+                None => PropPayload::None,
+            }),
+        );
+        self
+    }
+
+    /// Get the progress as a percentage text, if known
+    fn get_percentage(&self) -> Option<String> {
+        match self
+            .props
+            .get_ref(Attribute::Custom(SPINNER_PROGRESS))
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::One(PropValue::F64(progress))) => {
+                Some(format!("{}%", (progress.clamp(0.0, 1.0) * 100.0).round()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the glyph and its color, if the spinner is done
+    fn get_done(&self) -> Option<(String, Color)> {
+        match self
+            .props
+            .get_ref(Attribute::Custom(SPINNER_DONE))
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Tup2((PropValue::Str(glyph), PropValue::Color(color)))) => {
+                Some((glyph.clone(), *color))
+            }
+            _ => None,
+        }
+    }
+
+    fn get_label(&self) -> Option<String> {
+        self.props
+            .get(Attribute::Custom(SPINNER_LABEL))
+            .map(|x| x.unwrap_string())
+    }
 }
 
 impl MockComponent for Spinner {
@@ -85,8 +217,32 @@ impl MockComponent for Spinner {
                 .props
                 .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
                 .unwrap_color();
-            // Get text
-            let text: Text = Text::from(Spans::from(TuiSpan::from(self.states.step().to_string())));
+            // Get text; once done, the glyph replaces the frames
+            let (frame, frame_style) = match self.get_done() {
+                Some((glyph, color)) => (glyph, Style::default().fg(color)),
+                None => (self.states.step(), Style::default()),
+            };
+            let percentage = self.get_percentage();
+            let mut spans: Vec<TuiSpan> = Vec::with_capacity(5);
+            if let Some(label) = self.get_label() {
+                // Label follows the frame and is truncated if it doesn't fit the area, leaving room for the percentage
+                let label_width = (area.width as usize).saturating_sub(
+                    frame.width() + 1 + percentage.as_ref().map(|x| x.width() + 1).unwrap_or(0),
+                );
+                spans.push(TuiSpan::styled(frame, frame_style));
+                spans.push(TuiSpan::from(" "));
+                spans.push(TuiSpan::from(crate::utils::truncate_with_ellipsis(
+                    label.as_str(),
+                    label_width,
+                )));
+            } else {
+                spans.push(TuiSpan::styled(frame, frame_style));
+            }
+            if let Some(percentage) = percentage {
+                spans.push(TuiSpan::from(" "));
+                spans.push(TuiSpan::from(percentage));
+            }
+            let text: Text = Text::from(Spans::from(spans));
             render.render_widget(
                 Paragraph::new(text)
                     .alignment(Alignment::Left)
@@ -101,11 +257,30 @@ impl MockComponent for Spinner {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
-        if matches!(attr, Attribute::Text) {
-            // Update sequence
-            self.states.reset(value.unwrap_string().as_str());
-        } else {
-            self.props.set(attr, value);
+        match attr {
+            Attribute::Text => {
+                // Update sequence
+                self.states.reset(value.unwrap_string().as_str());
+            }
+            Attribute::Custom(SPINNER_FRAMES) => {
+                let frames: Vec<String> = value
+                    .clone()
+                    .unwrap_payload()
+                    .unwrap_vec()
+                    .into_iter()
+                    .map(|x| x.unwrap_str())
+                    .collect();
+                self.states.set_frames(&frames);
+                self.props.set(attr, value);
+            }
+            Attribute::Custom(SPINNER_TICKS_PER_FRAME) => {
+                self.states.ticks_per_frame = value.clone().unwrap_length();
+                self.states.ticks = 0;
+                self.props.set(attr, value);
+            }
+            attr => {
+                self.props.set(attr, value);
+            }
         }
     }
 
@@ -124,6 +299,7 @@ mod tests {
     use super::*;
 
     use pretty_assertions::assert_eq;
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
 
     #[test]
     fn test_components_span() {
@@ -134,4 +310,127 @@ mod tests {
         // Get value
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    fn test_components_spinner_states() {
+        let mut states = SpinnerStates::default();
+        // Empty sequence
+        assert_eq!(states.step().as_str(), " ");
+        assert_eq!(states.step, 0);
+        // Single frame
+        states.set_frames(&["*"]);
+        assert_eq!(states.step().as_str(), "*");
+        assert_eq!(states.step().as_str(), "*");
+        assert_eq!(states.step, 0);
+        // Wrap
+        states.set_frames(Spinner::LINE);
+        assert_eq!(states.step().as_str(), "-");
+        assert_eq!(states.step().as_str(), "\\");
+        assert_eq!(states.step().as_str(), "|");
+        assert_eq!(states.step().as_str(), "/");
+        assert_eq!(states.step().as_str(), "-");
+        // Sequence
+        states.reset("ab");
+        assert_eq!(states.step().as_str(), "a");
+        assert_eq!(states.step().as_str(), "b");
+        assert_eq!(states.step().as_str(), "a");
+    }
+
+    #[test]
+    fn test_components_spinner_ticks_per_frame() {
+        let mut component = Spinner::default().frames(&["a", "b"]).ticks_per_frame(3);
+        assert_eq!(component.states.ticks_per_frame, 3);
+        // Fewer ticks than interval keep the same frame
+        assert_eq!(component.states.step().as_str(), "a");
+        assert_eq!(component.states.step().as_str(), "a");
+        assert_eq!(component.states.step().as_str(), "a");
+        // Advance
+        assert_eq!(component.states.step().as_str(), "b");
+        assert_eq!(component.states.step().as_str(), "b");
+        assert_eq!(component.states.step().as_str(), "b");
+        assert_eq!(component.states.step().as_str(), "a");
+    }
+
+    #[test]
+    fn test_components_spinner_label() {
+        let component = Spinner::default().frames(Spinner::DOTS);
+        assert_eq!(component.get_label(), None);
+        let component = component.label("Loading repositories...");
+        assert_eq!(
+            component.get_label().as_deref(),
+            Some("Loading repositories...")
+        );
+    }
+
+    #[test]
+    fn test_components_spinner_frames() {
+        let mut component = Spinner::default().frames(&["[=  ]", "[ = ]", "[  =]"]);
+        assert_eq!(component.states.sequence.len(), 3);
+        assert_eq!(component.states.step().as_str(), "[=  ]");
+        let component = Spinner::default().frames(Spinner::BRAILLE);
+        assert_eq!(component.states.sequence.len(), 8);
+    }
+
+    #[test]
+    fn test_components_spinner_finish() {
+        let mut component = Spinner::default()
+            .frames(Spinner::LINE)
+            .label("Cloning")
+            .finish("✓", Color::Green);
+        assert_eq!(
+            component.get_done(),
+            Some((String::from("✓"), Color::Green))
+        );
+        let mut terminal = Terminal::new(TestBackend::new(12, 1)).unwrap();
+        // Glyph doesn't cycle across ticks
+        for _ in 0..5 {
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 12, 1)))
+                .unwrap();
+            let buffer = terminal.backend().buffer();
+            assert_eq!(buffer[(0, 0)].symbol(), "✓");
+            assert_eq!(buffer[(0, 0)].fg, Color::Green);
+            let label: String = (2..9).map(|x| buffer[(x, 0)].symbol()).collect();
+            assert_eq!(label.as_str(), "Cloning");
+        }
+        assert_eq!(component.states.step, 0);
+        // Spin again
+        component.attr(
+            Attribute::Custom(SPINNER_DONE),
+            AttrValue::Payload(PropPayload::None),
+        );
+        assert_eq!(component.get_done(), None);
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 1)))
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(0, 0)].symbol(), "-");
+        assert_eq!(component.states.step, 1);
+    }
+
+    #[test]
+    fn test_components_spinner_progress() {
+        let mut component = Spinner::default()
+            .frames(Spinner::LINE)
+            .label("Fetching")
+            .progress(Some(0.424));
+        assert_eq!(component.get_percentage().as_deref(), Some("42%"));
+        let mut terminal = Terminal::new(TestBackend::new(16, 1)).unwrap();
+        let mut render = |component: &mut Spinner| {
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 16, 1)))
+                .unwrap();
+            let buffer = terminal.backend().buffer();
+            (0..16).map(|x| buffer[(x, 0)].symbol()).collect::<String>()
+        };
+        assert_eq!(render(&mut component).trim_end(), "- Fetching 42%");
+        // Keeps spinning
+        assert_eq!(render(&mut component).trim_end(), "\\ Fetching 42%");
+        // Back to pure spinning
+        let mut component = component.progress(None);
+        assert_eq!(component.get_percentage(), None);
+        assert_eq!(render(&mut component).trim_end(), "| Fetching");
+        // Without label, out of range progress is clamped
+        let mut component = Spinner::default().frames(Spinner::LINE).progress(Some(1.5));
+        assert_eq!(render(&mut component).trim_end(), "- 100%");
+    }
 }
@@ -3,7 +3,46 @@
 //! This module exposes component states
 
 pub use super::{
-    bar_chart::BarChartStates, chart::ChartStates, checkbox::CheckboxStates, input::InputStates,
-    list::ListStates, radio::RadioStates, select::SelectStates, spinner::SpinnerStates,
+    bar_chart::BarChartStates, chart::ChartStates, checkbox::CheckboxStates,
+    container::ContainerStates, input::InputStates, list::ListStates, paragraph::ParagraphStates,
+    radio::RadioStates, select::SelectStates, sparkline::SparklineStates, spinner::SpinnerStates,
     table::TableStates, textarea::TextareaStates,
 };
+
+#[cfg(test)]
+mod test {
+
+    use crate::{Chart, List, Table, Textarea};
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::props::{AttrValue, Attribute};
+    use tuirealm::ratatui::{backend::TestBackend, layout::Rect, Terminal};
+    use tuirealm::MockComponent;
+
+    fn assert_last_area<C, F>(mut component: C, last_area: F)
+    where
+        C: MockComponent,
+        F: Fn(&C) -> Option<Rect>,
+    {
+        assert_eq!(last_area(&component), None);
+        let mut terminal = Terminal::new(TestBackend::new(20, 10)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(2, 1, 16, 8)))
+            .unwrap();
+        assert_eq!(last_area(&component), Some(Rect::new(2, 1, 16, 8)));
+        // Updated even if not displayed
+        component.attr(Attribute::Display, AttrValue::Flag(false));
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+            .unwrap();
+        assert_eq!(last_area(&component), Some(Rect::new(0, 0, 10, 5)));
+    }
+
+    #[test]
+    fn test_components_states_last_area() {
+        assert_last_area(Chart::default(), |x| x.states.last_area());
+        assert_last_area(List::default().scroll(true), |x| x.states.last_area());
+        assert_last_area(Table::default().scroll(true), |x| x.states.last_area());
+        assert_last_area(Textarea::default(), |x| x.states.last_area());
+    }
+}
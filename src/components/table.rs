@@ -2,30 +2,57 @@
 //!
 //! `Table` represents a read-only textual table component which can be scrollable through arrows or inactive
 
-use super::props::TABLE_COLUMN_SPACING;
+use super::props::{
+    BORDERS_SIDES, TABLE_ACTIONS, TABLE_ACTION_HINTS, TABLE_COLUMN_SPACING, TABLE_EMPTY_TEXT,
+    TABLE_FILTER_QUERY, TABLE_HIGHLIGHT_MODIFIERS, TABLE_REPORT_ROW, TABLE_RESIZABLE_COLUMNS,
+    TABLE_SCROLLBAR, TABLE_SCROLL_MARGIN, TABLE_STRICT_BOUNDARY, TABLE_UNFOCUSED_HIGHLIGHT,
+    TABLE_WRAP_CELLS,
+};
 use std::cmp::max;
+use std::collections::LinkedList;
 
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
-    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
-    Table as PropTable, TextModifiers,
+    Alignment, AttrValue, Attribute, BorderSides, Borders, Color, PropPayload, PropValue, Props,
+    Style, Table as PropTable, TextModifiers, TextSpan,
 };
 use tuirealm::ratatui::{
-    layout::{Constraint, Rect},
-    text::Span,
-    widgets::{Cell, Row, Table as TuiTable, TableState},
+    layout::{Constraint, Layout, Rect},
+    text::{Line, Span, Text},
+    widgets::{Block, Cell, Row, Table as TuiTable, TableState},
 };
 use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::utils::Theme;
+
+/// Percentage points a column is resized by
+const COLUMN_RESIZE_STEP: u16 = 5;
 
 // -- States
 
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableStates {
-    pub list_index: usize, // Index of selected item in textarea
-    pub list_len: usize,   // Lines in text area
+    pub list_index: usize,  // Index of selected item in textarea
+    pub list_len: usize,    // Lines in text area
+    pub list_offset: usize, // Index of the first visible row
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub column: usize, // Index of the active column, when columns are resizable
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub width_deltas: Vec<i16>, // Percentage points added to the width of each column
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub last_area: Option<Rect>, // Area the table has been last rendered in
 }
 
 impl TableStates {
+    /// ### last_area
+    ///
+    /// Get the area the component has been last rendered in, if any
+    pub fn last_area(&self) -> Option<Rect> {
+        self.last_area
+    }
+
     /// ### set_list_len
     ///
     /// Set list length
@@ -75,6 +102,15 @@ impl TableStates {
         self.list_index = 0;
     }
 
+    /// ### list_index_at
+    ///
+    /// Set list index to the provided index, if in range
+    pub fn list_index_at(&mut self, index: usize) {
+        if index < self.list_len {
+            self.list_index = index;
+        }
+    }
+
     /// ### list_index_at_last
     ///
     /// Set list index at the last item of the list
@@ -111,6 +147,43 @@ impl TableStates {
             self.list_index
         }
     }
+
+    /// ### visible_range
+    ///
+    /// Get the range `(start, end)`, end excluded, of the rows visible in a viewport `height` rows tall,
+    /// scrolling from the current offset just enough to keep the selected row and `margin` rows around it visible,
+    /// as when rendering with the same scroll margin (0 if not set)
+    #[must_use]
+    pub fn visible_range(&self, height: usize, margin: usize) -> (usize, usize) {
+        if height == 0 {
+            return (self.list_index, self.list_index);
+        }
+        let start = crate::utils::scroll_offset(
+            self.list_index,
+            self.list_offset,
+            self.list_len,
+            height,
+            margin,
+        );
+        (start, (start + height).min(self.list_len))
+    }
+
+    /// ### width_delta
+    ///
+    /// Get the percentage points added to the width of `column`
+    pub fn width_delta(&self, column: usize) -> i16 {
+        self.width_deltas.get(column).copied().unwrap_or(0)
+    }
+
+    /// ### resize_column
+    ///
+    /// Add `delta` percentage points to the width of `column`
+    pub fn resize_column(&mut self, column: usize, delta: i16) {
+        if self.width_deltas.len() <= column {
+            self.width_deltas.resize(column + 1, 0);
+        }
+        self.width_deltas[column] += delta;
+    }
 }
 
 // -- Component
@@ -124,9 +197,15 @@ pub struct Table {
     pub states: TableStates,
     hg_str: Option<String>, // CRAP CRAP CRAP
     headers: Vec<String>,   // CRAP CRAP CRAP
+    row_heights: Vec<u16>,  // Heights of the rows last rendered with wrapped cells
 }
 
 impl Table {
+    /// Custom command widening the active column, when columns are resizable
+    pub const CMD_WIDEN_COLUMN: &'static str = "widen-column";
+    /// Custom command narrowing the active column, when columns are resizable
+    pub const CMD_NARROW_COLUMN: &'static str = "narrow-column";
+
     pub fn foreground(mut self, fg: Color) -> Self {
         self.attr(Attribute::Foreground, AttrValue::Color(fg));
         self
@@ -137,6 +216,23 @@ impl Table {
         self
     }
 
+    /// Set colors, borders and styles from `theme`
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(theme.foreground));
+        self.attr(Attribute::Background, AttrValue::Color(theme.background));
+        self.attr(Attribute::Borders, AttrValue::Borders(theme.borders()));
+        self.attr(
+            Attribute::TextProps,
+            AttrValue::TextModifiers(theme.modifiers),
+        );
+        self.attr(Attribute::FocusStyle, AttrValue::Style(theme.inactive));
+        self.attr(
+            Attribute::HighlightedColor,
+            AttrValue::Color(theme.highlighted_color),
+        );
+        self
+    }
+
     pub fn inactive(mut self, s: Style) -> Self {
         self.attr(Attribute::FocusStyle, AttrValue::Style(s));
         self
@@ -152,6 +248,16 @@ impl Table {
         self
     }
 
+    /// Set which sides of the block have a border (default all), keeping the other border properties.
+    /// The sides replace the ones of `borders()`, regardless of the order they're set in
+    pub fn borders_sides(mut self, sides: BorderSides) -> Self {
+        self.attr(
+            Attribute::Custom(BORDERS_SIDES),
+            AttrValue::Number(sides.bits() as isize),
+        );
+        self
+    }
+
     pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
         self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
         self
@@ -177,6 +283,48 @@ impl Table {
         self
     }
 
+    /// Set the modifiers applied to the highlighted row while focused, in place of `REVERSED`
+    pub fn highlight_modifiers(mut self, m: TextModifiers) -> Self {
+        self.attr(
+            Attribute::Custom(TABLE_HIGHLIGHT_MODIFIERS),
+            AttrValue::TextModifiers(m),
+        );
+        self
+    }
+
+    fn get_highlight_modifiers(&self) -> TextModifiers {
+        self.props
+            .get_or(
+                Attribute::Custom(TABLE_HIGHLIGHT_MODIFIERS),
+                AttrValue::TextModifiers(TextModifiers::REVERSED),
+            )
+            .unwrap_text_modifiers()
+    }
+
+    /// If scrollable, make `state()` return the text of each cell in the selected row, instead of its index
+    pub fn report_row(mut self, report: bool) -> Self {
+        self.attr(Attribute::Custom(TABLE_REPORT_ROW), AttrValue::Flag(report));
+        self
+    }
+
+    /// Keep the selected row highlighted with a dimmed style while the table is not focused (default `false`)
+    pub fn unfocused_highlight(mut self, highlight: bool) -> Self {
+        self.attr(
+            Attribute::Custom(TABLE_UNFOCUSED_HIGHLIGHT),
+            AttrValue::Flag(highlight),
+        );
+        self
+    }
+
+    fn is_unfocused_highlight(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(TABLE_UNFOCUSED_HIGHLIGHT),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
     pub fn column_spacing(mut self, w: u16) -> Self {
         self.attr(Attribute::Custom(TABLE_COLUMN_SPACING), AttrValue::Size(w));
         self
@@ -187,6 +335,13 @@ impl Table {
         self
     }
 
+    /// Wrap the content of cells into their column width, making rows as tall as their tallest cell.
+    /// Rows are never shorter than `row_height`
+    pub fn wrap_cells(mut self, wrap: bool) -> Self {
+        self.attr(Attribute::Custom(TABLE_WRAP_CELLS), AttrValue::Flag(wrap));
+        self
+    }
+
     pub fn widths(mut self, w: &[u16]) -> Self {
         self.attr(
             Attribute::Width,
@@ -210,16 +365,102 @@ impl Table {
         self
     }
 
+    /// Only show the rows containing `query` (case insensitive) in any of their cells.
+    /// The content is kept intact; set an empty query to show all the rows again
+    pub fn filter_query<S: Into<String>>(mut self, query: S) -> Self {
+        self.attr(
+            Attribute::Custom(TABLE_FILTER_QUERY),
+            AttrValue::String(query.into()),
+        );
+        self
+    }
+
+    /// Register keys triggering an action on the selected row: `Cmd::Type(key)` returns
+    /// `CmdResult::Submit(State::Tup2((String(action), Usize(row))))`. Works only if `scrollable`
+    pub fn actions(mut self, actions: &[(char, &str)]) -> Self {
+        let list: LinkedList<PropPayload> = actions
+            .iter()
+            .map(|(key, action)| {
+                PropPayload::Tup2((
+                    PropValue::Str(key.to_string()),
+                    PropValue::Str(action.to_string()),
+                ))
+            })
+            .collect();
+        self.attr(
+            Attribute::Custom(TABLE_ACTIONS),
+            AttrValue::Payload(PropPayload::Linked(list)),
+        );
+        self
+    }
+
+    /// Render the registered actions as a hint at the bottom of the block (default `false`)
+    pub fn action_hints(mut self, hints: bool) -> Self {
+        self.attr(
+            Attribute::Custom(TABLE_ACTION_HINTS),
+            AttrValue::Flag(hints),
+        );
+        self
+    }
+
     pub fn table(mut self, t: PropTable) -> Self {
         self.attr(Attribute::Content, AttrValue::Table(t));
         self
     }
 
+    /// Render a scrollbar on the right edge, reflecting the position of the cursor (works only if `scrollable`)
+    pub fn scrollbar(mut self, scrollbar: bool) -> Self {
+        self.attr(
+            Attribute::Custom(TABLE_SCROLLBAR),
+            AttrValue::Flag(scrollbar),
+        );
+        self
+    }
+
+    /// Keep at least `margin` items visible above and below the selected one while scrolling (works only if `scrollable`).
+    /// The margin is clamped to half the viewport
+    pub fn scroll_margin(mut self, margin: usize) -> Self {
+        self.attr(
+            Attribute::Custom(TABLE_SCROLL_MARGIN),
+            AttrValue::Length(margin),
+        );
+        self
+    }
+
+    /// Let the user resize the columns: `Move(Left)` and `Move(Right)` select the active column,
+    /// while `Custom(Table::CMD_WIDEN_COLUMN)` and `Custom(Table::CMD_NARROW_COLUMN)` resize it (e.g. bound to Shift+Right and Shift+Left)
+    pub fn resizable_columns(mut self, resizable: bool) -> Self {
+        self.attr(
+            Attribute::Custom(TABLE_RESIZABLE_COLUMNS),
+            AttrValue::Flag(resizable),
+        );
+        self
+    }
+
+    /// Set the text displayed, centered in the block, when the table has no rows
+    pub fn empty_text<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(
+            Attribute::Custom(TABLE_EMPTY_TEXT),
+            AttrValue::String(text.into()),
+        );
+        self
+    }
+
     pub fn rewind(mut self, r: bool) -> Self {
         self.attr(Attribute::Rewind, AttrValue::Flag(r));
         self
     }
 
+    /// If true, a move which hits a boundary of the table returns `CmdResult::Changed` with the unchanged state,
+    /// instead of `CmdResult::None`
+    pub fn strict_boundary(mut self, strict: bool) -> Self {
+        self.attr(
+            Attribute::Custom(TABLE_STRICT_BOUNDARY),
+            AttrValue::Flag(strict),
+        );
+        self
+    }
+
     /// Set initial selected line
     /// This method must be called after `rows` and `scrollable` in order to work
     pub fn selected_line(mut self, line: usize) -> Self {
@@ -230,6 +471,197 @@ impl Table {
         self
     }
 
+    /// ### to_csv
+    ///
+    /// Export the headers, if any, and the rows matching the filter query as CSV, a line for each row.
+    /// Cells containing commas, quotes or line breaks are quoted
+    pub fn to_csv(&self) -> String {
+        self.export_rows()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| match cell.contains([',', '"', '\n', '\r']) {
+                        true => format!("\"{}\"", cell.replace('"', "\"\"")),
+                        false => cell.to_string(),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",")
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// ### to_plain
+    ///
+    /// Export the headers, if any, and the rows matching the filter query as plain text,
+    /// padding the cells with spaces to align the columns, which are separated by the column spacing
+    pub fn to_plain(&self) -> String {
+        let rows = self.export_rows();
+        let spacing = " ".repeat(
+            self.props
+                .get_or(Attribute::Custom(TABLE_COLUMN_SPACING), AttrValue::Size(1))
+                .unwrap_size() as usize,
+        );
+        let columns = rows.iter().map(|x| x.len()).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|col| {
+                rows.iter()
+                    .filter_map(|row| row.get(col))
+                    .map(|x| x.width())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .zip(widths.iter())
+                    .map(|(cell, width)| format!("{cell}{}", " ".repeat(width - cell.width())))
+                    .collect::<Vec<String>>()
+                    .join(&spacing)
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// ### export_rows
+    ///
+    /// Get the text of the headers, if any, and of the cells of the rows matching the filter query
+    fn export_rows(&self) -> Vec<Vec<&str>> {
+        let mut rows: Vec<Vec<&str>> = Vec::new();
+        if let Some(PropPayload::Vec(headers)) = self
+            .props
+            .get_ref(Attribute::Text)
+            .and_then(|x| x.as_payload())
+        {
+            rows.push(
+                headers
+                    .iter()
+                    .filter_map(|x| match x {
+                        PropValue::Str(header) => Some(header.as_str()),
+                        _ => None,
+                    })
+                    .collect(),
+            );
+        }
+        if let Some(AttrValue::Table(table)) = self.props.get_ref(Attribute::Content) {
+            rows.extend(self.visible_rows().into_iter().map(|i| {
+                table[i]
+                    .iter()
+                    .map(|cell| cell.content.as_str())
+                    .collect::<Vec<&str>>()
+            }));
+        }
+        rows
+    }
+
+    /// ### row_at
+    ///
+    /// Get the index of the row rendered at the provided screen position, if any.
+    /// Use this to map mouse clicks to rows, then select the row with `Cmd::GoTo(Position::At(index))`
+    pub fn row_at(&self, x: u16, y: u16) -> Option<usize> {
+        let area = self.inner_area(self.states.last_area?);
+        let row_height = self
+            .props
+            .get_or(Attribute::Height, AttrValue::Size(1))
+            .unwrap_size()
+            .max(1);
+        // Skip header
+        let has_header = self
+            .props
+            .get(Attribute::Text)
+            .map(|x| !x.unwrap_payload().unwrap_vec().is_empty())
+            .unwrap_or(false);
+        let top = match has_header {
+            true => area.top().saturating_add(row_height),
+            false => area.top(),
+        };
+        if x < area.left() || x >= area.right() || y < top || y >= area.bottom() {
+            return None;
+        }
+        // Rows with wrapped cells may have different heights
+        if !self.row_heights.is_empty() {
+            let mut row_top = top;
+            for (index, height) in self
+                .row_heights
+                .iter()
+                .enumerate()
+                .skip(self.states.list_offset)
+            {
+                row_top = row_top.saturating_add(*height);
+                if y < row_top {
+                    return Some(index);
+                }
+            }
+            return None;
+        }
+        let index = self.states.list_offset + ((y - top) / row_height) as usize;
+        (index < self.states.list_len).then_some(index)
+    }
+
+    /// ### inner_area
+    ///
+    /// Get the area the rows are rendered in, given the component area
+    fn inner_area(&self, area: Rect) -> Rect {
+        let title = self
+            .props
+            .get_or(
+                Attribute::Title,
+                AttrValue::Title((String::default(), Alignment::Center)),
+            )
+            .unwrap_title();
+        let borders = crate::utils::get_borders(&self.props);
+        self.with_action_hints(crate::utils::get_block(borders, Some(title), true, None))
+            .inner(area)
+    }
+
+    /// ### get_actions
+    ///
+    /// Get the registered `(key, action)` pairs
+    fn get_actions(&self) -> Vec<(char, &str)> {
+        match self
+            .props
+            .get_ref(Attribute::Custom(TABLE_ACTIONS))
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Linked(actions)) => actions
+                .iter()
+                .filter_map(|x| match x {
+                    PropPayload::Tup2((PropValue::Str(key), PropValue::Str(action))) => {
+                        key.chars().next().map(|key| (key, action.as_str()))
+                    }
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### with_action_hints
+    ///
+    /// Add the hint for the registered actions at the bottom of `block`, if enabled
+    fn with_action_hints<'a>(&self, block: Block<'a>) -> Block<'a> {
+        let enabled = self
+            .props
+            .get_or(
+                Attribute::Custom(TABLE_ACTION_HINTS),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag();
+        let actions = self.get_actions();
+        if !enabled || actions.is_empty() {
+            return block;
+        }
+        let hints = actions
+            .iter()
+            .map(|(key, action)| format!("{key}: {action}"))
+            .collect::<Vec<String>>()
+            .join("  ");
+        block.title_bottom(Line::from(hints).left_aligned())
+    }
+
     /// ### scrollable
     ///
     /// returns the value of the scrollable flag; by default is false
@@ -239,24 +671,215 @@ impl Table {
             .unwrap_flag()
     }
 
+    fn has_scrollbar(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(TABLE_SCROLLBAR), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    fn is_resizable_columns(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(TABLE_RESIZABLE_COLUMNS),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn get_scroll_margin(&self) -> Option<usize> {
+        self.props
+            .get(Attribute::Custom(TABLE_SCROLL_MARGIN))
+            .map(|x| x.unwrap_length())
+    }
+
     fn rewindable(&self) -> bool {
         self.props
             .get_or(Attribute::Rewind, AttrValue::Flag(false))
             .unwrap_flag()
     }
 
+    /// ### has_rows
+    ///
+    /// Returns whether the table has at least a non-empty visible row
+    fn has_rows(&self) -> bool {
+        match self.props.get_ref(Attribute::Content) {
+            Some(AttrValue::Table(table)) => self
+                .visible_rows()
+                .into_iter()
+                .any(|i| !table[i].is_empty()),
+            _ => false,
+        }
+    }
+
+    /// ### visible_rows
+    ///
+    /// Get the indexes in the content of the rows matching the filter query
+    fn visible_rows(&self) -> Vec<usize> {
+        crate::utils::filter_rows(&self.props, TABLE_FILTER_QUERY)
+    }
+
+    /// ### original_index
+    ///
+    /// Get the index in the content of the selected row, or `None` if no row is visible
+    fn original_index(&self) -> Option<usize> {
+        self.visible_rows().get(self.states.list_index).copied()
+    }
+
+    /// ### selected_row_state
+    ///
+    /// Get the text of each cell in the selected row, or `State::None` if there is no such row
+    fn selected_row_state(&self) -> State {
+        match self.props.get_ref(Attribute::Content) {
+            Some(AttrValue::Table(table)) => match self.original_index().and_then(|x| table.get(x))
+            {
+                Some(row) if !row.is_empty() => State::Vec(
+                    row.iter()
+                        .map(|cell| StateValue::String(cell.content.clone()))
+                        .collect(),
+                ),
+                _ => State::None,
+            },
+            _ => State::None,
+        }
+    }
+
+    fn is_report_row(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(TABLE_REPORT_ROW), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    fn is_strict_boundary(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(TABLE_STRICT_BOUNDARY),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    /// ### move_result
+    ///
+    /// Get the result for a move or a scroll; if the cursor hit a boundary, `None` is returned,
+    /// unless `strict_boundary` is set
+    fn move_result(&self, prev: usize) -> CmdResult {
+        let moved = prev != self.states.list_index;
+        let result = match moved {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        };
+        crate::utils::boundary_result(moved, self.is_strict_boundary(), self.state(), result)
+    }
+
+    fn is_wrap_cells(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(TABLE_WRAP_CELLS), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    /// ### column_widths
+    ///
+    /// Calculate the width of each column, given the component area, the same way the table widget does
+    fn column_widths(&self, area: Rect) -> Vec<u16> {
+        let area = self.inner_area(area);
+        // Space for the highlighted symbol is taken only when a row is selected
+        let selection_width = match (self.is_scrollable(), &self.hg_str) {
+            (true, Some(hg_str)) => hg_str.width() as u16,
+            _ => 0,
+        };
+        let spacing = self
+            .props
+            .get_or(Attribute::Custom(TABLE_COLUMN_SPACING), AttrValue::Size(1))
+            .unwrap_size();
+        Layout::horizontal(self.layout())
+            .spacing(spacing)
+            .split(Rect {
+                width: area.width.saturating_sub(selection_width),
+                ..area
+            })
+            .iter()
+            .map(|x| x.width)
+            .collect()
+    }
+
+    /// ### wrap_cell
+    ///
+    /// Wrap the cell content into `width`; each line of the content is wrapped on its own
+    fn wrap_cell(&self, col: &TextSpan, width: u16) -> Text<'static> {
+        let lines: Vec<Line> = col
+            .content
+            .split('\n')
+            .flat_map(|line| {
+                let mut span = col.clone();
+                span.content = line.to_string();
+                crate::utils::wrap_spans(&[span], width.max(1) as usize, 0, &self.props)
+            })
+            .collect();
+        Text::from(lines)
+    }
+
     /// ### layout
     ///
     /// Returns layout based on properties.
     /// If layout is not set in properties, they'll be divided by rows number
     fn layout(&self) -> Vec<Constraint> {
-        match self.props.get(Attribute::Width).map(|x| x.unwrap_payload()) {
-            Some(PropPayload::Vec(widths)) => widths
-                .iter()
-                .cloned()
-                .map(|x| x.unwrap_u16())
-                .map(Constraint::Percentage)
+        self.column_percentages()
+            .into_iter()
+            .map(Constraint::Percentage)
+            .collect()
+    }
+
+    /// ### column_percentages
+    ///
+    /// Get the width of each column in percentage, resized by the user if columns are resizable
+    fn column_percentages(&self) -> Vec<u16> {
+        let widths = self.base_percentages();
+        match self.is_resizable_columns() {
+            true => widths
+                .into_iter()
+                .enumerate()
+                .map(|(i, x)| (x as i16 + self.states.width_delta(i)).clamp(1, 100) as u16)
                 .collect(),
+            false => widths,
+        }
+    }
+
+    /// ### resize_active_column
+    ///
+    /// Widen (or narrow) the active column by `COLUMN_RESIZE_STEP`, taking the width from (or giving it to)
+    /// the next column, or the previous one for the last column, and keeping both at least 1% wide.
+    /// Returns whether the widths changed
+    fn resize_active_column(&mut self, widen: bool) -> bool {
+        let widths = self.column_percentages();
+        let column = self.states.column;
+        let neighbour = match column + 1 < widths.len() {
+            true => column + 1,
+            false => column.saturating_sub(1),
+        };
+        let (Some(width), Some(neighbour_width)) = (widths.get(column), widths.get(neighbour))
+        else {
+            return false;
+        };
+        if neighbour == column {
+            return false;
+        }
+        let delta = match widen {
+            true => COLUMN_RESIZE_STEP.min(neighbour_width - 1) as i16,
+            false => -(COLUMN_RESIZE_STEP.min(width - 1) as i16),
+        };
+        self.states.resize_column(column, delta);
+        self.states.resize_column(neighbour, -delta);
+        delta != 0
+    }
+
+    /// ### base_percentages
+    ///
+    /// Get the width of each column in percentage, as set with `Width`, or split equally
+    fn base_percentages(&self) -> Vec<u16> {
+        match self.props.get(Attribute::Width).map(|x| x.unwrap_payload()) {
+            Some(PropPayload::Vec(widths)) => {
+                widths.iter().cloned().map(|x| x.unwrap_u16()).collect()
+            }
             _ => {
                 // Get amount of columns (maximum len of row elements)
                 let columns: usize =
@@ -266,9 +889,7 @@ impl Table {
                     };
                 // Calc width in equal way, make sure not to divide by zero (this can happen when rows is [[]])
                 let width: u16 = (100 / max(columns, 1)) as u16;
-                (0..columns)
-                    .map(|_| Constraint::Percentage(width))
-                    .collect()
+                (0..columns).map(|_| width).collect()
             }
         }
     }
@@ -276,6 +897,7 @@ impl Table {
 
 impl MockComponent for Table {
     fn view(&mut self, render: &mut Frame, area: Rect) {
+        self.states.last_area = Some(area);
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
             let foreground = self
                 .props
@@ -299,10 +921,7 @@ impl MockComponent for Table {
                     AttrValue::Title((String::default(), Alignment::Center)),
                 )
                 .unwrap_title();
-            let borders = self
-                .props
-                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
-                .unwrap_borders();
+            let borders = crate::utils::get_borders(&self.props);
             let focus = self
                 .props
                 .get_or(Attribute::Focus, AttrValue::Flag(false))
@@ -315,53 +934,104 @@ impl MockComponent for Table {
                 .props
                 .get_or(Attribute::Height, AttrValue::Size(1))
                 .unwrap_size();
+            // Highlighted symbol
+            self.hg_str = self
+                .props
+                .get(Attribute::HighlightedStr)
+                .map(|x| x.unwrap_string());
+            let wrap_widths: Option<Vec<u16>> =
+                self.is_wrap_cells().then(|| self.column_widths(area));
+            self.row_heights.clear();
             // Make rows
+            let visible = self.visible_rows();
             let rows: Vec<Row> = match self.props.get(Attribute::Content).map(|x| x.unwrap_table())
             {
-                Some(table) => table
+                Some(table) => visible
                     .iter()
-                    .map(|row| {
-                        let columns: Vec<Cell> = row
-                            .iter()
-                            .map(|col| {
-                                let (fg, bg, modifiers) =
-                                    crate::utils::use_or_default_styles(&self.props, col);
-                                Cell::from(Span::styled(
-                                    col.content.clone(),
-                                    Style::default().add_modifier(modifiers).fg(fg).bg(bg),
-                                ))
-                            })
-                            .collect();
-                        Row::new(columns).height(row_height)
+                    .map(|i| &table[*i])
+                    .map(|row| match &wrap_widths {
+                        Some(widths) => {
+                            let cells: Vec<Text> = row
+                                .iter()
+                                .enumerate()
+                                .map(|(i, col)| {
+                                    self.wrap_cell(col, widths.get(i).copied().unwrap_or(0))
+                                })
+                                .collect();
+                            let height = cells
+                                .iter()
+                                .map(|x| x.height() as u16)
+                                .max()
+                                .unwrap_or(0)
+                                .max(row_height);
+                            self.row_heights.push(height);
+                            Row::new(cells).height(height)
+                        }
+                        None => {
+                            let columns: Vec<Cell> = row
+                                .iter()
+                                .map(|col| {
+                                    let (fg, bg, modifiers) =
+                                        crate::utils::use_or_default_styles(&self.props, col);
+                                    Cell::from(Span::styled(
+                                        col.content.clone(),
+                                        Style::default().add_modifier(modifiers).fg(fg).bg(bg),
+                                    ))
+                                })
+                                .collect();
+                            Row::new(columns).height(row_height)
+                        }
                     })
                     .collect(), // Make List item from TextSpan
                 _ => Vec::new(),
             };
+            // Placeholder when there are no rows
+            if let Some(text) = self
+                .props
+                .get(Attribute::Custom(TABLE_EMPTY_TEXT))
+                .filter(|_| !self.has_rows())
+                .map(|x| x.unwrap_string())
+            {
+                let style = Style::default()
+                    .fg(foreground)
+                    .bg(background)
+                    .add_modifier(modifiers);
+                let block = self.with_action_hints(crate::utils::get_block(
+                    borders,
+                    Some(title),
+                    focus,
+                    inactive_style,
+                ));
+                crate::utils::draw_empty_text(render, block.style(style), area, &text, style);
+                self.states.list_offset = 0;
+                return;
+            }
             let highlighted_color = self
                 .props
                 .get(Attribute::HighlightedColor)
                 .map(|x| x.unwrap_color());
             let widths: Vec<Constraint> = self.layout();
 
-            let mut table = TuiTable::new(rows, &widths).block(crate::utils::get_block(
-                borders,
-                Some(title),
-                focus,
-                inactive_style,
+            let mut table = TuiTable::new(rows, &widths).block(self.with_action_hints(
+                crate::utils::get_block(borders, Some(title), focus, inactive_style),
             ));
-            if let Some(highlighted_color) = highlighted_color {
-                table = table.highlight_style(Style::default().fg(highlighted_color).add_modifier(
-                    match focus {
-                        true => modifiers | TextModifiers::REVERSED,
-                        false => modifiers,
-                    },
-                ));
+            if !focus && self.is_scrollable() && self.is_unfocused_highlight() {
+                // Dimmed highlight, so the selected row stays visible while inactive
+                let mut style = Style::default()
+                    .add_modifier(modifiers | self.get_highlight_modifiers() | TextModifiers::DIM);
+                if let Some(highlighted_color) = highlighted_color {
+                    style = style.fg(highlighted_color);
+                }
+                table = table.row_highlight_style(style);
+            } else if let Some(highlighted_color) = highlighted_color {
+                table =
+                    table.row_highlight_style(Style::default().fg(highlighted_color).add_modifier(
+                        match focus {
+                            true => modifiers | self.get_highlight_modifiers(),
+                            false => modifiers,
+                        },
+                    ));
             }
-            // Highlighted symbol
-            self.hg_str = self
-                .props
-                .get(Attribute::HighlightedStr)
-                .map(|x| x.unwrap_string());
             if let Some(hg_str) = &self.hg_str {
                 table = table.highlight_symbol(hg_str.as_str());
             }
@@ -386,7 +1056,18 @@ impl MockComponent for Table {
                 })
                 .unwrap_or_default();
             if !self.headers.is_empty() {
-                let headers: Vec<&str> = self.headers.iter().map(|x| x.as_str()).collect();
+                // The active column is underlined while it can be resized
+                let active = (focus && self.is_resizable_columns()).then_some(self.states.column);
+                let headers: Vec<Cell> = self
+                    .headers
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| match Some(i) == active {
+                        true => Cell::from(x.as_str())
+                            .style(Style::default().add_modifier(TextModifiers::UNDERLINED)),
+                        false => Cell::from(x.as_str()),
+                    })
+                    .collect();
                 table = table.header(
                     Row::new(headers)
                         .style(
@@ -399,11 +1080,52 @@ impl MockComponent for Table {
                 );
             }
             if self.is_scrollable() {
+                // Viewport is measured in rows; the header takes a row too
+                let inner = self.inner_area(area);
+                let header_height = match self.headers.is_empty() {
+                    true => 0,
+                    false => row_height,
+                };
+                let viewport = inner.height.saturating_sub(header_height) / row_height.max(1);
                 let mut state: TableState = TableState::default();
                 state.select(Some(self.states.list_index));
+                match (self.get_scroll_margin(), self.row_heights.is_empty()) {
+                    (Some(margin), true) => {
+                        *state.offset_mut() = crate::utils::scroll_offset(
+                            self.states.list_index,
+                            self.states.list_offset,
+                            self.states.list_len,
+                            viewport as usize,
+                            margin,
+                        );
+                    }
+                    // Rows with wrapped cells may take more lines
+                    (margin, false) => {
+                        *state.offset_mut() = crate::utils::scroll_offset_by_heights(
+                            self.states.list_index,
+                            self.states.list_offset,
+                            &self.row_heights,
+                            inner.height.saturating_sub(header_height) as usize,
+                            margin.unwrap_or(0),
+                        );
+                    }
+                    (None, true) => {}
+                }
                 render.render_stateful_widget(table, area, &mut state);
+                self.states.list_offset = state.offset();
+                if self.has_scrollbar() {
+                    crate::utils::draw_scrollbar(
+                        render,
+                        area,
+                        inner,
+                        self.states.list_len,
+                        viewport as usize,
+                        self.states.list_index,
+                    );
+                }
             } else {
                 render.render_widget(table, area);
+                self.states.list_offset = 0;
             }
         }
     }
@@ -413,30 +1135,42 @@ impl MockComponent for Table {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        // Keep the selected row selected when the filter changes, if it's still visible
+        let selected = match attr == Attribute::Custom(TABLE_FILTER_QUERY) {
+            true => self.original_index(),
+            false => None,
+        };
         self.props.set(attr, value);
-        if matches!(attr, Attribute::Content) {
+        if matches!(attr, Attribute::Content) || attr == Attribute::Custom(TABLE_FILTER_QUERY) {
             // Update list len and fix index
-            self.states.set_list_len(
-                match self.props.get(Attribute::Content).map(|x| x.unwrap_table()) {
-                    Some(spans) => spans.len(),
-                    _ => 0,
-                },
-            );
+            let visible = self.visible_rows();
+            self.states.set_list_len(visible.len());
+            if let Some(index) = selected.and_then(|x| crate::utils::visible_position(&visible, x))
+            {
+                self.states.list_index = index;
+            }
             self.states.fix_list_index();
         } else if matches!(attr, Attribute::Value) && self.is_scrollable() {
-            self.states.list_index = self
+            let line = self
                 .props
                 .get(Attribute::Value)
                 .map(|x| x.unwrap_payload().unwrap_one().unwrap_usize())
                 .unwrap_or(0);
+            // Select the first visible row from the provided line
+            self.states.list_index =
+                crate::utils::visible_position(&self.visible_rows(), line).unwrap_or(line);
             self.states.fix_list_index();
         }
     }
 
     fn state(&self) -> State {
-        match self.is_scrollable() {
-            true => State::One(StateValue::Usize(self.states.list_index)),
-            false => State::None,
+        match (self.is_scrollable(), self.is_report_row()) {
+            (true, true) => self.selected_row_state(),
+            (true, false) => match self.original_index() {
+                Some(index) => State::One(StateValue::Usize(index)),
+                None => State::None,
+            },
+            (false, _) => State::None,
         }
     }
 
@@ -445,20 +1179,12 @@ impl MockComponent for Table {
             Cmd::Move(Direction::Down) => {
                 let prev = self.states.list_index;
                 self.states.incr_list_index(self.rewindable());
-                if prev != self.states.list_index {
-                    CmdResult::Changed(self.state())
-                } else {
-                    CmdResult::None
-                }
+                self.move_result(prev)
             }
             Cmd::Move(Direction::Up) => {
                 let prev = self.states.list_index;
                 self.states.decr_list_index(self.rewindable());
-                if prev != self.states.list_index {
-                    CmdResult::Changed(self.state())
-                } else {
-                    CmdResult::None
-                }
+                self.move_result(prev)
             }
             Cmd::Scroll(Direction::Down) => {
                 let prev = self.states.list_index;
@@ -466,13 +1192,12 @@ impl MockComponent for Table {
                     .props
                     .get_or(Attribute::ScrollStep, AttrValue::Length(8))
                     .unwrap_length();
-                let step: usize = self.states.calc_max_step_ahead(step);
-                (0..step).for_each(|_| self.states.incr_list_index(false));
-                if prev != self.states.list_index {
-                    CmdResult::Changed(self.state())
-                } else {
-                    CmdResult::None
+                // Wrap around only if the cursor is already at the boundary
+                match self.states.calc_max_step_ahead(step) {
+                    0 if self.rewindable() => self.states.incr_list_index(true),
+                    step => (0..step).for_each(|_| self.states.incr_list_index(false)),
                 }
+                self.move_result(prev)
             }
             Cmd::Scroll(Direction::Up) => {
                 let prev = self.states.list_index;
@@ -480,13 +1205,12 @@ impl MockComponent for Table {
                     .props
                     .get_or(Attribute::ScrollStep, AttrValue::Length(8))
                     .unwrap_length();
-                let step: usize = self.states.calc_max_step_behind(step);
-                (0..step).for_each(|_| self.states.decr_list_index(false));
-                if prev != self.states.list_index {
-                    CmdResult::Changed(self.state())
-                } else {
-                    CmdResult::None
+                // Wrap around only if the cursor is already at the boundary
+                match self.states.calc_max_step_behind(step) {
+                    0 if self.rewindable() => self.states.decr_list_index(true),
+                    step => (0..step).for_each(|_| self.states.decr_list_index(false)),
                 }
+                self.move_result(prev)
             }
             Cmd::GoTo(Position::Begin) => {
                 let prev = self.states.list_index;
@@ -506,6 +1230,62 @@ impl MockComponent for Table {
                     CmdResult::None
                 }
             }
+            Cmd::GoTo(Position::At(index)) => {
+                let prev = self.states.list_index;
+                // Targets out of range are clamped to the last item
+                self.states
+                    .list_index_at(index.min(self.states.list_len.saturating_sub(1)));
+                if prev != self.states.list_index {
+                    CmdResult::Changed(self.state())
+                } else {
+                    CmdResult::None
+                }
+            }
+            Cmd::Move(Direction::Left) if self.is_resizable_columns() => {
+                let prev = self.states.column;
+                self.states.column = self.states.column.saturating_sub(1);
+                match prev != self.states.column {
+                    true => CmdResult::Changed(self.state()),
+                    false => CmdResult::None,
+                }
+            }
+            Cmd::Move(Direction::Right) if self.is_resizable_columns() => {
+                let prev = self.states.column;
+                let columns = self.base_percentages().len();
+                self.states.column = (self.states.column + 1).min(columns.saturating_sub(1));
+                match prev != self.states.column {
+                    true => CmdResult::Changed(self.state()),
+                    false => CmdResult::None,
+                }
+            }
+            Cmd::Custom(Self::CMD_WIDEN_COLUMN) if self.is_resizable_columns() => {
+                match self.resize_active_column(true) {
+                    true => CmdResult::Changed(self.state()),
+                    false => CmdResult::None,
+                }
+            }
+            Cmd::Custom(Self::CMD_NARROW_COLUMN) if self.is_resizable_columns() => {
+                match self.resize_active_column(false) {
+                    true => CmdResult::Changed(self.state()),
+                    false => CmdResult::None,
+                }
+            }
+            Cmd::Submit if self.is_scrollable() => match self.state() {
+                State::None => CmdResult::None,
+                state => CmdResult::Submit(state),
+            },
+            Cmd::Type(ch) if self.is_scrollable() => {
+                match (
+                    self.get_actions().into_iter().find(|(key, _)| *key == ch),
+                    self.original_index(),
+                ) {
+                    (Some((_, action)), Some(index)) => CmdResult::Submit(State::Tup2((
+                        StateValue::String(action.to_string()),
+                        StateValue::Usize(index),
+                    ))),
+                    _ => CmdResult::None,
+                }
+            }
             _ => CmdResult::None,
         }
     }
@@ -516,7 +1296,20 @@ mod tests {
 
     use super::*;
     use pretty_assertions::assert_eq;
-    use tuirealm::props::{TableBuilder, TextSpan};
+    use tuirealm::props::{BorderType, TableBuilder, TextSpan};
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
+
+    /// Make `n` rows with a single cell, whose text is `cell(index)`
+    fn make_rows(n: usize, cell: impl Fn(usize) -> String) -> PropTable {
+        let mut rows = TableBuilder::default();
+        for i in 0..n {
+            if i > 0 {
+                rows.add_row();
+            }
+            rows.add_col(TextSpan::from(cell(i)));
+        }
+        rows.build()
+    }
 
     #[test]
     fn table_states() {
@@ -552,6 +1345,23 @@ mod tests {
         assert_eq!(states.list_index, 2);
     }
 
+    #[test]
+    fn table_states_visible_range() {
+        let mut states = TableStates::default();
+        assert_eq!(states.visible_range(5, 0), (0, 0));
+        states.set_list_len(20);
+        assert_eq!(states.visible_range(5, 0), (0, 5));
+        states.list_index = 10;
+        assert_eq!(states.visible_range(5, 0), (6, 11));
+        states.list_index = 19;
+        assert_eq!(states.visible_range(5, 0), (15, 20));
+        // From the current offset, keeping the margin
+        states.list_offset = 5;
+        states.list_index = 6;
+        assert_eq!(states.visible_range(5, 0), (5, 10));
+        assert_eq!(states.visible_range(5, 2), (4, 9));
+    }
+
     #[test]
     fn test_component_table_scrolling() {
         // Make component
@@ -737,6 +1547,54 @@ mod tests {
         assert_eq!(component.state(), State::None);
     }
 
+    #[test]
+    fn test_components_table_row_at() {
+        let mut component = Table::default()
+            .borders(Borders::default())
+            .scroll(true)
+            .row_height(2)
+            .headers(&["Event", "Message"])
+            .table(make_rows(10, |i| format!("event {i}")));
+        // Not rendered yet
+        assert_eq!(component.row_at(1, 3), None);
+        let mut terminal = Terminal::new(TestBackend::new(30, 10)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 30, 10)))
+            .unwrap();
+        // Borders, header and outside
+        assert_eq!(component.row_at(1, 0), None);
+        assert_eq!(component.row_at(1, 1), None);
+        assert_eq!(component.row_at(1, 2), None);
+        assert_eq!(component.row_at(1, 9), None);
+        assert_eq!(component.row_at(0, 3), None);
+        assert_eq!(component.row_at(1, 3), Some(0));
+        assert_eq!(component.row_at(1, 4), Some(0));
+        assert_eq!(component.row_at(1, 5), Some(1));
+        assert_eq!(component.row_at(1, 8), Some(2));
+        // Scroll, then click
+        component.perform(Cmd::GoTo(Position::End));
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 30, 10)))
+            .unwrap();
+        assert_eq!(component.states.list_offset, 7);
+        let index = component.row_at(1, 3).unwrap();
+        assert_eq!(index, 7);
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(index))),
+            CmdResult::Changed(State::One(StateValue::Usize(7)))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(index))),
+            CmdResult::None
+        );
+        // Out of range is clamped
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(10))),
+            CmdResult::Changed(State::One(StateValue::Usize(9)))
+        );
+        assert_eq!(component.states.list_index, 9);
+    }
+
     #[test]
     fn should_init_list_value() {
         let mut component = Table::default()
@@ -788,4 +1646,724 @@ mod tests {
         );
         assert_eq!(component.states.list_index, 6);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_components_table_states_serde() {
+        let mut states = TableStates::default();
+        states.set_list_len(5);
+        states.list_index = 3;
+        states.resize_column(1, 5);
+        let json = serde_json::to_string(&states).unwrap();
+        let restored: TableStates = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.list_index, 3);
+        assert_eq!(restored.list_len, 5);
+        assert_eq!(restored.width_deltas, vec![0, 5]);
+    }
+
+    #[test]
+    fn test_components_table_theme() {
+        let theme = Theme {
+            highlighted_color: Color::LightYellow,
+            modifiers: TextModifiers::BOLD,
+            ..Theme::default()
+        };
+        let component = Table::default().theme(&theme);
+        assert_eq!(
+            component.query(Attribute::TextProps),
+            Some(AttrValue::TextModifiers(TextModifiers::BOLD))
+        );
+        assert_eq!(
+            component.query(Attribute::HighlightedColor),
+            Some(AttrValue::Color(Color::LightYellow))
+        );
+    }
+
+    #[test]
+    fn test_components_table_highlight_modifiers() {
+        let render = |component: &mut Table| {
+            let mut terminal = Terminal::new(TestBackend::new(10, 4)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 10, 4)))
+                .unwrap();
+            terminal.backend().buffer()[(1, 1)].modifier
+        };
+        let mut component = Table::default()
+            .highlighted_color(Color::Yellow)
+            .scroll(true)
+            .table(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("row"))
+                    .build(),
+            );
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        // Default is unchanged
+        assert_eq!(component.get_highlight_modifiers(), TextModifiers::REVERSED);
+        assert!(render(&mut component).contains(TextModifiers::REVERSED));
+        let mut component =
+            component.highlight_modifiers(TextModifiers::BOLD | TextModifiers::UNDERLINED);
+        assert_eq!(
+            component.query(Attribute::Custom(TABLE_HIGHLIGHT_MODIFIERS)),
+            Some(AttrValue::TextModifiers(
+                TextModifiers::BOLD | TextModifiers::UNDERLINED
+            ))
+        );
+        let modifier = render(&mut component);
+        assert!(modifier.contains(TextModifiers::BOLD | TextModifiers::UNDERLINED));
+        assert!(!modifier.contains(TextModifiers::REVERSED));
+    }
+
+    #[test]
+    fn test_components_table_unfocused_highlight() {
+        let render = |component: &mut Table| {
+            let mut terminal = Terminal::new(TestBackend::new(10, 4)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 10, 4)))
+                .unwrap();
+            terminal.backend().buffer()[(1, 1)].clone()
+        };
+        let mut component = Table::default()
+            .highlighted_color(Color::Yellow)
+            .scroll(true)
+            .table(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("row"))
+                    .build(),
+            );
+        // Unfocused without the option: no highlight modifiers
+        assert!(!render(&mut component)
+            .modifier
+            .contains(TextModifiers::REVERSED));
+        let mut component = component.unfocused_highlight(true);
+        assert_eq!(
+            component.query(Attribute::Custom(TABLE_UNFOCUSED_HIGHLIGHT)),
+            Some(AttrValue::Flag(true))
+        );
+        let unfocused = render(&mut component);
+        assert_eq!(unfocused.fg, Color::Yellow);
+        assert!(unfocused
+            .modifier
+            .contains(TextModifiers::REVERSED | TextModifiers::DIM));
+        // Focused is not dimmed
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        let focused = render(&mut component);
+        assert!(focused.modifier.contains(TextModifiers::REVERSED));
+        assert!(!focused.modifier.contains(TextModifiers::DIM));
+        assert_ne!(focused.modifier, unfocused.modifier);
+    }
+
+    #[test]
+    fn test_components_table_strict_boundary() {
+        let mut component = Table::default().scroll(true).step(4).table(
+            TableBuilder::default()
+                .add_col(TextSpan::from("first"))
+                .add_row()
+                .add_col(TextSpan::from("second"))
+                .build(),
+        );
+        // Boundaries return None by default
+        assert_eq!(component.perform(Cmd::Move(Direction::Up)), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::None
+        );
+        let mut component = component.strict_boundary(true);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        // GoTo is not affected
+        assert_eq!(component.perform(Cmd::GoTo(Position::End)), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_table_empty_text() {
+        let render = |component: &mut Table| {
+            let mut terminal = Terminal::new(TestBackend::new(14, 5)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 14, 5)))
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        let row = |buffer: &tuirealm::ratatui::buffer::Buffer, y: u16| -> String {
+            (0..14).map(|x| buffer[(x, y)].symbol()).collect()
+        };
+        let mut component = Table::default()
+            .table(TableBuilder::default().build())
+            .empty_text("No results");
+        assert_eq!(
+            component.query(Attribute::Custom(TABLE_EMPTY_TEXT)),
+            Some(AttrValue::String("No results".to_string()))
+        );
+        // Placeholder is centered in the block
+        let buffer = render(&mut component);
+        assert_eq!(row(&buffer, 2).as_str(), "│ No results │");
+        // Rows are rendered when there are any
+        let mut component = component.table(
+            TableBuilder::default()
+                .add_col(TextSpan::from("first"))
+                .build(),
+        );
+        let buffer = render(&mut component);
+        assert_eq!(row(&buffer, 1).as_str(), "│first       │");
+        assert_eq!(row(&buffer, 2).as_str(), "│            │");
+    }
+
+    #[test]
+    fn test_components_table_default_modifiers() {
+        let mut component = Table::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .modifiers(TextModifiers::BOLD)
+            .table(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("a"))
+                    .add_col(TextSpan::from("b").italic())
+                    .build(),
+            );
+        let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 2)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        // Cells inherit the table modifiers, and add their own on top
+        assert_eq!(buffer[(0, 0)].modifier, TextModifiers::BOLD);
+        let cell = (0..10).map(|x| &buffer[(x, 0)]).find(|x| x.symbol() == "b");
+        assert_eq!(
+            cell.unwrap().modifier,
+            TextModifiers::BOLD | TextModifiers::ITALIC
+        );
+    }
+
+    #[test]
+    fn test_components_table_scrollbar() {
+        let render = |component: &mut Table| {
+            let mut terminal = Terminal::new(TestBackend::new(8, 5)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 8, 5)))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (1..4)
+                .map(|y| buffer[(7, y)].symbol().to_string())
+                .collect::<Vec<String>>()
+                .concat()
+        };
+        let rows = |n: usize| make_rows(n, |i| i.to_string());
+        let mut component = Table::default().scroll(true).scrollbar(true).table(rows(9));
+        assert_eq!(render(&mut component).as_str(), "█║║");
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(render(&mut component).as_str(), "║║█");
+        // Hidden if the content fits
+        let mut component = component.table(rows(3));
+        assert_eq!(render(&mut component).as_str(), "│││");
+        // Hidden if not set
+        let mut component = component.scrollbar(false).table(rows(9));
+        assert_eq!(render(&mut component).as_str(), "│││");
+    }
+
+    #[test]
+    fn test_components_table_wrap_cells_scroll() {
+        let mut component = Table::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .widths(&[100])
+            .scroll(true)
+            .scroll_margin(1)
+            .wrap_cells(true)
+            .table(make_rows(6, |i| format!("{i}\n-")));
+        let mut terminal = Terminal::new(TestBackend::new(4, 4)).unwrap();
+        // Each row takes two lines, so two rows fit in the viewport
+        for (index, top) in [(1, "1"), (2, "2"), (3, "3"), (2, "2"), (1, "1"), (0, "0")] {
+            component.perform(Cmd::GoTo(Position::At(index)));
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 4, 4)))
+                .unwrap();
+            assert_eq!(terminal.backend().buffer()[(0, 0)].symbol(), top);
+            assert_eq!(component.states.list_offset, index);
+        }
+    }
+
+    #[test]
+    fn test_components_table_wrap_cells() {
+        let render = |component: &mut Table| {
+            let mut terminal = Terminal::new(TestBackend::new(14, 8)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 14, 8)))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (1..7)
+                .map(|y| (1..13).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+                .collect::<Vec<String>>()
+        };
+        let mut component = Table::default()
+            .widths(&[50, 50])
+            .column_spacing(0)
+            .wrap_cells(true)
+            .table(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("id"))
+                    .add_col(TextSpan::from("a long description"))
+                    .add_row()
+                    .add_col(TextSpan::from("next"))
+                    .add_col(TextSpan::from("one\ntwo"))
+                    .build(),
+            );
+        assert_eq!(
+            render(&mut component),
+            vec![
+                "id    a long",
+                "      descri",
+                "      ption ",
+                "next  one   ",
+                "      two   ",
+                "            ",
+            ]
+        );
+        // Row at takes the row heights into account
+        assert_eq!(component.row_at(2, 3), Some(0));
+        assert_eq!(component.row_at(2, 4), Some(1));
+        assert_eq!(component.row_at(2, 5), Some(1));
+        assert_eq!(component.row_at(2, 6), None);
+        // Rows are never shorter than row height
+        let mut component = component.row_height(3);
+        let lines = render(&mut component);
+        assert_eq!(lines[3].as_str(), "next  one   ");
+        assert_eq!(lines[4].as_str(), "      two   ");
+        assert_eq!(component.row_at(2, 6), Some(1));
+        // Cells are clipped without wrap
+        let mut component = component.row_height(1).wrap_cells(false);
+        let lines = render(&mut component);
+        assert_eq!(lines[0].as_str(), "id    a long");
+        assert_eq!(lines[1].as_str(), "next  onetwo");
+        assert_eq!(component.row_at(2, 2), Some(1));
+    }
+
+    #[test]
+    fn test_components_table_submit() {
+        let rows = TableBuilder::default()
+            .add_col(TextSpan::from("first"))
+            .add_row()
+            .add_col(TextSpan::from("second"))
+            .build();
+        let mut component = Table::default().scroll(true).table(rows.clone());
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(1)))
+        );
+        // Not scrollable
+        let mut component = Table::default().table(rows);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_table_report_row() {
+        let mut component = Table::default().scroll(true).table(
+            TableBuilder::default()
+                .add_col(TextSpan::from("lemon"))
+                .add_col(TextSpan::from("yellow"))
+                .add_row()
+                .add_col(TextSpan::from("strawberry"))
+                .add_col(TextSpan::from("red"))
+                .build(),
+        );
+        // Index by default
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
+        // Row cells
+        let mut component = component.report_row(true);
+        assert_eq!(
+            component.query(Attribute::Custom(TABLE_REPORT_ROW)),
+            Some(AttrValue::Flag(true))
+        );
+        assert_eq!(
+            component.state(),
+            State::Vec(vec![
+                StateValue::String(String::from("strawberry")),
+                StateValue::String(String::from("red")),
+            ])
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::Vec(vec![
+                StateValue::String(String::from("lemon")),
+                StateValue::String(String::from("yellow")),
+            ]))
+        );
+        // Empty table
+        let component = Table::default()
+            .scroll(true)
+            .report_row(true)
+            .table(TableBuilder::default().build());
+        assert_eq!(component.state(), State::None);
+    }
+
+    #[test]
+    fn test_components_table_borders_sides() {
+        let component = Table::default()
+            .borders(Borders::default().modifiers(BorderType::Double))
+            .borders_sides(BorderSides::TOP | BorderSides::BOTTOM);
+        let borders = crate::utils::get_borders(&component.props);
+        assert_eq!(borders.sides, BorderSides::TOP | BorderSides::BOTTOM);
+        assert_eq!(borders.modifiers, BorderType::Double);
+    }
+
+    #[test]
+    fn test_components_table_goto_at() {
+        let mut component = Table::default().scroll(true).table(
+            TableBuilder::default()
+                .add_col(TextSpan::from("first"))
+                .add_row()
+                .add_col(TextSpan::from("second"))
+                .add_row()
+                .add_col(TextSpan::from("third"))
+                .build(),
+        );
+        // In range
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(1))),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(1))),
+            CmdResult::None
+        );
+        // Out of range is clamped to the last item
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(100))),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(3))),
+            CmdResult::None
+        );
+        assert_eq!(component.states.list_index, 2);
+        // Empty
+        let mut component = Table::default().scroll(true);
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(4))),
+            CmdResult::None
+        );
+        assert_eq!(component.states.list_index, 0);
+    }
+
+    #[test]
+    fn test_components_table_filter_query() {
+        let mut component = Table::default()
+            .scroll(true)
+            .actions(&[('k', "kill")])
+            .table(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("apple"))
+                    .add_row()
+                    .add_col(TextSpan::from("banana"))
+                    .add_row()
+                    .add_col(TextSpan::from("cherry"))
+                    .build(),
+            )
+            .filter_query("CHERRY");
+        assert_eq!(component.states.list_len, 1);
+        assert_eq!(component.state(), State::One(StateValue::Usize(2)));
+        // Reported row and actions refer to the original content
+        assert_eq!(
+            component.perform(Cmd::Type('k')),
+            CmdResult::Submit(State::Tup2((
+                StateValue::String(String::from("kill")),
+                StateValue::Usize(2)
+            )))
+        );
+        let mut component = component.report_row(true);
+        assert_eq!(
+            component.state(),
+            State::Vec(vec![StateValue::String(String::from("cherry"))])
+        );
+        // Nothing visible
+        component.attr(
+            Attribute::Custom(TABLE_FILTER_QUERY),
+            AttrValue::String(String::from("kiwi")),
+        );
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(component.perform(Cmd::Type('k')), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_table_actions() {
+        let mut component = Table::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .scroll(true)
+            .actions(&[('k', "kill"), ('r', "restart")])
+            .table(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("init"))
+                    .add_row()
+                    .add_col(TextSpan::from("sshd"))
+                    .build(),
+            );
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(
+            component.perform(Cmd::Type('k')),
+            CmdResult::Submit(State::Tup2((
+                StateValue::String(String::from("kill")),
+                StateValue::Usize(1)
+            )))
+        );
+        assert_eq!(
+            component.perform(Cmd::Type('r')),
+            CmdResult::Submit(State::Tup2((
+                StateValue::String(String::from("restart")),
+                StateValue::Usize(1)
+            )))
+        );
+        // Unregistered key
+        assert_eq!(component.perform(Cmd::Type('x')), CmdResult::None);
+        // Hints
+        let mut component = component.action_hints(true);
+        let mut terminal = Terminal::new(TestBackend::new(20, 3)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 20, 3)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let line: String = (0..20).map(|x| buffer[(x, 2)].symbol()).collect();
+        assert_eq!(line, "k: kill  r: restart ");
+        assert_eq!(component.row_at(0, 2), None);
+        // Not scrollable
+        let mut component = component.scroll(false);
+        assert_eq!(component.perform(Cmd::Type('k')), CmdResult::None);
+        // Empty
+        let mut component = Table::default().scroll(true).actions(&[('k', "kill")]);
+        assert_eq!(component.perform(Cmd::Type('k')), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_table_resizable_columns() {
+        let mut component = Table::default().widths(&[30, 30, 30]).table(
+            TableBuilder::default()
+                .add_col(TextSpan::from("a"))
+                .add_col(TextSpan::from("b"))
+                .add_col(TextSpan::from("c"))
+                .build(),
+        );
+        // Not resizable
+        component.perform(Cmd::Custom(Table::CMD_WIDEN_COLUMN));
+        assert_eq!(component.column_percentages(), vec![30, 30, 30]);
+        let mut component = component.resizable_columns(true);
+        // Widen the active column, taking the width from the next one
+        assert_eq!(
+            component.perform(Cmd::Custom(Table::CMD_WIDEN_COLUMN)),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(
+            component.layout(),
+            vec![
+                Constraint::Percentage(35),
+                Constraint::Percentage(25),
+                Constraint::Percentage(30)
+            ]
+        );
+        component.perform(Cmd::Custom(Table::CMD_WIDEN_COLUMN));
+        assert_eq!(component.column_percentages(), vec![40, 20, 30]);
+        // Select the last column
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::None)
+        );
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.column, 2);
+        // Narrow it, giving the width to the previous one, down to 1%
+        (0..6).for_each(|_| {
+            component.perform(Cmd::Custom(Table::CMD_NARROW_COLUMN));
+        });
+        assert_eq!(component.column_percentages(), vec![40, 49, 1]);
+        assert_eq!(
+            component.perform(Cmd::Custom(Table::CMD_NARROW_COLUMN)),
+            CmdResult::None
+        );
+        // The neighbour can't go below 1% either
+        component.perform(Cmd::Move(Direction::Left));
+        assert_eq!(
+            component.perform(Cmd::Custom(Table::CMD_WIDEN_COLUMN)),
+            CmdResult::None
+        );
+        assert_eq!(component.column_percentages(), vec![40, 49, 1]);
+        assert_eq!(component.states.width_deltas, vec![10, 19, -29]);
+    }
+
+    #[test]
+    fn test_components_table_resizable_columns_full_width() {
+        let mut component = Table::default()
+            .widths(&[50, 25, 25])
+            .scroll(true)
+            .resizable_columns(true)
+            .table(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("a"))
+                    .add_col(TextSpan::from("b"))
+                    .add_col(TextSpan::from("c"))
+                    .build(),
+            );
+        // Widths already fill the whole width
+        assert_eq!(
+            component.perform(Cmd::Custom(Table::CMD_WIDEN_COLUMN)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(component.column_percentages(), vec![55, 20, 25]);
+        component.perform(Cmd::Move(Direction::Right));
+        component.perform(Cmd::Move(Direction::Right));
+        component.perform(Cmd::Custom(Table::CMD_WIDEN_COLUMN));
+        assert_eq!(component.column_percentages(), vec![55, 15, 30]);
+        component.perform(Cmd::Custom(Table::CMD_NARROW_COLUMN));
+        component.perform(Cmd::Custom(Table::CMD_NARROW_COLUMN));
+        assert_eq!(component.column_percentages(), vec![55, 25, 20]);
+        assert_eq!(component.column_percentages().iter().sum::<u16>(), 100);
+    }
+
+    #[test]
+    fn test_components_table_scroll_margin() {
+        // Rendering applies the margin
+        let mut component = Table::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .scroll(true)
+            .scroll_margin(2)
+            .table(make_rows(20, |i| i.to_string()));
+        let mut terminal = Terminal::new(TestBackend::new(10, 5)).unwrap();
+        for _ in 0..3 {
+            component.perform(Cmd::Move(Direction::Down));
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+                .unwrap();
+        }
+        assert_eq!(component.states.list_offset, 1);
+        component.perform(Cmd::GoTo(Position::End));
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+            .unwrap();
+        assert_eq!(component.states.list_offset, 15);
+        component.perform(Cmd::Move(Direction::Up));
+        component.perform(Cmd::Move(Direction::Up));
+        component.perform(Cmd::Move(Direction::Up));
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+            .unwrap();
+        assert_eq!(component.states.list_offset, 14);
+    }
+
+    #[test]
+    fn test_components_table_same_content() {
+        let rows = |n: usize| make_rows(n, |i| i.to_string());
+        let mut component = Table::default().scroll(true).table(rows(10));
+        component.perform(Cmd::GoTo(Position::At(8)));
+        let mut terminal = Terminal::new(TestBackend::new(10, 5)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 10, 5)))
+            .unwrap();
+        let offset = component.states.list_offset;
+        assert!(offset > 0);
+        // Setting the same content again keeps index and offset
+        component.attr(Attribute::Content, AttrValue::Table(rows(10)));
+        assert_eq!(component.states.list_index, 8);
+        assert_eq!(component.states.list_offset, offset);
+        assert_eq!(component.state(), State::One(StateValue::Usize(8)));
+        // Different content fixes the index
+        component.attr(Attribute::Content, AttrValue::Table(rows(5)));
+        assert_eq!(component.state(), State::One(StateValue::Usize(4)));
+    }
+
+    #[test]
+    fn test_components_table_scroll_rewind() {
+        let rows = make_rows(10, |i| i.to_string());
+        let mut component = Table::default().scroll(true).step(4).table(rows);
+        // Without rewind the step is clamped at the boundaries
+        component.perform(Cmd::GoTo(Position::At(8)));
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(9)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::None
+        );
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::None
+        );
+        // With rewind the cursor wraps, once at the boundary
+        let mut component = component.rewind(true);
+        component.perform(Cmd::GoTo(Position::At(8)));
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(9)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(9)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(5)))
+        );
+    }
+
+    #[test]
+    fn test_components_table_export() {
+        let component = Table::default().headers(&["Name", "Notes"]).table(
+            TableBuilder::default()
+                .add_col(TextSpan::from("apple"))
+                .add_col(TextSpan::from("red, sweet"))
+                .add_row()
+                .add_col(TextSpan::from("kiwi"))
+                .add_col(TextSpan::from("the \"hairy\" one"))
+                .add_row()
+                .add_col(TextSpan::from("lime"))
+                .add_col(TextSpan::from("sour"))
+                .build(),
+        );
+        assert_eq!(
+            component.to_csv(),
+            "Name,Notes\napple,\"red, sweet\"\nkiwi,\"the \"\"hairy\"\" one\"\nlime,sour"
+        );
+        assert_eq!(
+            component.to_plain(),
+            "Name  Notes\napple red, sweet\nkiwi  the \"hairy\" one\nlime  sour"
+        );
+        // Filtered rows are skipped
+        let component = component.filter_query("i").column_spacing(2);
+        assert_eq!(
+            component.to_plain(),
+            "Name  Notes\nkiwi  the \"hairy\" one\nlime  sour"
+        );
+        assert_eq!(
+            Table::default()
+                .table(
+                    TableBuilder::default()
+                        .add_col(TextSpan::from("two\nlines"))
+                        .build()
+                )
+                .to_csv(),
+            "\"two\nlines\""
+        );
+    }
 }
@@ -13,20 +13,38 @@ use tuirealm::props::{
 };
 use tuirealm::ratatui::{
     layout::Rect,
-    widgets::{List, ListItem, ListState},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, ListState},
 };
-use tuirealm::{Frame, MockComponent, State};
-use unicode_width::UnicodeWidthStr;
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+// -- Props
+use super::props::{TEXTAREA_SCROLLBAR, TEXTAREA_TAB_WIDTH, TEXTAREA_WRAP_MARKER};
 
 // -- States
 
 #[derive(Default)]
 pub struct TextareaStates {
-    pub list_index: usize, // Index of selected item in textarea
-    pub list_len: usize,   // Lines in text area
+    pub list_index: usize,       // Index of selected item in textarea
+    pub list_len: usize,         // Lines in text area
+    pub h_offset: usize,         // Index of the first visible column, when wrap is disabled
+    pub columns: usize,          // Width of the longest line
+    pub width: usize,            // Columns visible in the textarea
+    pub last_area: Option<Rect>, // Area the textarea has been last rendered in
 }
 
 impl TextareaStates {
+    /// ### last_area
+    ///
+    /// Get the area the component has been last rendered in, if any
+    pub fn last_area(&self) -> Option<Rect> {
+        self.last_area
+    }
+
     /// ### set_list_len
     ///
     /// Set list length and fix list index
@@ -84,6 +102,69 @@ impl TextareaStates {
         }
     }
 
+    /// ### visible_range
+    ///
+    /// Get the range `(start, end)`, end excluded, of the rows visible in a viewport `height` lines tall,
+    /// given the `heights` of the rows once rendered (wrapped rows take more lines).
+    /// As ratatui does when rendering, the rows are shown from the first one, scrolling just enough to keep the selected row visible
+    #[must_use]
+    pub fn visible_range(&self, heights: &[usize], height: usize) -> (usize, usize) {
+        if height == 0 || heights.is_empty() {
+            return (self.list_index, self.list_index);
+        }
+        let selected = self.list_index.min(heights.len() - 1);
+        // Rows which fit from the top
+        let (mut start, mut end, mut used) = (0, 0, 0);
+        while end < heights.len() && used + heights[end] <= height {
+            used += heights[end];
+            end += 1;
+        }
+        // Show the selected row, hiding the rows at the top which don't fit anymore
+        while end <= selected {
+            used += heights[end];
+            end += 1;
+            while used > height {
+                used -= heights[start];
+                start += 1;
+            }
+        }
+        (start, end)
+    }
+
+    /// ### set_columns
+    ///
+    /// Set the width of the longest line and the visible width and fix horizontal offset
+    pub fn set_columns(&mut self, columns: usize, width: usize) {
+        self.columns = columns;
+        self.width = width;
+        self.fix_h_offset();
+    }
+
+    /// ### scroll_right
+    ///
+    /// Scroll right by `step` columns, without moving past the end of the longest line
+    pub fn scroll_right(&mut self, step: usize) {
+        self.h_offset = (self.h_offset + step).min(self.max_h_offset());
+    }
+
+    /// ### scroll_left
+    ///
+    /// Scroll left by `step` columns
+    pub fn scroll_left(&mut self, step: usize) {
+        self.h_offset = self.h_offset.saturating_sub(step);
+    }
+
+    /// ### fix_h_offset
+    ///
+    /// Keep horizontal offset if possible, otherwise set it to the max horizontal offset
+    pub fn fix_h_offset(&mut self) {
+        self.h_offset = self.h_offset.min(self.max_h_offset());
+    }
+
+    fn max_h_offset(&self) -> usize {
+        self.columns.saturating_sub(self.width)
+    }
+
     /// ### calc_max_step_ahead
     ///
     /// Calculate the max step ahead to scroll list
@@ -121,6 +202,10 @@ pub struct Textarea {
     props: Props,
     pub states: TextareaStates,
     hg_str: Option<String>, // CRAP CRAP CRAP
+    text_revision: usize,   // Incremented each time the text or its styles change
+    wrap_cache: Option<(usize, usize, Vec<ListItem<'static>>)>, // (text revision, wrap width, wrapped lines)
+    #[cfg(feature = "regex")]
+    highlighters: Vec<(Regex, Style)>,
 }
 
 impl Textarea {
@@ -164,6 +249,49 @@ impl Textarea {
         self
     }
 
+    /// Wrap lines into the textarea width (default `true`); when disabled, lines can be scrolled horizontally
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.attr(Attribute::TextWrap, AttrValue::Flag(wrap));
+        self
+    }
+
+    /// Render a scrollbar on the right edge, reflecting the position in the text
+    pub fn scrollbar(mut self, scrollbar: bool) -> Self {
+        self.attr(
+            Attribute::Custom(TEXTAREA_SCROLLBAR),
+            AttrValue::Flag(scrollbar),
+        );
+        self
+    }
+
+    /// Set the amount of columns between tab stops, used to expand tabs into spaces (default 4)
+    pub fn tab_width(mut self, width: usize) -> Self {
+        self.attr(
+            Attribute::Custom(TEXTAREA_TAB_WIDTH),
+            AttrValue::Length(width),
+        );
+        self
+    }
+
+    /// Render a dimmed `marker` at the end of each line which continues on the next row because of wrapping.
+    /// The marker takes columns from the wrap width
+    pub fn wrap_marker(mut self, marker: char) -> Self {
+        self.attr(
+            Attribute::Custom(TEXTAREA_WRAP_MARKER),
+            AttrValue::String(marker.to_string()),
+        );
+        self
+    }
+
+    /// Style the text matching each regex with the associated style.
+    /// When more regexes match the same text, the first one in the list wins.
+    #[cfg(feature = "regex")]
+    pub fn highlighters(mut self, highlighters: Vec<(Regex, Style)>) -> Self {
+        self.highlighters = highlighters;
+        self.invalidate_wrap_cache();
+        self
+    }
+
     pub fn text_rows(mut self, rows: &[TextSpan]) -> Self {
         self.states.set_list_len(rows.len());
         self.attr(
@@ -174,10 +302,293 @@ impl Textarea {
         );
         self
     }
+
+    /// Set text rows from a string, making a row for each line.
+    /// Empty lines are kept, while a trailing newline doesn't make an extra row.
+    pub fn text<S: AsRef<str>>(self, s: S) -> Self {
+        let rows: Vec<TextSpan> = s.as_ref().lines().map(TextSpan::from).collect();
+        self.text_rows(&rows)
+    }
+
+    /// ### full_text
+    ///
+    /// Get the whole text, joining the rows with a newline
+    pub fn full_text(&self) -> String {
+        self.text_rows_content().join("\n")
+    }
+
+    /// ### visible_text
+    ///
+    /// Get the text of the rows visible in the area the textarea has been last rendered in,
+    /// joining them with a newline. Returns an empty string if it has never been rendered.
+    pub fn visible_text(&self) -> String {
+        let area = match self.states.last_area() {
+            Some(area) => area,
+            None => return String::new(),
+        };
+        let height = self.get_block().inner(area).height as usize;
+        let heights: Vec<usize> = match self.is_wrap() {
+            true => {
+                let width = self.wrap_width(area);
+                match self.cached_lines(width) {
+                    Some(lines) => lines.iter().map(|x| x.height()).collect(),
+                    None => self
+                        .text_spans()
+                        .into_iter()
+                        .map(|x| ListItem::new(self.wrap_row(x, width)).height())
+                        .collect(),
+                }
+            }
+            false => vec![1; self.states.list_len],
+        };
+        let (start, end) = self.states.visible_range(&heights, height);
+        let rows = self.text_rows_content();
+        rows[start.min(rows.len())..end.min(rows.len())].join("\n")
+    }
+}
+
+impl Textarea {
+    /// ### invalidate_wrap_cache
+    ///
+    /// Bump the text revision, so that lines are wrapped again on the next render
+    fn invalidate_wrap_cache(&mut self) {
+        self.text_revision = self.text_revision.wrapping_add(1);
+        self.wrap_cache = None;
+    }
+
+    /// ### cached_lines
+    ///
+    /// Get the wrapped lines cached for `width`, if still valid for the current text revision
+    fn cached_lines(&self, width: usize) -> Option<&[ListItem<'static>]> {
+        match &self.wrap_cache {
+            Some((revision, cached_width, lines))
+                if *revision == self.text_revision && *cached_width == width =>
+            {
+                Some(lines.as_slice())
+            }
+            _ => None,
+        }
+    }
+
+    fn is_wrap(&self) -> bool {
+        self.props
+            .get_or(Attribute::TextWrap, AttrValue::Flag(true))
+            .unwrap_flag()
+    }
+
+    fn has_scrollbar(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(TEXTAREA_SCROLLBAR),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn get_wrap_marker(&self) -> Option<String> {
+        self.props
+            .get(Attribute::Custom(TEXTAREA_WRAP_MARKER))
+            .map(|x| x.unwrap_string())
+    }
+
+    /// ### wrap_width
+    ///
+    /// Get the width rows are wrapped at when rendered in `area`
+    fn wrap_width(&self, area: Rect) -> usize {
+        // NOTE: wrap width is width of area minus 2 (block) minus width of highlighting string
+        (area.width as usize)
+            .saturating_sub(self.hg_str.as_ref().map(|x| x.width()).unwrap_or(0))
+            .saturating_sub(2)
+    }
+
+    /// ### get_block
+    ///
+    /// Make the block the textarea is rendered in
+    fn get_block(&self) -> Block<'static> {
+        let title = self
+            .props
+            .get_or(
+                Attribute::Title,
+                AttrValue::Title((String::default(), Alignment::Center)),
+            )
+            .unwrap_title();
+        let borders = self
+            .props
+            .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+            .unwrap_borders();
+        let focus = self
+            .props
+            .get_or(Attribute::Focus, AttrValue::Flag(false))
+            .unwrap_flag();
+        let inactive_style = self
+            .props
+            .get(Attribute::FocusStyle)
+            .map(|x| x.unwrap_style());
+        crate::utils::get_block(borders, Some(title), focus, inactive_style)
+    }
+
+    /// ### wrap_row
+    ///
+    /// Wrap a row at `width`, ending each segment but the last with the wrap marker, if set
+    fn wrap_row(&self, row: TextSpan, width: usize) -> Vec<Line<'static>> {
+        let marker = self.get_wrap_marker();
+        let marker_width = marker.as_ref().map(|x| x.width()).unwrap_or(0);
+        let mut lines = crate::utils::wrap_spans(
+            &self.highlight(row),
+            width.saturating_sub(marker_width),
+            0,
+            &self.props,
+        );
+        if let Some(marker) = marker {
+            let segments = lines.len();
+            lines
+                .iter_mut()
+                .take(segments.saturating_sub(1))
+                .for_each(|line| {
+                    line.spans.push(Span::styled(
+                        marker.clone(),
+                        Style::default().add_modifier(TextModifiers::DIM),
+                    ))
+                });
+        }
+        lines
+    }
+
+    fn get_tab_width(&self) -> usize {
+        self.props
+            .get_or(Attribute::Custom(TEXTAREA_TAB_WIDTH), AttrValue::Length(4))
+            .unwrap_length()
+    }
+
+    /// ### text_rows_content
+    ///
+    /// Get the content of the text rows, as they've been set
+    fn text_rows_content(&self) -> Vec<&str> {
+        match self
+            .props
+            .get_ref(Attribute::Text)
+            .and_then(|x| x.as_payload())
+        {
+            Some(PropPayload::Vec(spans)) => spans
+                .iter()
+                .filter_map(|x| match x {
+                    PropValue::TextSpan(span) => Some(span.content.as_str()),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### text_spans
+    ///
+    /// Get the text rows, with tabs expanded into spaces
+    fn text_spans(&self) -> Vec<TextSpan> {
+        let tab_width = self.get_tab_width();
+        match self.props.get(Attribute::Text).map(|x| x.unwrap_payload()) {
+            Some(PropPayload::Vec(spans)) => spans
+                .into_iter()
+                .map(|x| {
+                    let mut span = x.unwrap_text_span();
+                    span.content = crate::utils::expand_tabs(&span.content, tab_width);
+                    span
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### unwrapped_lines
+    ///
+    /// Make one line for each row, skipping the columns before the horizontal offset
+    fn unwrapped_lines(&self) -> Vec<ListItem<'static>> {
+        self.text_spans()
+            .into_iter()
+            .map(|x| {
+                let mut skip = self.states.h_offset;
+                let spans: Vec<Span> = self
+                    .highlight(x)
+                    .iter()
+                    .map(|x| {
+                        let content = skip_columns(x.content.as_str(), skip);
+                        skip = skip.saturating_sub(x.content.width());
+                        let (fg, bg, tmod) = crate::utils::use_or_default_styles(&self.props, x);
+                        Span::styled(content, Style::default().fg(fg).bg(bg).add_modifier(tmod))
+                    })
+                    .filter(|x| !x.content.is_empty())
+                    .collect();
+                ListItem::new(Line::from(spans))
+            })
+            .collect()
+    }
+
+    /// ### highlight
+    ///
+    /// Split the row into spans, styling the runs matched by highlighters
+    #[cfg(feature = "regex")]
+    fn highlight(&self, row: TextSpan) -> Vec<TextSpan> {
+        let content = row.content.as_str();
+        if self.highlighters.is_empty() || content.is_empty() {
+            return vec![row];
+        }
+        // Index of the highlighter styling each byte; first match wins
+        let mut owners: Vec<Option<usize>> = vec![None; content.len()];
+        for (i, (regex, _)) in self.highlighters.iter().enumerate() {
+            for m in regex.find_iter(content) {
+                owners[m.range()]
+                    .iter_mut()
+                    .filter(|x| x.is_none())
+                    .for_each(|x| *x = Some(i));
+            }
+        }
+        // Group bytes with the same owner into spans
+        let mut spans: Vec<TextSpan> = Vec::new();
+        let mut start: usize = 0;
+        for end in 1..=content.len() {
+            if end < content.len() && owners[end] == owners[start] {
+                continue;
+            }
+            let mut span = TextSpan {
+                content: content[start..end].to_string(),
+                ..row.clone()
+            };
+            if let Some(i) = owners[start] {
+                let style = self.highlighters[i].1;
+                span.fg = style.fg.unwrap_or(span.fg);
+                span.bg = style.bg.unwrap_or(span.bg);
+                span.modifiers = (span.modifiers | style.add_modifier) - style.sub_modifier;
+            }
+            spans.push(span);
+            start = end;
+        }
+        spans
+    }
+
+    #[cfg(not(feature = "regex"))]
+    fn highlight(&self, row: TextSpan) -> Vec<TextSpan> {
+        vec![row]
+    }
+}
+
+/// ### skip_columns
+///
+/// Remove the first `columns` columns from `s`
+fn skip_columns(s: &str, columns: usize) -> String {
+    let mut skipped: usize = 0;
+    s.chars()
+        .skip_while(|c| {
+            if skipped >= columns {
+                return false;
+            }
+            skipped += c.width().unwrap_or(0);
+            true
+        })
+        .collect()
 }
 
 impl MockComponent for Textarea {
     fn view(&mut self, render: &mut Frame, area: Rect) {
+        self.states.last_area = Some(area);
         // Make a Span
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
             // Make text items
@@ -186,22 +597,30 @@ impl MockComponent for Textarea {
                 .props
                 .get(Attribute::HighlightedStr)
                 .map(|x| x.unwrap_string());
-            // NOTE: wrap width is width of area minus 2 (block) minus width of highlighting string
-            let wrap_width =
-                (area.width as usize) - self.hg_str.as_ref().map(|x| x.width()).unwrap_or(0) - 2;
-            let lines: Vec<ListItem> =
-                match self.props.get(Attribute::Text).map(|x| x.unwrap_payload()) {
-                    Some(PropPayload::Vec(spans)) => spans
-                        .iter()
-                        .cloned()
-                        .map(|x| x.unwrap_text_span())
-                        .map(|x| {
-                            crate::utils::wrap_spans(vec![x].as_slice(), wrap_width, &self.props)
-                        })
+            let wrap_width = self.wrap_width(area);
+            let lines: Vec<ListItem> = if self.is_wrap() {
+                if self.cached_lines(wrap_width).is_none() {
+                    let lines: Vec<ListItem<'static>> = self
+                        .text_spans()
+                        .into_iter()
+                        .map(|x| self.wrap_row(x, wrap_width))
                         .map(ListItem::new)
-                        .collect(),
-                    _ => Vec::new(),
-                };
+                        .collect();
+                    self.wrap_cache = Some((self.text_revision, wrap_width, lines));
+                }
+                self.cached_lines(wrap_width)
+                    .map(|x| x.to_vec())
+                    .unwrap_or_default()
+            } else {
+                let columns = self
+                    .text_spans()
+                    .iter()
+                    .map(|x| x.content.width())
+                    .max()
+                    .unwrap_or(0);
+                self.states.set_columns(columns, wrap_width);
+                self.unwrapped_lines()
+            };
             let foreground = self
                 .props
                 .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
@@ -217,36 +636,16 @@ impl MockComponent for Textarea {
                     AttrValue::TextModifiers(TextModifiers::empty()),
                 )
                 .unwrap_text_modifiers();
-            let title = self
-                .props
-                .get_or(
-                    Attribute::Title,
-                    AttrValue::Title((String::default(), Alignment::Center)),
-                )
-                .unwrap_title();
-            let borders = self
-                .props
-                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
-                .unwrap_borders();
-            let focus = self
-                .props
-                .get_or(Attribute::Focus, AttrValue::Flag(false))
-                .unwrap_flag();
-            let inactive_style = self
-                .props
-                .get(Attribute::FocusStyle)
-                .map(|x| x.unwrap_style());
             let mut state: ListState = ListState::default();
             state.select(Some(self.states.list_index));
+            // Scrollbar is measured in rendered lines, since rows may be wrapped
+            let heights: Vec<usize> = lines.iter().map(|x| x.height()).collect();
+            let block = self.get_block();
+            let inner = block.inner(area);
             // Make component
 
             let mut list = List::new(lines)
-                .block(crate::utils::get_block(
-                    borders,
-                    Some(title),
-                    focus,
-                    inactive_style,
-                ))
+                .block(block)
                 .direction(tuirealm::ratatui::widgets::ListDirection::TopToBottom)
                 .style(
                     Style::default()
@@ -259,6 +658,16 @@ impl MockComponent for Textarea {
                 list = list.highlight_symbol(hg_str);
             }
             render.render_stateful_widget(list, area, &mut state);
+            if self.has_scrollbar() {
+                crate::utils::draw_scrollbar(
+                    render,
+                    area,
+                    inner,
+                    heights.iter().sum(),
+                    inner.height as usize,
+                    heights.iter().take(self.states.list_index).sum(),
+                );
+            }
         }
     }
 
@@ -267,6 +676,18 @@ impl MockComponent for Textarea {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        // Wrapped lines depend on the text, on the tab width, on the wrap marker and on the default styles
+        if matches!(
+            attr,
+            Attribute::Text
+                | Attribute::Foreground
+                | Attribute::Background
+                | Attribute::TextProps
+                | Attribute::Custom(TEXTAREA_TAB_WIDTH)
+                | Attribute::Custom(TEXTAREA_WRAP_MARKER)
+        ) {
+            self.invalidate_wrap_cache();
+        }
         self.props.set(attr, value);
         // Update list len and fix index
         self.states.set_list_len(
@@ -279,10 +700,12 @@ impl MockComponent for Textarea {
     }
 
     fn state(&self) -> State {
-        State::None
+        State::One(StateValue::Usize(self.states.list_index))
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        let prev_index = self.states.list_index;
+        let prev_h_offset = self.states.h_offset;
         match cmd {
             Cmd::Move(Direction::Down) => {
                 self.states.incr_list_index();
@@ -312,9 +735,19 @@ impl MockComponent for Textarea {
             Cmd::GoTo(Position::End) => {
                 self.states.list_index_at_last();
             }
+            Cmd::Move(Direction::Right) if !self.is_wrap() => {
+                self.states.scroll_right(1);
+            }
+            Cmd::Move(Direction::Left) if !self.is_wrap() => {
+                self.states.scroll_left(1);
+            }
             _ => {}
         }
-        CmdResult::None
+        if prev_index != self.states.list_index || prev_h_offset != self.states.h_offset {
+            CmdResult::Changed(self.state())
+        } else {
+            CmdResult::None
+        }
     }
 }
 
@@ -324,6 +757,29 @@ mod tests {
     use super::*;
 
     use pretty_assertions::assert_eq;
+    use tuirealm::ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn test_components_textarea_states_visible_range() {
+        let mut states = TextareaStates::default();
+        assert_eq!(states.visible_range(&[], 5), (0, 0));
+        states.set_list_len(20);
+        let heights = vec![1; 20];
+        assert_eq!(states.visible_range(&heights, 5), (0, 5));
+        states.list_index = 10;
+        assert_eq!(states.visible_range(&heights, 5), (6, 11));
+        states.list_index = 19;
+        assert_eq!(states.visible_range(&heights, 5), (15, 20));
+        // Wrapped rows
+        let heights = [2, 1, 3, 1];
+        states.set_list_len(4);
+        states.list_index = 1;
+        assert_eq!(states.visible_range(&heights, 3), (0, 2));
+        states.list_index = 2;
+        assert_eq!(states.visible_range(&heights, 3), (2, 3));
+        states.list_index = 3;
+        assert_eq!(states.visible_range(&heights, 5), (1, 4));
+    }
 
     #[test]
     fn test_components_textarea() {
@@ -353,43 +809,380 @@ mod tests {
         assert_eq!(component.states.list_index, 1); // Kept
         assert_eq!(component.states.list_len, 3);
         // get value
-        assert_eq!(component.state(), State::None);
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
         // Render
         assert_eq!(component.states.list_index, 1);
         // Handle inputs
         assert_eq!(
             component.perform(Cmd::Move(Direction::Down)),
-            CmdResult::None
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
         );
         // Index should be incremented
         assert_eq!(component.states.list_index, 2);
+        // Already at last item
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
         // Index should be decremented
-        assert_eq!(component.perform(Cmd::Move(Direction::Up)), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
         // Index should be incremented
         assert_eq!(component.states.list_index, 1);
         // Index should be 2
         assert_eq!(
             component.perform(Cmd::Scroll(Direction::Down)),
-            CmdResult::None
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
         );
         // Index should be incremented
         assert_eq!(component.states.list_index, 2);
         // Index should be 0
         assert_eq!(
             component.perform(Cmd::Scroll(Direction::Up)),
-            CmdResult::None
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
         );
         // End
-        assert_eq!(component.perform(Cmd::GoTo(Position::End)), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
         assert_eq!(component.states.list_index, 2);
         // Home
         assert_eq!(
             component.perform(Cmd::GoTo(Position::Begin)),
-            CmdResult::None
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
         );
         // Index should be incremented
         assert_eq!(component.states.list_index, 0);
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::Begin)),
+            CmdResult::None
+        );
+        // Horizontal scroll is ignored while wrapping
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
         // On key
         assert_eq!(component.perform(Cmd::Delete), CmdResult::None);
     }
+
+    #[test]
+    fn test_components_textarea_text() {
+        let component = Textarea::default().text("a\n\nb");
+        assert_eq!(component.states.list_len, 3);
+        assert_eq!(
+            component.query(Attribute::Text),
+            Some(AttrValue::Payload(PropPayload::Vec(vec![
+                PropValue::TextSpan(TextSpan::from("a")),
+                PropValue::TextSpan(TextSpan::from("")),
+                PropValue::TextSpan(TextSpan::from("b")),
+            ])))
+        );
+        assert_eq!(Textarea::default().text("a\nb\n").states.list_len, 2);
+        assert_eq!(Textarea::default().text("a\r\nb\n\n").states.list_len, 3);
+        assert_eq!(Textarea::default().text("single").states.list_len, 1);
+        assert_eq!(Textarea::default().text("").states.list_len, 0);
+    }
+
+    #[test]
+    fn test_components_textarea_wrap_cache() {
+        let mut component = Textarea::default()
+            .borders(Borders::default())
+            .text_rows(&[TextSpan::from("welcome to tui-realm, this line is long")]);
+        let mut terminal = Terminal::new(TestBackend::new(20, 10)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 20, 10)))
+            .unwrap();
+        let revision = component.text_revision;
+        assert!(component.cached_lines(18).is_some());
+        // Render again with same width and text: cache is reused
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 20, 10)))
+            .unwrap();
+        assert_eq!(component.text_revision, revision);
+        assert!(component.cached_lines(18).is_some());
+        // Different width: lines are wrapped again
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 10)))
+            .unwrap();
+        assert!(component.cached_lines(18).is_none());
+        assert!(component.cached_lines(10).is_some());
+        // Changing the text invalidates the cache
+        component.attr(
+            Attribute::Text,
+            AttrValue::Payload(PropPayload::Vec(vec![PropValue::TextSpan(TextSpan::from(
+                "hello",
+            ))])),
+        );
+        assert_ne!(component.text_revision, revision);
+        assert!(component.cached_lines(10).is_none());
+        // Unrelated attributes keep it
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 10)))
+            .unwrap();
+        component.attr(Attribute::ScrollStep, AttrValue::Length(2));
+        assert!(component.cached_lines(10).is_some());
+    }
+
+    #[test]
+    fn test_components_textarea_wrap_marker() {
+        let mut component = Textarea::default()
+            .borders(Borders::default())
+            .wrap_marker('↵')
+            .text_rows(&[
+                TextSpan::from("aaaa bbbb cccc dddd eeee ffff gggg"),
+                TextSpan::from("short"),
+            ]);
+        let mut terminal = Terminal::new(TestBackend::new(12, 8)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 8)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (1..6)
+            .map(|y| (1..11).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        // The marker takes a column from the wrap width
+        assert_eq!(
+            rows,
+            vec![
+                "aaaa bbbb↵",
+                "cccc dddd↵",
+                "eeee ffff↵",
+                "gggg      ",
+                "short     "
+            ]
+        );
+        assert!(buffer[(10, 1)].modifier.contains(TextModifiers::DIM));
+        let markers = buffer
+            .content()
+            .iter()
+            .filter(|x| x.symbol() == "↵")
+            .count();
+        assert_eq!(markers, 3);
+        // No marker by default
+        let mut component = Textarea::default()
+            .borders(Borders::default())
+            .text_rows(&[TextSpan::from("aaaa bbbb cccc dddd eeee ffff gggg")]);
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 8)))
+            .unwrap();
+        let markers = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .filter(|x| x.symbol() == "↵")
+            .count();
+        assert_eq!(markers, 0);
+    }
+
+    #[test]
+    fn test_components_textarea_no_wrap() {
+        let mut component = Textarea::default()
+            .borders(Borders::default())
+            .wrap(false)
+            .text_rows(&[
+                TextSpan::from("0123456789abcdef"),
+                TextSpan::from("short"),
+                TextSpan::from("x"),
+            ]);
+        let mut terminal = Terminal::new(TestBackend::new(12, 5)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 5)))
+            .unwrap();
+        // Lines are not wrapped
+        assert_eq!(component.states.columns, 16);
+        assert_eq!(component.states.width, 10);
+        let buffer = terminal.backend().buffer().clone();
+        assert_eq!(buffer[(1, 1)].symbol(), "0");
+        assert_eq!(buffer[(10, 1)].symbol(), "9");
+        assert_eq!(buffer[(1, 2)].symbol(), "s");
+        // Scroll right
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(component.states.h_offset, 1);
+        (0..10).for_each(|_| {
+            component.perform(Cmd::Move(Direction::Right));
+        });
+        assert_eq!(component.states.h_offset, 6);
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 5)))
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        assert_eq!(buffer[(1, 1)].symbol(), "6");
+        assert_eq!(buffer[(10, 1)].symbol(), "f");
+        assert_eq!(buffer[(1, 2)].symbol(), " ");
+        component.perform(Cmd::Move(Direction::Left));
+        assert_eq!(component.states.h_offset, 5);
+        // Vertical scroll is kept
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(component.states.list_index, 1);
+        // Wider area fixes the offset
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 20, 5)))
+            .unwrap();
+        assert_eq!(component.states.h_offset, 0);
+        // Horizontal scroll is ignored when wrapping
+        component.attr(Attribute::TextWrap, AttrValue::Flag(true));
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.h_offset, 0);
+    }
+
+    #[test]
+    fn test_components_textarea_skip_columns() {
+        assert_eq!(skip_columns("hello", 0), "hello");
+        assert_eq!(skip_columns("hello", 2), "llo");
+        assert_eq!(skip_columns("hello", 10), "");
+        assert_eq!(skip_columns("🚀rocket", 2), "rocket");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_components_textarea_highlighters() {
+        let mut component = Textarea::default()
+            .foreground(Color::White)
+            .highlighters(vec![
+                (
+                    Regex::new("ERROR").unwrap(),
+                    Style::default()
+                        .fg(Color::Red)
+                        .add_modifier(TextModifiers::BOLD),
+                ),
+                (
+                    Regex::new(r"\[\w+\]").unwrap(),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ])
+            .text_rows(&[
+                TextSpan::from("[ERROR] disk full"),
+                TextSpan::from("no match").fg(Color::Green),
+            ]);
+        let spans = component.highlight(TextSpan::from("[ERROR] disk full"));
+        let contents: Vec<&str> = spans.iter().map(|x| x.content.as_str()).collect();
+        // First match wins where highlighters overlap
+        assert_eq!(contents, vec!["[", "ERROR", "]", " disk full"]);
+        assert_eq!(spans[0].fg, Color::Cyan);
+        assert_eq!(spans[1].fg, Color::Red);
+        assert_eq!(spans[1].modifiers, TextModifiers::BOLD);
+        assert_eq!(spans[2].fg, Color::Cyan);
+        assert_eq!(spans[3].fg, Color::Reset);
+        // Rows without matches are kept as they are
+        assert_eq!(
+            component.highlight(TextSpan::from("no match").fg(Color::Green)),
+            vec![TextSpan::from("no match").fg(Color::Green)]
+        );
+        // Render
+        let mut terminal = Terminal::new(TestBackend::new(20, 4)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 20, 4)))
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        assert_eq!(buffer[(1, 1)].fg, Color::Cyan);
+        assert_eq!(buffer[(2, 1)].symbol(), "E");
+        assert_eq!(buffer[(2, 1)].fg, Color::Red);
+        assert_eq!(buffer[(9, 1)].fg, Color::White);
+        assert_eq!(buffer[(1, 2)].fg, Color::Green);
+        // Highlighting applies without wrap as well
+        component.attr(Attribute::TextWrap, AttrValue::Flag(false));
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 20, 4)))
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        assert_eq!(buffer[(2, 1)].symbol(), "E");
+        assert_eq!(buffer[(2, 1)].fg, Color::Red);
+    }
+
+    #[test]
+    fn test_components_textarea_scrollbar() {
+        let render = |component: &mut Textarea| {
+            let mut terminal = Terminal::new(TestBackend::new(8, 5)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 8, 5)))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (1..4)
+                .map(|y| buffer[(7, y)].symbol().to_string())
+                .collect::<Vec<String>>()
+                .concat()
+        };
+        let mut component = Textarea::default()
+            .scrollbar(true)
+            .text("a\nb\nc\nd\ne\nf\ng\nh\ni");
+        assert_eq!(render(&mut component).as_str(), "█║║");
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(render(&mut component).as_str(), "║║█");
+        // Wrapped lines are taken into account
+        let mut component = component.text("abcdefghijklmnopqrstuvwxyz");
+        assert_eq!(render(&mut component).as_str(), "█║║");
+        // Hidden if the content fits
+        let mut component = component.text("a\nb");
+        assert_eq!(render(&mut component).as_str(), "│││");
+    }
+
+    #[test]
+    fn test_components_textarea_tab_width() {
+        let render = |component: &mut Textarea| {
+            let mut terminal = Terminal::new(TestBackend::new(14, 4)).unwrap();
+            terminal
+                .draw(|f| component.view(f, Rect::new(0, 0, 14, 4)))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (1..3)
+                .map(|y| (1..13).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+                .collect::<Vec<String>>()
+        };
+        // Mixed tabs and spaces are aligned to the same column
+        let mut component = Textarea::default().text("ab\tx\n \t y");
+        assert_eq!(render(&mut component), vec!["ab  x       ", "     y      "]);
+        let mut component = component.tab_width(8);
+        assert_eq!(
+            component.query(Attribute::Custom(TEXTAREA_TAB_WIDTH)),
+            Some(AttrValue::Length(8))
+        );
+        assert_eq!(render(&mut component), vec!["ab      x   ", "         y  "]);
+        // Without wrap
+        component.attr(Attribute::TextWrap, AttrValue::Flag(false));
+        assert_eq!(render(&mut component), vec!["ab      x   ", "         y  "]);
+        assert_eq!(component.states.columns, 10);
+    }
+
+    #[test]
+    fn test_components_textarea_full_and_visible_text() {
+        let mut component = Textarea::default()
+            .borders(Borders::default())
+            .text("one\ntwo\nthree\nfour\nfive");
+        assert_eq!(component.full_text(), "one\ntwo\nthree\nfour\nfive");
+        // Never rendered
+        assert_eq!(component.visible_text(), "");
+        // Three rows visible, inside borders
+        let mut terminal = Terminal::new(TestBackend::new(20, 5)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 20, 5)))
+            .unwrap();
+        assert_eq!(component.visible_text(), "one\ntwo\nthree");
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.visible_text(), "three\nfour\nfive");
+        assert_eq!(Textarea::default().full_text(), "");
+        // Wrapped rows take more lines
+        let mut component = Textarea::default()
+            .borders(Borders::default())
+            .text("aaaa bbbb\ntwo\nthree");
+        let mut terminal = Terminal::new(TestBackend::new(6, 5)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 6, 5)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (1..4)
+            .map(|y| (1..5).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(rows, vec!["aaaa", "bbbb", "two "]);
+        assert_eq!(component.visible_text(), "aaaa bbbb\ntwo");
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.visible_text(), "two\nthree");
+    }
 }
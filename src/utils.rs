@@ -5,23 +5,188 @@
 // deps
 extern crate textwrap;
 extern crate unicode_width;
+use std::collections::HashMap;
 // local
-use tuirealm::props::{Alignment, AttrValue, Attribute, Borders, TextModifiers, TextSpan};
-use tuirealm::Props;
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, BorderSides, BorderType, Borders, PropPayload, PropValue,
+    TextModifiers, TextSpan,
+};
+use tuirealm::{Event, Frame, MockComponent, Props, State};
+
+use crate::props::BORDERS_SIDES;
+
+use tuirealm::ratatui::buffer::Buffer;
+use tuirealm::ratatui::layout::Rect;
 // ext
 use tuirealm::ratatui::style::{Color, Modifier, Style};
 use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::text::Span;
-use tuirealm::ratatui::widgets::Block;
-use unicode_width::UnicodeWidthStr;
+use tuirealm::ratatui::widgets::{
+    Block, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// ## Theme
+///
+/// A set of colors and styles which can be applied at once to components through their `theme` builder.
+/// Attributes set after the theme override the theme ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub foreground: Color,
+    pub background: Color,
+    pub border_color: Color,
+    pub border_type: BorderType,
+    pub highlighted_color: Color,
+    pub inactive: Style,
+    pub modifiers: TextModifiers,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            foreground: Color::Reset,
+            background: Color::Reset,
+            border_color: Color::Reset,
+            border_type: BorderType::Plain,
+            highlighted_color: Color::Reset,
+            inactive: Style::default(),
+            modifiers: TextModifiers::empty(),
+        }
+    }
+}
+
+impl Theme {
+    /// ### borders
+    ///
+    /// Get the borders for the theme
+    pub fn borders(&self) -> Borders {
+        Borders::default()
+            .modifiers(self.border_type)
+            .color(self.border_color)
+    }
+}
+
+/// ## KeyMap
+///
+/// Maps keys to the `Cmd` components conventionally perform for them, so that `Component::on()`
+/// only has to handle the keys which are specific to the component.
+/// Keys are bound together with their modifiers; when no binding matches the pressed modifiers,
+/// the binding of the key without modifiers, if any, is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyMap {
+    bindings: HashMap<(Key, KeyModifiers), Cmd>,
+    type_chars: bool,
+}
+
+impl Default for KeyMap {
+    /// Arrows move, page up/down scroll, home/end go to the begin/end, enter submits,
+    /// backspace deletes and delete cancels
+    fn default() -> Self {
+        Self {
+            bindings: HashMap::from([
+                ((Key::Left, KeyModifiers::NONE), Cmd::Move(Direction::Left)),
+                (
+                    (Key::Right, KeyModifiers::NONE),
+                    Cmd::Move(Direction::Right),
+                ),
+                ((Key::Up, KeyModifiers::NONE), Cmd::Move(Direction::Up)),
+                ((Key::Down, KeyModifiers::NONE), Cmd::Move(Direction::Down)),
+                (
+                    (Key::PageUp, KeyModifiers::NONE),
+                    Cmd::Scroll(Direction::Up),
+                ),
+                (
+                    (Key::PageDown, KeyModifiers::NONE),
+                    Cmd::Scroll(Direction::Down),
+                ),
+                ((Key::Home, KeyModifiers::NONE), Cmd::GoTo(Position::Begin)),
+                ((Key::End, KeyModifiers::NONE), Cmd::GoTo(Position::End)),
+                ((Key::Enter, KeyModifiers::NONE), Cmd::Submit),
+                ((Key::Backspace, KeyModifiers::NONE), Cmd::Delete),
+                ((Key::Delete, KeyModifiers::NONE), Cmd::Cancel),
+            ]),
+            type_chars: false,
+        }
+    }
+}
+
+impl KeyMap {
+    /// Map `key`, with its modifiers, to `cmd`, replacing the current binding, if any.
+    /// A `Key` is bound without modifiers.
+    pub fn bind<K: Into<KeyEvent>>(mut self, key: K, cmd: Cmd) -> Self {
+        let KeyEvent { code, modifiers } = key.into();
+        self.bindings.insert((code, modifiers), cmd);
+        self
+    }
+
+    /// Remove the binding of `key`, with its modifiers
+    pub fn unbind<K: Into<KeyEvent>>(mut self, key: K) -> Self {
+        let KeyEvent { code, modifiers } = key.into();
+        self.bindings.remove(&(code, modifiers));
+        self
+    }
+
+    /// If true, characters typed without modifiers, but shift, which aren't bound map to `Cmd::Type`
+    /// (default `false`)
+    pub fn type_chars(mut self, type_chars: bool) -> Self {
+        self.type_chars = type_chars;
+        self
+    }
+
+    /// ### cmd
+    ///
+    /// Get the `Cmd` mapped to the provided event, if any
+    pub fn cmd<U>(&self, ev: &Event<U>) -> Option<Cmd>
+    where
+        U: Eq + PartialEq + Clone + PartialOrd,
+    {
+        let Event::Keyboard(KeyEvent { code, modifiers }) = ev else {
+            return None;
+        };
+        let binding = self
+            .bindings
+            .get(&(*code, *modifiers))
+            .or_else(|| self.bindings.get(&(*code, KeyModifiers::NONE)));
+        match (binding, code) {
+            (Some(cmd), _) => Some(*cmd),
+            (None, Key::Char(ch))
+                if self.type_chars
+                    && (KeyModifiers::SHIFT | KeyModifiers::NONE).contains(*modifiers) =>
+            {
+                Some(Cmd::Type(*ch))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// ### default_keymap
+///
+/// Get the `Cmd` conventionally mapped to the provided event by the default `KeyMap`, if any
+pub fn default_keymap<U>(ev: Event<U>) -> Option<Cmd>
+where
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    KeyMap::default().cmd(&ev)
+}
 
 /// ### wrap_spans
 ///
 /// Given a vector of `TextSpans`, it creates a list of `Spans` which mustn't exceed the provided width parameter.
 /// Each `Spans` in the returned `Vec` is a line in the text.
-pub fn wrap_spans<'a>(spans: &[TextSpan], width: usize, props: &Props) -> Vec<Spans<'a>> {
+/// Wrapped continuation lines are indented by `subsequent_indent` spaces (hanging indent).
+pub fn wrap_spans<'a>(
+    spans: &[TextSpan],
+    width: usize,
+    subsequent_indent: usize,
+    props: &Props,
+) -> Vec<Spans<'a>> {
     // Prepare result (capacity will be at least spans.len)
     let mut res: Vec<Spans> = Vec::with_capacity(spans.len());
+    // Indentation can't take the entire line
+    let indent: String = " ".repeat(subsequent_indent.min(width.saturating_sub(1)));
     // Prepare environment
     let mut line_width: usize = 0; // Incremental line width; mustn't exceed `width`.
     let mut line_spans: Vec<Span> = Vec::new(); // Current line; when done, push to res and re-initialize
@@ -33,15 +198,24 @@ pub fn wrap_spans<'a>(spans: &[TextSpan], width: usize, props: &Props) -> Vec<Sp
             // Check if entire line is wider than the area
             if span.content.width() > width {
                 // Wrap
-                let span_lines = textwrap::wrap(span.content.as_str(), width);
+                let span_lines = textwrap::wrap(
+                    span.content.as_str(),
+                    textwrap::Options::new(width).subsequent_indent(indent.as_str()),
+                );
                 // iter lines
-                for span_line in span_lines.iter() {
-                    // Check if width would exceed...
-                    if line_width + span_line.width() > width {
+                for (i, span_line) in span_lines.iter().enumerate() {
+                    // Indentation is re-applied when a new line is started
+                    let span_line = match i {
+                        0 => span_line.as_ref(),
+                        _ => span_line.strip_prefix(indent.as_str()).unwrap_or(span_line),
+                    };
+                    // Lines after the first one have been wrapped by textwrap, so they always start a new line;
+                    // joining them would drop the whitespace textwrap trimmed at the wrap point
+                    if i > 0 || line_width + span_line.width() > width {
                         // New line
                         res.push(Spans::from(line_spans));
-                        line_width = 0;
-                        line_spans = Vec::new();
+                        line_width = indent.width();
+                        line_spans = indent_spans(indent.as_str());
                     }
                     // Increment line width
                     line_width += span_line.width();
@@ -56,8 +230,8 @@ pub fn wrap_spans<'a>(spans: &[TextSpan], width: usize, props: &Props) -> Vec<Sp
             } else {
                 // Just initialize a new line
                 res.push(Spans::from(line_spans));
-                line_width = 0;
-                line_spans = Vec::new();
+                line_width = indent.width();
+                line_spans = indent_spans(indent.as_str());
             }
         }
         // Push span to line
@@ -75,9 +249,20 @@ pub fn wrap_spans<'a>(spans: &[TextSpan], width: usize, props: &Props) -> Vec<Sp
     res
 }
 
+/// ### indent_spans
+///
+/// Returns the spans to start a wrapped continuation line with
+fn indent_spans<'a>(indent: &str) -> Vec<Span<'a>> {
+    match indent.is_empty() {
+        true => Vec::new(),
+        false => vec![Span::raw(indent.to_string())],
+    }
+}
+
 /// ### use_or_default_styles
 ///
 /// Returns the styles to be used; in case in span are default, use props'.
+/// Span modifiers are added on top of the props' text modifiers.
 /// The values returned are `(foreground, background, modifiers)`
 pub fn use_or_default_styles(props: &Props, span: &TextSpan) -> (Color, Color, Modifier) {
     (
@@ -93,30 +278,28 @@ pub fn use_or_default_styles(props: &Props, span: &TextSpan) -> (Color, Color, M
                 .unwrap_color(),
             _ => span.bg,
         },
-        match span.modifiers.is_empty() {
-            true => props
-                .get_or(
-                    Attribute::TextProps,
-                    AttrValue::TextModifiers(TextModifiers::empty()),
-                )
-                .unwrap_text_modifiers(),
-            false => span.modifiers,
-        },
+        props
+            .get_or(
+                Attribute::TextProps,
+                AttrValue::TextModifiers(TextModifiers::empty()),
+            )
+            .unwrap_text_modifiers()
+            | span.modifiers,
     )
 }
 
 /// ### get_block
 ///
 /// Construct a block for widget using block properties.
-/// If focus is true the border color is applied, otherwise inactive_style
+/// If focus is true the border color is applied, otherwise inactive_style.
+/// Borders are drawn only on the sides set in `props`
 pub fn get_block<'a>(
     props: Borders,
     title: Option<(String, Alignment)>,
     focus: bool,
     inactive_style: Option<Style>,
 ) -> Block<'a> {
-    let title = title.unwrap_or((String::default(), Alignment::Left));
-    Block::default()
+    let block = Block::default()
         .borders(props.sides)
         .border_style(match focus {
             true => props.style(),
@@ -124,9 +307,235 @@ pub fn get_block<'a>(
                 inactive_style.unwrap_or_else(|| Style::default().fg(Color::Reset).bg(Color::Reset))
             }
         })
-        .border_type(props.modifiers)
-        .title(title.0)
-        .title_alignment(title.1)
+        .border_type(props.modifiers);
+    // An empty title would still take the top row, when there's no top border
+    match title {
+        Some((text, alignment)) if !text.is_empty() => block.title(text).title_alignment(alignment),
+        _ => block,
+    }
+}
+
+/// ### get_borders
+///
+/// Get the `Borders` set in `props`; if sides are set as `Custom($BORDERS_SIDES, Number)` (`borders_sides()`),
+/// they replace the sides of the `Borders`, whichever has been set first
+pub fn get_borders(props: &Props) -> Borders {
+    let borders = props
+        .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+        .unwrap_borders();
+    match props.get(Attribute::Custom(BORDERS_SIDES)) {
+        Some(AttrValue::Number(sides)) => {
+            borders.sides(BorderSides::from_bits_truncate(sides as u8))
+        }
+        _ => borders,
+    }
+}
+
+/// ### draw_empty_text
+///
+/// Render `block` in `area` with `text` centered inside it.
+/// Used by components to display a placeholder when they have no entries
+pub fn draw_empty_text(render: &mut Frame, block: Block, area: Rect, text: &str, style: Style) {
+    let inner = block.inner(area);
+    render.render_widget(block, area);
+    if inner.height == 0 {
+        return;
+    }
+    let row = Rect {
+        y: inner.y + (inner.height - 1) / 2,
+        height: 1,
+        ..inner
+    };
+    render.render_widget(
+        Paragraph::new(text)
+            .style(style)
+            .alignment(Alignment::Center),
+        row,
+    );
+}
+
+/// ### scroll_offset
+///
+/// Get the index of the first item visible in a viewport `height` items tall, out of `len` items, scrolling from `offset`
+/// just enough to keep at least `margin` items visible above and below the selected one, at `index`.
+/// The margin is clamped to half the viewport
+pub fn scroll_offset(
+    index: usize,
+    offset: usize,
+    len: usize,
+    height: usize,
+    margin: usize,
+) -> usize {
+    if height == 0 {
+        return index;
+    }
+    let margin = margin.min(height.saturating_sub(1) / 2);
+    let offset = if index < offset + margin {
+        index.saturating_sub(margin)
+    } else if index + margin >= offset + height {
+        index + margin + 1 - height
+    } else {
+        offset
+    };
+    offset.min(len.saturating_sub(height))
+}
+
+/// ### scroll_offset_by_heights
+///
+/// Like `scroll_offset`, but for items of different heights, in lines: get the index of the first item visible
+/// in a viewport `height` lines tall, scrolling from `offset` just enough to keep at least `margin` items visible
+/// above and below the selected one, at `index`. The selected item is kept visible even if the margin doesn't fit
+pub fn scroll_offset_by_heights(
+    index: usize,
+    offset: usize,
+    heights: &[u16],
+    height: usize,
+    margin: usize,
+) -> usize {
+    if height == 0 || heights.is_empty() {
+        return index;
+    }
+    let index = index.min(heights.len() - 1);
+    let margin = margin.min(height.saturating_sub(1) / 2);
+    let lines = |start: usize, end: usize| -> usize {
+        heights[start..end].iter().map(|x| *x as usize).sum()
+    };
+    // Keep the margin above the selected item
+    let mut offset = offset.min(index.saturating_sub(margin));
+    // Scroll down until the selected item and the margin below it fit
+    let last = (index + margin).min(heights.len() - 1);
+    while offset < index && lines(offset, last + 1) > height {
+        offset += 1;
+    }
+    // Don't leave empty lines below the last item
+    while offset > 0 && lines(offset - 1, heights.len()) <= height {
+        offset -= 1;
+    }
+    offset
+}
+
+/// ### draw_scrollbar
+///
+/// Render a vertical scrollbar on the right edge of `area`, spanning the rows of `inner` (the area inside the block).
+/// `content_len` is the amount of rows of the content, `viewport_len` the amount of rows which fit in the view
+/// and `position` the current row. Nothing is rendered if the content fits entirely in the view
+pub fn draw_scrollbar(
+    render: &mut Frame,
+    area: Rect,
+    inner: Rect,
+    content_len: usize,
+    viewport_len: usize,
+    position: usize,
+) {
+    if content_len <= viewport_len || area.width == 0 || inner.height == 0 {
+        return;
+    }
+    let scrollbar_area = Rect {
+        x: area.right() - 1,
+        y: inner.y,
+        width: 1,
+        height: inner.height,
+    };
+    let mut state = ScrollbarState::new(content_len)
+        .viewport_content_length(viewport_len)
+        .position(position.min(content_len - 1));
+    render.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None),
+        scrollbar_area,
+        &mut state,
+    );
+}
+
+/// ### get_progress
+///
+/// Get the progress set as `Attribute::Value` in `props`; 0.0 if unset
+pub fn get_progress(props: &Props) -> f64 {
+    props
+        .get_or(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::F64(0.0))),
+        )
+        .unwrap_payload()
+        .unwrap_one()
+        .unwrap_f64()
+}
+
+/// ### step_progress
+///
+/// Move the progress of `component` forward (or backward) by the step set as `Attribute::Custom(step_attr)` (0.1 by default),
+/// clamped to [0.0, 1.0]. Returns `CmdResult::None` if progress didn't change
+pub fn step_progress(
+    component: &mut dyn MockComponent,
+    step_attr: &'static str,
+    forward: bool,
+) -> CmdResult {
+    let unwrap_f64 = |x: Option<AttrValue>, default: f64| match x {
+        Some(AttrValue::Payload(PropPayload::One(PropValue::F64(x)))) => x,
+        _ => default,
+    };
+    let prev = unwrap_f64(component.query(Attribute::Value), 0.0);
+    let step = unwrap_f64(component.query(Attribute::Custom(step_attr)), 0.1);
+    let progress = match forward {
+        true => prev + step,
+        false => prev - step,
+    }
+    .clamp(0.0, 1.0);
+    if progress == prev {
+        return CmdResult::None;
+    }
+    component.attr(
+        Attribute::Value,
+        AttrValue::Payload(PropPayload::One(PropValue::F64(progress))),
+    );
+    CmdResult::Changed(component.state())
+}
+
+/// ### filter_rows
+///
+/// Get the indexes of the rows of the `Content` table in `props` which contain the query set as `Custom(query_attr)`
+/// (case insensitive) in any of their spans. All the rows are returned if the query is unset or empty
+pub fn filter_rows(props: &Props, query_attr: &'static str) -> Vec<usize> {
+    let query = props
+        .get_ref(Attribute::Custom(query_attr))
+        .and_then(|x| x.as_string())
+        .map(|x| x.to_lowercase())
+        .unwrap_or_default();
+    match props.get_ref(Attribute::Content) {
+        Some(AttrValue::Table(table)) => table
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| {
+                query.is_empty()
+                    || row
+                        .iter()
+                        .any(|col| col.content.to_lowercase().contains(&query))
+            })
+            .map(|(i, _)| i)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// ### visible_position
+///
+/// Get the position among the `visible` rows (as returned by `filter_rows`) of the first one at or after `index` in the content,
+/// if any
+pub fn visible_position(visible: &[usize], index: usize) -> Option<usize> {
+    visible.iter().position(|x| *x >= index)
+}
+
+/// ### boundary_result
+///
+/// Get the result of a move of a selector cursor, given whether the cursor `moved` and the `result` the selector
+/// reports by default. If the cursor hit a boundary and `strict` is set (`strict_boundary`),
+/// `CmdResult::Changed` with the unchanged `state` is returned instead, so that the caller can tell the boundary was hit
+pub fn boundary_result(moved: bool, strict: bool, state: State, result: CmdResult) -> CmdResult {
+    match !moved && strict {
+        true => CmdResult::Changed(state),
+        false => result,
+    }
 }
 
 /// ### calc_utf8_cursor_position
@@ -137,14 +546,574 @@ pub fn calc_utf8_cursor_position(chars: &[char]) -> u16 {
     chars.iter().collect::<String>().width() as u16
 }
 
+/// ### wrap_tabs
+///
+/// Given the width of each tab title, split the tabs into lines which fit `width`, as rendered by the `Tabs` widget,
+/// which pads each title with a space on both sides and puts a divider between them.
+/// Returns the range of the tabs on each line; a tab wider than `width` takes a line on its own
+pub fn wrap_tabs(widths: &[usize], width: usize) -> Vec<std::ops::Range<usize>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut line_width = 0;
+    for (i, w) in widths.iter().enumerate() {
+        // Divider before each tab but the first of the line
+        let tab_width = w + 2 + usize::from(i > start);
+        if i > start && line_width + tab_width > width {
+            lines.push(start..i);
+            start = i;
+            line_width = w + 2;
+        } else {
+            line_width += tab_width;
+        }
+    }
+    if start < widths.len() {
+        lines.push(start..widths.len());
+    }
+    lines
+}
+
+/// ### truncate_with_ellipsis
+///
+/// Truncate `text` so that its width doesn't exceed `width`.
+/// If the text has been truncated, the last character is replaced with an ellipsis (`…`)
+pub fn truncate_with_ellipsis(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    let mut truncated = String::with_capacity(width);
+    let mut truncated_width: usize = 0;
+    for ch in text.chars() {
+        let ch_width = ch.width().unwrap_or(0);
+        // Keep a column for the ellipsis
+        if truncated_width + ch_width + 1 > width {
+            break;
+        }
+        truncated.push(ch);
+        truncated_width += ch_width;
+    }
+    if width > 0 {
+        truncated.push('…');
+    }
+    truncated
+}
+
+/// ### expand_tabs
+///
+/// Replace each tab in `text` with the spaces needed to reach the next tab stop, every `width` columns.
+/// Columns are counted from the last newline. If `width` is 0, tabs are removed
+pub fn expand_tabs(text: &str, width: usize) -> String {
+    if !text.contains('\t') {
+        return text.to_string();
+    }
+    let mut expanded = String::with_capacity(text.len());
+    let mut column: usize = 0;
+    for ch in text.chars() {
+        match ch {
+            '\t' if width > 0 => {
+                let spaces = width - (column % width);
+                expanded.push_str(&" ".repeat(spaces));
+                column += spaces;
+            }
+            '\t' => {}
+            '\n' => {
+                expanded.push(ch);
+                column = 0;
+            }
+            _ => {
+                expanded.push(ch);
+                column += ch.width().unwrap_or(0);
+            }
+        }
+    }
+    expanded
+}
+
+/// ### hyperlink_area
+///
+/// Wrap the text rendered in each row of `area` into an OSC 8 hyperlink pointing to `url`.
+/// Since ratatui doesn't model hyperlinks, the text of each row is moved into its first cell, which takes the style of that cell,
+/// while the following cells are skipped.
+///
+/// > ⚠️ ratatui computes the cell width including the escape sequence bytes, so the cells following the link on the same row
+/// > may not be redrawn until the link changes.
+pub fn hyperlink_area(buf: &mut Buffer, area: Rect, url: &str) {
+    let area = area.intersection(buf.area);
+    for y in area.top()..area.bottom() {
+        let filled: Vec<u16> = (area.left()..area.right())
+            .filter(|x| !buf[(*x, y)].symbol().trim().is_empty())
+            .collect();
+        let (Some(first), Some(last)) = (filled.first().copied(), filled.last().copied()) else {
+            continue;
+        };
+        let mut text = String::new();
+        let mut x = first;
+        while x <= last {
+            let symbol = buf[(x, y)].symbol();
+            text.push_str(symbol);
+            x += symbol.width().max(1) as u16;
+        }
+        buf[(first, y)].set_symbol(&format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\"));
+        for x in first + 1..=last {
+            buf[(x, y)].set_skip(true);
+        }
+    }
+}
+
+/// ### parse_inline_markdown
+///
+/// Parse a tiny subset of inline markdown into styled spans: `**bold**`, `*italic*` and `` `code` ``.
+/// Markers can be escaped with a backslash, while unmatched markers are rendered literally.
+pub fn parse_inline_markdown(text: &str) -> Vec<Span<'static>> {
+    let chars: Vec<char> = text.chars().collect();
+    // Text chunks, with the modifiers applied so far; unmatched markers are kept as text
+    let mut chunks: Vec<(String, Modifier)> = Vec::new();
+    // Runs of `*` which may still open an emphasis
+    let mut openers: Vec<MarkdownDelimiter> = Vec::new();
+    let mut plain = String::new();
+    let flush = |plain: &mut String, chunks: &mut Vec<(String, Modifier)>| {
+        if !plain.is_empty() {
+            chunks.push((std::mem::take(plain), Modifier::empty()));
+        }
+    };
+    let mut i = 0;
+    while i < chars.len() {
+        match (chars[i], chars.get(i + 1).copied()) {
+            ('\\', Some(c @ ('*' | '`' | '\\'))) => {
+                plain.push(c);
+                i += 2;
+            }
+            ('`', _) => match chars[i + 1..].iter().position(|c| *c == '`') {
+                Some(end) if end > 0 => {
+                    flush(&mut plain, &mut chunks);
+                    let code: String = chars[i + 1..i + 1 + end].iter().collect();
+                    chunks.push((code, Modifier::REVERSED));
+                    i += end + 2;
+                }
+                _ => {
+                    plain.push('`');
+                    i += 1;
+                }
+            },
+            ('*', _) => {
+                flush(&mut plain, &mut chunks);
+                let count = chars[i..].iter().take_while(|c| **c == '*').count();
+                let mut delimiter = MarkdownDelimiter {
+                    chunk: chunks.len(),
+                    count,
+                    can_open: chars.get(i + count).is_some_and(|c| !c.is_whitespace()),
+                };
+                chunks.push(("*".repeat(count), Modifier::empty()));
+                let can_close = i > 0 && !chars[i - 1].is_whitespace();
+                // Match the closest openers
+                while can_close && delimiter.count > 0 {
+                    let Some(opener) = openers.last_mut() else {
+                        break;
+                    };
+                    let len = match opener.count >= 2 && delimiter.count >= 2 {
+                        true => 2,
+                        false => 1,
+                    };
+                    let modifier = match len {
+                        2 => Modifier::BOLD,
+                        _ => Modifier::ITALIC,
+                    };
+                    chunks[opener.chunk + 1..delimiter.chunk]
+                        .iter_mut()
+                        .for_each(|(_, x)| *x |= modifier);
+                    opener.count -= len;
+                    delimiter.count -= len;
+                    chunks[opener.chunk].0.truncate(opener.count);
+                    chunks[delimiter.chunk].0.truncate(delimiter.count);
+                    if opener.count == 0 {
+                        openers.pop();
+                    }
+                }
+                if delimiter.count > 0 && delimiter.can_open {
+                    openers.push(delimiter);
+                }
+                i += count;
+            }
+            (c, _) => {
+                plain.push(c);
+                i += 1;
+            }
+        }
+    }
+    flush(&mut plain, &mut chunks);
+    // Merge adjacent chunks with the same style
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for (text, modifier) in chunks.into_iter().filter(|(x, _)| !x.is_empty()) {
+        let style = Style::default().add_modifier(modifier);
+        match spans.last_mut() {
+            Some(last) if last.style == style => last.content.to_mut().push_str(&text),
+            _ => spans.push(Span::styled(text, style)),
+        }
+    }
+    spans
+}
+
+/// A run of `*` markers in inline markdown
+struct MarkdownDelimiter {
+    /// Index of the chunk holding the run
+    chunk: usize,
+    /// Markers in the run not matched yet
+    count: usize,
+    /// Whether the run is followed by a non-whitespace char
+    can_open: bool,
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
-    use tuirealm::props::{Alignment, BorderSides, BorderType, Props};
+    use tuirealm::props::{Alignment, BorderType, Props, TableBuilder};
+    use tuirealm::StateValue;
 
     use pretty_assertions::assert_eq;
 
+    fn key<U: Eq + PartialEq + Clone + PartialOrd>(code: Key) -> Event<U> {
+        Event::Keyboard(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_utils_theme() {
+        use crate::{Input, List, Radio, Select, Table};
+
+        let theme = Theme {
+            foreground: Color::Yellow,
+            background: Color::Black,
+            border_color: Color::Yellow,
+            border_type: BorderType::Rounded,
+            highlighted_color: Color::LightYellow,
+            inactive: Style::default().fg(Color::Gray),
+            modifiers: TextModifiers::BOLD,
+        };
+        let components: Vec<Box<dyn MockComponent>> = vec![
+            Box::new(Input::default().theme(&theme).foreground(Color::Red)),
+            Box::new(List::default().theme(&theme).foreground(Color::Red)),
+            Box::new(Radio::default().theme(&theme).foreground(Color::Red)),
+            Box::new(Select::default().theme(&theme).foreground(Color::Red)),
+            Box::new(Table::default().theme(&theme).foreground(Color::Red)),
+        ];
+        for component in components {
+            // Later builders override the theme
+            assert_eq!(
+                component.query(Attribute::Foreground),
+                Some(AttrValue::Color(Color::Red))
+            );
+            assert_eq!(
+                component.query(Attribute::Background),
+                Some(AttrValue::Color(Color::Black))
+            );
+            assert_eq!(
+                component.query(Attribute::Borders),
+                Some(AttrValue::Borders(theme.borders()))
+            );
+            assert_eq!(
+                component.query(Attribute::FocusStyle),
+                Some(AttrValue::Style(Style::default().fg(Color::Gray)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_utils_get_borders() {
+        let mut props = Props::default();
+        assert_eq!(get_borders(&props), Borders::default());
+        props.set(
+            Attribute::Borders,
+            AttrValue::Borders(Borders::default().color(Color::Red)),
+        );
+        assert_eq!(get_borders(&props).sides, BorderSides::ALL);
+        // Sides replace the borders ones
+        props.set(
+            Attribute::Custom(BORDERS_SIDES),
+            AttrValue::Number(BorderSides::BOTTOM.bits() as isize),
+        );
+        let borders = get_borders(&props);
+        assert_eq!(borders.sides, BorderSides::BOTTOM);
+        assert_eq!(borders.color, Color::Red);
+        props.set(
+            Attribute::Borders,
+            AttrValue::Borders(Borders::default().sides(BorderSides::TOP)),
+        );
+        assert_eq!(get_borders(&props).sides, BorderSides::BOTTOM);
+    }
+
+    #[test]
+    fn test_utils_filter_rows() {
+        let mut props = Props::default();
+        assert!(filter_rows(&props, "query").is_empty());
+        props.set(
+            Attribute::Content,
+            AttrValue::Table(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("apple"))
+                    .add_row()
+                    .add_col(TextSpan::from("banana"))
+                    .add_col(TextSpan::from("split"))
+                    .add_row()
+                    .add_col(TextSpan::from("cherry"))
+                    .build(),
+            ),
+        );
+        // No query
+        assert_eq!(filter_rows(&props, "query"), vec![0, 1, 2]);
+        // Case insensitive, in any span
+        props.set(
+            Attribute::Custom("query"),
+            AttrValue::String(String::from("PL")),
+        );
+        assert_eq!(filter_rows(&props, "query"), vec![0, 1]);
+        props.set(
+            Attribute::Custom("query"),
+            AttrValue::String(String::from("kiwi")),
+        );
+        assert!(filter_rows(&props, "query").is_empty());
+        // Position among the visible rows
+        assert_eq!(visible_position(&[0, 2, 5], 2), Some(1));
+        assert_eq!(visible_position(&[0, 2, 5], 3), Some(2));
+        assert_eq!(visible_position(&[0, 2, 5], 6), None);
+        assert_eq!(visible_position(&[], 0), None);
+    }
+
+    #[test]
+    fn test_utils_scroll_offset() {
+        // Near the top edge the offset can't go below 0
+        assert_eq!(scroll_offset(1, 0, 20, 5, 2), 0);
+        // Moving down keeps the margin below the selection
+        assert_eq!(scroll_offset(3, 0, 20, 5, 2), 1);
+        assert_eq!(scroll_offset(3, 1, 20, 5, 2), 1);
+        // Moving up keeps the margin above the selection
+        assert_eq!(scroll_offset(11, 10, 20, 5, 2), 9);
+        // Near the bottom edge the offset can't go beyond the last page
+        assert_eq!(scroll_offset(19, 10, 20, 5, 2), 15);
+        // Margin is clamped to half the viewport
+        assert_eq!(scroll_offset(10, 0, 20, 5, 10), 8);
+        assert_eq!(scroll_offset(10, 0, 20, 0, 2), 10);
+    }
+
+    #[test]
+    fn test_utils_scroll_offset_by_heights() {
+        // Same as `scroll_offset` with items one line tall
+        let heights = [1; 20];
+        for (index, offset, margin) in [(1, 0, 2), (3, 0, 2), (3, 1, 2), (11, 10, 2), (19, 10, 2)] {
+            assert_eq!(
+                scroll_offset_by_heights(index, offset, &heights, 5, margin),
+                scroll_offset(index, offset, 20, 5, margin)
+            );
+        }
+        // Taller items take more lines
+        let heights = [2, 3, 1, 2, 2, 1];
+        assert_eq!(scroll_offset_by_heights(1, 0, &heights, 5, 0), 0);
+        assert_eq!(scroll_offset_by_heights(2, 0, &heights, 5, 0), 1);
+        assert_eq!(scroll_offset_by_heights(3, 0, &heights, 5, 0), 2);
+        assert_eq!(scroll_offset_by_heights(3, 0, &heights, 5, 1), 2);
+        // Moving up keeps the margin above the selection
+        assert_eq!(scroll_offset_by_heights(4, 4, &heights, 5, 1), 3);
+        // The last page is filled
+        assert_eq!(scroll_offset_by_heights(5, 5, &heights, 5, 0), 3);
+        // The selected item is visible even if taller than the viewport
+        assert_eq!(scroll_offset_by_heights(1, 0, &heights, 2, 0), 1);
+        assert_eq!(scroll_offset_by_heights(1, 0, &[], 5, 0), 1);
+    }
+
+    #[test]
+    fn test_utils_boundary_result() {
+        let state = State::One(StateValue::Usize(0));
+        // Moved: default result
+        assert_eq!(
+            boundary_result(true, true, state.clone(), CmdResult::None),
+            CmdResult::None
+        );
+        // Boundary without strict: default result
+        assert_eq!(
+            boundary_result(false, false, state.clone(), CmdResult::None),
+            CmdResult::None
+        );
+        assert_eq!(
+            boundary_result(
+                false,
+                false,
+                state.clone(),
+                CmdResult::Changed(state.clone())
+            ),
+            CmdResult::Changed(state.clone())
+        );
+        // Boundary with strict
+        assert_eq!(
+            boundary_result(false, true, state.clone(), CmdResult::None),
+            CmdResult::Changed(state)
+        );
+    }
+
+    #[test]
+    fn test_utils_step_progress() {
+        use crate::props::LINE_GAUGE_STEP;
+        use crate::LineGauge;
+
+        let mut component = LineGauge::default().progress(0.5).step(0.25);
+        assert_eq!(
+            step_progress(&mut component, LINE_GAUGE_STEP, true),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(
+            component.query(Attribute::Value),
+            Some(AttrValue::Payload(PropPayload::One(PropValue::F64(0.75))))
+        );
+        step_progress(&mut component, LINE_GAUGE_STEP, true);
+        // At the end
+        assert_eq!(
+            step_progress(&mut component, LINE_GAUGE_STEP, true),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.query(Attribute::Value),
+            Some(AttrValue::Payload(PropPayload::One(PropValue::F64(1.0))))
+        );
+        // Decrease; clamped at 0
+        let mut component = component.progress(0.1);
+        assert_eq!(
+            step_progress(&mut component, LINE_GAUGE_STEP, false),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(
+            step_progress(&mut component, LINE_GAUGE_STEP, false),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.query(Attribute::Value),
+            Some(AttrValue::Payload(PropPayload::One(PropValue::F64(0.0))))
+        );
+        // Default step
+        let mut component = LineGauge::default().progress(0.5);
+        step_progress(&mut component, LINE_GAUGE_STEP, false);
+        assert_eq!(
+            component.query(Attribute::Value),
+            Some(AttrValue::Payload(PropPayload::One(PropValue::F64(0.4))))
+        );
+    }
+
+    #[test]
+    fn test_utils_default_keymap() {
+        use tuirealm::NoUserEvent;
+
+        let expected = [
+            (Key::Left, Cmd::Move(Direction::Left)),
+            (Key::Right, Cmd::Move(Direction::Right)),
+            (Key::Up, Cmd::Move(Direction::Up)),
+            (Key::Down, Cmd::Move(Direction::Down)),
+            (Key::PageUp, Cmd::Scroll(Direction::Up)),
+            (Key::PageDown, Cmd::Scroll(Direction::Down)),
+            (Key::Home, Cmd::GoTo(Position::Begin)),
+            (Key::End, Cmd::GoTo(Position::End)),
+            (Key::Enter, Cmd::Submit),
+            (Key::Backspace, Cmd::Delete),
+            (Key::Delete, Cmd::Cancel),
+        ];
+        for (code, cmd) in expected {
+            assert_eq!(default_keymap(key::<NoUserEvent>(code)), Some(cmd));
+        }
+        // Fall back to the key without modifiers
+        assert_eq!(
+            default_keymap::<NoUserEvent>(Event::Keyboard(KeyEvent::new(
+                Key::Down,
+                KeyModifiers::SHIFT
+            ))),
+            Some(Cmd::Move(Direction::Down))
+        );
+        // Unbound keys and other events
+        assert_eq!(default_keymap(key::<NoUserEvent>(Key::Tab)), None);
+        assert_eq!(default_keymap(key::<NoUserEvent>(Key::Char('a'))), None);
+        assert_eq!(default_keymap::<NoUserEvent>(Event::Tick), None);
+    }
+
+    #[test]
+    fn test_utils_keymap() {
+        use tuirealm::NoUserEvent;
+
+        let keymap = KeyMap::default()
+            .bind(Key::Char(' '), Cmd::Toggle)
+            .bind(Key::Enter, Cmd::Change)
+            .bind(
+                KeyEvent::new(Key::Left, KeyModifiers::CONTROL),
+                Cmd::GoTo(Position::Begin),
+            )
+            .bind(
+                KeyEvent::new(Key::Backspace, KeyModifiers::CONTROL),
+                Cmd::Cancel,
+            )
+            .unbind(Key::Delete)
+            .type_chars(true);
+        assert_eq!(
+            keymap.cmd(&key::<NoUserEvent>(Key::Char(' '))),
+            Some(Cmd::Toggle)
+        );
+        assert_eq!(
+            keymap.cmd(&key::<NoUserEvent>(Key::Enter)),
+            Some(Cmd::Change)
+        );
+        assert_eq!(keymap.cmd(&key::<NoUserEvent>(Key::Delete)), None);
+        // Bindings with modifiers are told apart from the key without modifiers
+        assert_eq!(
+            keymap.cmd::<NoUserEvent>(&Event::Keyboard(KeyEvent::new(
+                Key::Left,
+                KeyModifiers::CONTROL
+            ))),
+            Some(Cmd::GoTo(Position::Begin))
+        );
+        assert_eq!(
+            keymap.cmd::<NoUserEvent>(&Event::Keyboard(KeyEvent::new(
+                Key::Left,
+                KeyModifiers::SHIFT
+            ))),
+            Some(Cmd::Move(Direction::Left))
+        );
+        assert_eq!(
+            keymap.cmd(&key::<NoUserEvent>(Key::Left)),
+            Some(Cmd::Move(Direction::Left))
+        );
+        assert_eq!(
+            keymap.cmd::<NoUserEvent>(&Event::Keyboard(KeyEvent::new(
+                Key::Backspace,
+                KeyModifiers::CONTROL
+            ))),
+            Some(Cmd::Cancel)
+        );
+        assert_eq!(
+            keymap.cmd(&key::<NoUserEvent>(Key::Backspace)),
+            Some(Cmd::Delete)
+        );
+        assert_eq!(
+            keymap
+                .clone()
+                .unbind(KeyEvent::new(Key::Left, KeyModifiers::CONTROL))
+                .cmd::<NoUserEvent>(&Event::Keyboard(KeyEvent::new(
+                    Key::Left,
+                    KeyModifiers::CONTROL
+                ))),
+            Some(Cmd::Move(Direction::Left))
+        );
+        assert_eq!(
+            keymap.cmd(&key::<NoUserEvent>(Key::Char('a'))),
+            Some(Cmd::Type('a'))
+        );
+        assert_eq!(
+            keymap.cmd::<NoUserEvent>(&Event::Keyboard(KeyEvent::new(
+                Key::Char('A'),
+                KeyModifiers::SHIFT
+            ))),
+            Some(Cmd::Type('A'))
+        );
+        assert_eq!(
+            keymap.cmd::<NoUserEvent>(&Event::Keyboard(KeyEvent::new(
+                Key::Char('c'),
+                KeyModifiers::CONTROL
+            ))),
+            None
+        );
+    }
+
     #[test]
     fn test_components_utils_wrap_spans() {
         let mut props: Props = Props::default();
@@ -156,19 +1125,19 @@ mod test {
         props.set(Attribute::Background, AttrValue::Color(Color::White));
         // Prepare spans; let's start with two simple spans, which fits the line
         let spans: Vec<TextSpan> = vec![TextSpan::from("hello, "), TextSpan::from("world!")];
-        assert_eq!(wrap_spans(&spans, 64, &props).len(), 1);
+        assert_eq!(wrap_spans(&spans, 64, 0, &props).len(), 1);
         // Let's make a sentence, which would require two lines
         let spans: Vec<TextSpan> = vec![
             TextSpan::from("Hello, everybody, I'm Uncle Camel!"),
             TextSpan::from("How's it going today?"),
         ];
-        assert_eq!(wrap_spans(&spans, 32, &props).len(), 2);
+        assert_eq!(wrap_spans(&spans, 32, 0, &props).len(), 2);
         // Let's make a sentence, which requires 3 lines, but with only one span
         let spans: Vec<TextSpan> = vec![TextSpan::from(
             "Hello everybody! My name is Uncle Camel. How's it going today?",
         )];
         // makes Hello everybody, my name is uncle, camel. how's it, goind today
-        assert_eq!(wrap_spans(&spans, 16, &props).len(), 4);
+        assert_eq!(wrap_spans(&spans, 16, 0, &props).len(), 4);
         // Combine
         let spans: Vec<TextSpan> = vec![
             TextSpan::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit."),
@@ -177,7 +1146,85 @@ mod test {
             TextSpan::from("Sed vitae rutrum quam."),
         ];
         // "Lorem ipsum dolor sit amet,", "consectetur adipiscing elit. Canem!", "In posuere sollicitudin vulputate", "Sed vitae rutrum quam."
-        assert_eq!(wrap_spans(&spans, 36, &props).len(), 4);
+        assert_eq!(wrap_spans(&spans, 36, 0, &props).len(), 4);
+    }
+
+    #[test]
+    fn test_components_utils_wrap_spans_styles() {
+        let mut props: Props = Props::default();
+        props.set(
+            Attribute::TextProps,
+            AttrValue::TextModifiers(TextModifiers::BOLD),
+        );
+        let spans: Vec<TextSpan> = vec![
+            TextSpan::from("alpha beta gamma").fg(Color::Red),
+            TextSpan::from("delta epsilon zeta")
+                .fg(Color::Green)
+                .italic(),
+            TextSpan::from("eta theta iota").fg(Color::Blue),
+        ];
+        let lines: Vec<Vec<(String, Style)>> = wrap_spans(&spans, 11, 0, &props)
+            .into_iter()
+            .map(|line| {
+                line.spans
+                    .into_iter()
+                    .map(|x| (x.content.to_string(), x.style))
+                    .collect()
+            })
+            .collect();
+        let red = Style::default()
+            .fg(Color::Red)
+            .bg(Color::Reset)
+            .add_modifier(TextModifiers::BOLD);
+        let green = Style::default()
+            .fg(Color::Green)
+            .bg(Color::Reset)
+            .add_modifier(TextModifiers::BOLD | TextModifiers::ITALIC);
+        let blue = Style::default()
+            .fg(Color::Blue)
+            .bg(Color::Reset)
+            .add_modifier(TextModifiers::BOLD);
+        let fragment = |text: &str, style: Style| (text.to_string(), style);
+        assert_eq!(
+            lines,
+            vec![
+                vec![fragment("alpha beta", red)],
+                vec![fragment("gamma", red), fragment("delta", green)],
+                // Wrapped lines of a span are never joined
+                vec![fragment("epsilon", green)],
+                vec![fragment("zeta", green)],
+                vec![fragment("eta theta", blue)],
+                vec![fragment("iota", blue)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_components_utils_wrap_spans_subsequent_indent() {
+        let props: Props = Props::default();
+        let spans: Vec<TextSpan> = vec![TextSpan::from(
+            "1. Hello everybody! My name is Uncle Camel. How's it going today?",
+        )];
+        let lines = wrap_spans(&spans, 20, 3, &props);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0].to_string(), "1. Hello everybody!");
+        assert_eq!(lines[1].to_string(), "   My name is Uncle");
+        assert!(lines
+            .iter()
+            .skip(1)
+            .all(|x| x.to_string().starts_with("   ")));
+        assert!(lines.iter().all(|x| x.width() <= 20));
+        // Indent is applied to continuation lines made of several spans too
+        let spans: Vec<TextSpan> = vec![
+            TextSpan::from("- Hello, everybody,"),
+            TextSpan::from("how's it going?"),
+        ];
+        let lines = wrap_spans(&spans, 20, 2, &props);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].to_string(), "  how's it going?");
+        // No indent by default
+        let lines = wrap_spans(&spans, 20, 0, &props);
+        assert_eq!(lines[1].to_string(), "how's it going?");
     }
 
     #[test]
@@ -198,12 +1245,20 @@ mod test {
         assert_eq!(fg, Color::Yellow);
         assert_eq!(bg, Color::Cyan);
         assert!(modifiers.intersects(Modifier::UNDERLINED));
+        // Span modifiers are added to the component's
+        assert!(modifiers.intersects(Modifier::BOLD));
+        assert!(!modifiers.intersects(Modifier::ITALIC));
         // Default
         let span: TextSpan = TextSpan::from("test");
         let (fg, bg, modifiers) = use_or_default_styles(&props, &span);
         assert_eq!(fg, Color::Red);
         assert_eq!(bg, Color::White);
-        assert!(modifiers.intersects(Modifier::BOLD));
+        assert_eq!(modifiers, Modifier::BOLD);
+        // No component modifiers
+        let props = Props::default();
+        let span: TextSpan = TextSpan::from("test").italic();
+        let (_, _, modifiers) = use_or_default_styles(&props, &span);
+        assert_eq!(modifiers, Modifier::ITALIC);
     }
 
     #[test]
@@ -219,6 +1274,195 @@ mod test {
             None,
         );
         get_block(props, None, false, None);
+        // Empty titles don't take the top row
+        let area = Rect::new(0, 0, 10, 4);
+        let props = Borders::default().sides(BorderSides::BOTTOM);
+        let block = get_block(
+            props.clone(),
+            Some((String::default(), Alignment::Left)),
+            true,
+            None,
+        );
+        assert_eq!(block.inner(area), Rect::new(0, 0, 10, 3));
+        let block = get_block(
+            props,
+            Some(("title".to_string(), Alignment::Left)),
+            true,
+            None,
+        );
+        assert_eq!(block.inner(area), Rect::new(0, 1, 10, 2));
+    }
+
+    #[test]
+    fn test_components_utils_draw_empty_text() {
+        use tuirealm::ratatui::{backend::TestBackend, Terminal};
+        let mut terminal = Terminal::new(TestBackend::new(9, 4)).unwrap();
+        terminal
+            .draw(|f| {
+                draw_empty_text(
+                    f,
+                    Block::default().borders(BorderSides::ALL),
+                    Rect::new(0, 0, 9, 4),
+                    "empty",
+                    Style::default().fg(Color::Red),
+                )
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..9).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert_eq!(row.as_str(), "│ empty │");
+        assert_eq!(buffer[(2, 1)].fg, Color::Red);
+    }
+
+    #[test]
+    fn test_components_utils_draw_scrollbar() {
+        use tuirealm::ratatui::{backend::TestBackend, Terminal};
+        let draw = |content_len: usize, position: usize| {
+            let mut terminal = Terminal::new(TestBackend::new(5, 6)).unwrap();
+            terminal
+                .draw(|f| {
+                    draw_scrollbar(
+                        f,
+                        Rect::new(0, 0, 5, 6),
+                        Rect::new(1, 1, 3, 4),
+                        content_len,
+                        4,
+                        position,
+                    )
+                })
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (0..6)
+                .map(|y| buffer[(4, y)].symbol().to_string())
+                .collect::<Vec<String>>()
+                .concat()
+        };
+        // Content fits
+        assert_eq!(draw(4, 0).as_str(), "      ");
+        // Thumb at the beginning and at the end
+        assert_eq!(draw(8, 0).as_str(), " █║║║ ");
+        assert_eq!(draw(8, 7).as_str(), " ║║║█ ");
+    }
+
+    #[test]
+    fn test_components_utils_wrap_tabs() {
+        assert!(wrap_tabs(&[], 10).is_empty());
+        // " a │ bb │ c " is 12 columns wide
+        assert_eq!(wrap_tabs(&[1, 2, 1], 12), vec![0..3]);
+        assert_eq!(wrap_tabs(&[1, 2, 1], 11), vec![0..2, 2..3]);
+        assert_eq!(wrap_tabs(&[1, 2, 1], 4), vec![0..1, 1..2, 2..3]);
+        // Too wide tabs take a line on their own
+        assert_eq!(wrap_tabs(&[20, 1, 1], 10), vec![0..1, 1..3]);
+    }
+
+    #[test]
+    fn test_components_utils_truncate_with_ellipsis() {
+        assert_eq!(truncate_with_ellipsis("hello", 5).as_str(), "hello");
+        assert_eq!(truncate_with_ellipsis("hello", 16).as_str(), "hello");
+        assert_eq!(truncate_with_ellipsis("hello world", 6).as_str(), "hello…");
+        assert_eq!(truncate_with_ellipsis("hello", 1).as_str(), "…");
+        assert_eq!(truncate_with_ellipsis("hello", 0).as_str(), "");
+        // Wide characters
+        assert_eq!(truncate_with_ellipsis("我之😄", 4).as_str(), "我…");
+    }
+
+    #[test]
+    fn test_components_utils_expand_tabs() {
+        assert_eq!(expand_tabs("no tabs", 4).as_str(), "no tabs");
+        assert_eq!(expand_tabs("\tx", 4).as_str(), "    x");
+        assert_eq!(expand_tabs("ab\tx", 4).as_str(), "ab  x");
+        assert_eq!(expand_tabs("abcd\tx", 4).as_str(), "abcd    x");
+        // Mixed tabs and spaces
+        assert_eq!(expand_tabs("a \t\tx", 4).as_str(), "a       x");
+        // Wide characters and newlines
+        assert_eq!(expand_tabs("我\tx", 4).as_str(), "我  x");
+        assert_eq!(expand_tabs("abc\n\tx", 2).as_str(), "abc\n  x");
+        assert_eq!(expand_tabs("a\tx", 0).as_str(), "ax");
+    }
+
+    #[test]
+    fn test_components_utils_hyperlink_area() {
+        let area = Rect::new(0, 0, 10, 2);
+        let mut buf = Buffer::empty(area);
+        buf.set_string(2, 0, "foo bar", Style::default());
+        hyperlink_area(&mut buf, area, "https://example.com");
+        assert_eq!(
+            buf[(2, 0)].symbol(),
+            "\x1b]8;;https://example.com\x1b\\foo bar\x1b]8;;\x1b\\"
+        );
+        assert!((3..9).all(|x| buf[(x, 0)].skip));
+        assert!(!buf[(9, 0)].skip);
+        // Empty rows are kept
+        assert!((0..10).all(|x| buf[(x, 1)].symbol() == " " && !buf[(x, 1)].skip));
+    }
+
+    #[test]
+    fn test_components_utils_parse_inline_markdown() {
+        let md = |text: &str| -> Vec<(String, Modifier)> {
+            parse_inline_markdown(text)
+                .into_iter()
+                .map(|x| (x.content.to_string(), x.style.add_modifier))
+                .collect()
+        };
+        let plain = Modifier::empty();
+        assert_eq!(md(""), vec![]);
+        assert_eq!(md("hello"), vec![("hello".to_string(), plain)]);
+        assert_eq!(
+            md("press **ESC** to `quit`"),
+            vec![
+                ("press ".to_string(), plain),
+                ("ESC".to_string(), Modifier::BOLD),
+                (" to ".to_string(), plain),
+                ("quit".to_string(), Modifier::REVERSED),
+            ]
+        );
+        // Nested
+        assert_eq!(
+            md("*very **important** thing*"),
+            vec![
+                ("very ".to_string(), Modifier::ITALIC),
+                ("important".to_string(), Modifier::ITALIC | Modifier::BOLD),
+                (" thing".to_string(), Modifier::ITALIC),
+            ]
+        );
+        assert_eq!(
+            md("***both***"),
+            vec![("both".to_string(), Modifier::BOLD | Modifier::ITALIC)]
+        );
+        // Adjacent
+        assert_eq!(
+            md("**a***b*"),
+            vec![
+                ("a".to_string(), Modifier::BOLD),
+                ("b".to_string(), Modifier::ITALIC),
+            ]
+        );
+        // Code is not parsed
+        assert_eq!(
+            md("`**a**`"),
+            vec![("**a**".to_string(), Modifier::REVERSED)]
+        );
+        // Escaped and unmatched markers
+        assert_eq!(
+            md(r"\*not italic\* 2 * 3 **open `x"),
+            vec![("*not italic* 2 * 3 **open `x".to_string(), plain)]
+        );
+    }
+
+    #[test]
+    fn test_components_utils_parse_inline_markdown_unbalanced() {
+        let plain = |text: &str| vec![Span::styled(text.to_string(), Style::default())];
+        // Unclosed markers are parsed in a single pass
+        let text = "*a ".repeat(5000);
+        assert_eq!(parse_inline_markdown(&text), plain(&text));
+        let text = format!("{}`", "**a *b c ".repeat(5000));
+        assert_eq!(parse_inline_markdown(&text), plain(&text));
+        // Closed at the end
+        let text = format!("*{}a*", "b ** ".repeat(5000));
+        let spans = parse_inline_markdown(&text);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].style.add_modifier, Modifier::ITALIC);
+        assert_eq!(spans[0].content.len(), text.len() - 2);
     }
 
     #[test]